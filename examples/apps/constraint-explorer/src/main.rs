@@ -14,14 +14,14 @@ use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
     layout::{
-        Constraint::{self, Fill, Length, Max, Min, Percentage, Ratio},
+        Constraint::{self, Fill, FillMax, Length, Max, Min, Percentage, Ratio},
         Flex, Layout, Rect,
     },
     style::{
         palette::tailwind::{BLUE, SKY, SLATE, STONE},
         Color, Style, Stylize,
     },
-    symbols::{self, line},
+    symbols,
     text::{Line, Span, Text},
     widgets::{Block, Paragraph, Widget, Wrap},
     DefaultTerminal,
@@ -146,6 +146,7 @@ impl App {
             | Constraint::Min(v)
             | Constraint::Max(v)
             | Constraint::Fill(v)
+            | Constraint::FillMax(_, v)
             | Constraint::Percentage(v) => *v = v.saturating_add(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_add(1),
         };
@@ -160,6 +161,7 @@ impl App {
             | Constraint::Min(v)
             | Constraint::Max(v)
             | Constraint::Fill(v)
+            | Constraint::FillMax(_, v)
             | Constraint::Percentage(v) => *v = v.saturating_sub(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_sub(1),
         };
@@ -239,7 +241,7 @@ impl From<Constraint> for ConstraintName {
             Ratio(_, _) => Self::Ratio,
             Min(_) => Self::Min,
             Max(_) => Self::Max,
-            Fill(_) => Self::Fill,
+            Fill(_) | FillMax(_, _) => Self::Fill,
         }
     }
 }
@@ -496,16 +498,7 @@ impl SpacerBlock {
 
     /// A block with a corner borders
     fn block() -> impl Widget {
-        let corners_only = symbols::border::Set {
-            top_left: line::NORMAL.top_left,
-            top_right: line::NORMAL.top_right,
-            bottom_left: line::NORMAL.bottom_left,
-            bottom_right: line::NORMAL.bottom_right,
-            vertical_left: " ",
-            vertical_right: " ",
-            horizontal_top: " ",
-            horizontal_bottom: " ",
-        };
+        let corners_only = symbols::border::PLAIN.with_edges([" "; 4]);
         Block::bordered()
             .border_set(corners_only)
             .border_style(Self::BORDER_COLOR)
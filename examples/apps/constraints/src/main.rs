@@ -380,7 +380,7 @@ impl Example {
             Constraint::Length(_) => LENGTH_COLOR,
             Constraint::Percentage(_) => PERCENTAGE_COLOR,
             Constraint::Ratio(_, _) => RATIO_COLOR,
-            Constraint::Fill(_) => FILL_COLOR,
+            Constraint::Fill(_) | Constraint::FillMax(_, _) => FILL_COLOR,
             Constraint::Min(_) => MIN_COLOR,
             Constraint::Max(_) => MAX_COLOR,
         };
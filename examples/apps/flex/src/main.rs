@@ -20,7 +20,7 @@ use ratatui::{
         Flex, Layout, Rect,
     },
     style::{palette::tailwind, Color, Modifier, Style, Stylize},
-    symbols::{self, line},
+    symbols,
     text::{Line, Text},
     widgets::{
         Block, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, Tabs,
@@ -64,7 +64,7 @@ const EXAMPLE_DATA: &[(&str, &[Constraint])] = &[
         &[Max(20), Length(10)],
     ),
     (
-        "Min grows always but also allows Fill to grow",
+        "Min keeps its minimum size when Fill shares the excess space",
         &[Percentage(50), Fill(1), Fill(2), Min(50)],
     ),
     (
@@ -446,16 +446,7 @@ impl Widget for Example {
 impl Example {
     fn render_spacer(spacer: Rect, buf: &mut Buffer) {
         if spacer.width > 1 {
-            let corners_only = symbols::border::Set {
-                top_left: line::NORMAL.top_left,
-                top_right: line::NORMAL.top_right,
-                bottom_left: line::NORMAL.bottom_left,
-                bottom_right: line::NORMAL.bottom_right,
-                vertical_left: " ",
-                vertical_right: " ",
-                horizontal_top: " ",
-                horizontal_bottom: " ",
-            };
+            let corners_only = symbols::border::PLAIN.with_edges([" "; 4]);
             Block::bordered()
                 .border_set(corners_only)
                 .border_style(Style::reset().dark_gray())
@@ -511,7 +502,7 @@ const fn color_for_constraint(constraint: Constraint) -> Color {
         Constraint::Length(_) => SLATE.c700,
         Constraint::Percentage(_) => SLATE.c800,
         Constraint::Ratio(_, _) => SLATE.c900,
-        Constraint::Fill(_) => SLATE.c950,
+        Constraint::Fill(_) | Constraint::FillMax(_, _) => SLATE.c950,
     }
 }
 
@@ -109,8 +109,17 @@ use crate::{
     layout::{Position, Size},
 };
 
+mod color_mode;
+pub use self::color_mode::ColorMode;
+
+mod fallback;
+pub use self::fallback::ModifierFallbacks;
+
+mod resize;
+pub use self::resize::ResizeReceiver;
+
 mod test;
-pub use self::test::TestBackend;
+pub use self::test::{BackendOp, TestBackend};
 
 /// Enum representing the different types of clearing operations that can be performed
 /// on the terminal screen.
@@ -141,6 +150,52 @@ pub struct WindowSize {
     pub pixels: Size,
 }
 
+impl WindowSize {
+    /// Returns the size of a single cell in pixels.
+    ///
+    /// This divides the size of the window in pixels by its size in columns and rows, rounding
+    /// down. It can be used to draw graphics with the correct proportions, e.g. to find the aspect
+    /// ratio of a cell.
+    ///
+    /// Returns `None` when the size of a cell is unknown, which is the case when the terminal does
+    /// not report its size in pixels (and reports `0,0` instead, see [`WindowSize::pixels`]) or
+    /// when the window has no columns or rows. Callers should then fall back to an assumed cell
+    /// size, such as the common 1:2 width to height ratio of terminal fonts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{backend::WindowSize, layout::Size};
+    ///
+    /// let window_size = WindowSize {
+    ///     columns_rows: Size::new(80, 24),
+    ///     pixels: Size::new(640, 480),
+    /// };
+    /// assert_eq!(window_size.cell_size(), Some(Size::new(8, 20)));
+    ///
+    /// let unknown = WindowSize {
+    ///     columns_rows: Size::new(80, 24),
+    ///     pixels: Size::new(0, 0),
+    /// };
+    /// assert_eq!(unknown.cell_size(), None);
+    /// ```
+    pub const fn cell_size(self) -> Option<Size> {
+        let Self {
+            columns_rows,
+            pixels,
+        } = self;
+        if columns_rows.width == 0 || columns_rows.height == 0 {
+            return None;
+        }
+        let width = pixels.width / columns_rows.width;
+        let height = pixels.height / columns_rows.height;
+        if width == 0 || height == 0 {
+            return None;
+        }
+        Some(Size::new(width, height))
+    }
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries. It defines the
 /// methods required to draw content, manipulate the cursor, and clear the terminal screen.
 ///
@@ -219,6 +274,65 @@ pub trait Backend {
     /// ```
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()>;
 
+    /// Save the current cursor position using the terminal's own save slot.
+    ///
+    /// The position can later be restored with [`restore_cursor_position`], without having to
+    /// query it first. Terminals have a single save slot, so saving again replaces the previously
+    /// saved position. This is useful when writing output around a render, such as printing above
+    /// an inline viewport.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::backend::{TestBackend};
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// use ratatui::{backend::Backend, layout::Position};
+    ///
+    /// backend.set_cursor_position(Position { x: 10, y: 20 })?;
+    /// backend.save_cursor_position()?;
+    /// backend.set_cursor_position(Position::ORIGIN)?;
+    /// backend.restore_cursor_position()?;
+    /// assert_eq!(backend.get_cursor_position()?, Position { x: 10, y: 20 });
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the command could not be written or if the backend
+    /// does not support saving the cursor position.
+    ///
+    /// [`restore_cursor_position`]: Self::restore_cursor_position
+    fn save_cursor_position(&mut self) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "save_cursor_position is not supported with this backend",
+        ))
+    }
+
+    /// Move the cursor back to the position saved by [`save_cursor_position`].
+    ///
+    /// If no position was saved, terminals usually move the cursor to the top left corner of the
+    /// screen.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the command could not be written or if the backend
+    /// does not support restoring the cursor position.
+    ///
+    /// [`save_cursor_position`]: Self::save_cursor_position
+    fn restore_cursor_position(&mut self) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "restore_cursor_position is not supported with this backend",
+        ))
+    }
+
     /// Get the current cursor position on the terminal screen.
     ///
     /// The returned tuple contains the x and y coordinates of the cursor. The origin
@@ -310,9 +424,112 @@ pub trait Backend {
     /// syscall, and the user is also most likely to need columns and rows along with pixel size.
     fn window_size(&mut self) -> io::Result<WindowSize>;
 
+    /// Returns a channel that receives the new size of the terminal every time it is resized.
+    ///
+    /// This lets an event loop wait for resizes alongside other events instead of polling
+    /// [`size`](Self::size) on every frame. Backends that support it listen for resizes on a
+    /// background thread (e.g. by handling the `SIGWINCH` signal on Unix), which stops when the
+    /// receiver is dropped. Every call creates a new channel. Backends can use
+    /// [`ResizeReceiver::from_sigwinch`] to implement this method on Unix.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an [`io::ErrorKind::Unsupported`] error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::backend::{TestBackend};
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// use ratatui::backend::Backend;
+    ///
+    /// let resizes = backend.resize_rx()?;
+    /// for size in &resizes {
+    ///     println!("resized to {size}");
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the backend does not support resize notifications or
+    /// if the background listener could not be started.
+    fn resize_rx(&mut self) -> io::Result<ResizeReceiver> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "resize_rx is not supported with this backend",
+        ))
+    }
+
+    /// Returns whether the output of the backend is a terminal (TTY).
+    ///
+    /// When the output is redirected to a pipe or a file, an application can use this to fall
+    /// back to plain output instead of enabling raw mode and emitting escape sequences.
+    ///
+    /// The default implementation returns `false`, which is also the answer of backends that do
+    /// not write to a terminal (such as [`TestBackend`]) or that cannot tell. Some backends only
+    /// detect it when asked to, e.g. the Crossterm backend with `CrosstermBackend::detect_tty`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::backend::{TestBackend};
+    /// # let backend = TestBackend::new(80, 25);
+    /// use ratatui::backend::Backend;
+    ///
+    /// if !backend.is_tty() {
+    ///     println!("not a terminal, printing plain output");
+    /// }
+    /// ```
+    fn is_tty(&self) -> bool {
+        false
+    }
+
     /// Flush any buffered content to the terminal screen.
     fn flush(&mut self) -> io::Result<()>;
 
+    /// Write raw bytes directly to the terminal output.
+    ///
+    /// This is an escape hatch for sending escape sequences that are not modeled by the backend,
+    /// such as changing the color palette or querying a terminal specific capability. The bytes
+    /// are written as-is without any interpretation, so it is up to the caller to ensure that they
+    /// do not leave the terminal in a state that conflicts with what has been drawn.
+    ///
+    /// The bytes are written to the same output stream as [`draw`], in call order. Calling this
+    /// before [`Terminal::draw`] sends the bytes ahead of the frame's content, and calling it after
+    /// sends them after the frame's content. Like [`draw`], the bytes may be buffered until the
+    /// next call to [`flush`].
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::backend::{TestBackend};
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// use ratatui::backend::Backend;
+    ///
+    /// // set the terminal window title
+    /// backend.write_raw(b"\x1b]0;My App\x07")?;
+    /// backend.flush()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the bytes could not be written or if the backend does
+    /// not support writing raw bytes.
+    ///
+    /// [`draw`]: Self::draw
+    /// [`flush`]: Self::flush
+    /// [`Terminal::draw`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html#method.draw
+    fn write_raw(&mut self, _bytes: &[u8]) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "write_raw is not supported with this backend",
+        ))
+    }
+
     /// Scroll a region of the screen upwards, where a region is specified by a (half-open) range
     /// of rows.
     ///
@@ -374,10 +591,30 @@ pub trait Backend {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
     use strum::ParseError;
 
     use super::*;
 
+    #[rstest]
+    #[case::exact(Size::new(80, 24), Size::new(640, 480), Some(Size::new(8, 20)))]
+    #[case::rounded_down(Size::new(3, 2), Size::new(20, 33), Some(Size::new(6, 16)))]
+    #[case::unknown_pixels(Size::new(80, 24), Size::new(0, 0), None)]
+    #[case::unknown_pixel_height(Size::new(80, 24), Size::new(640, 0), None)]
+    #[case::smaller_than_a_pixel(Size::new(80, 24), Size::new(40, 480), None)]
+    #[case::no_cells(Size::new(0, 0), Size::new(640, 480), None)]
+    fn window_size_cell_size(
+        #[case] columns_rows: Size,
+        #[case] pixels: Size,
+        #[case] expected: Option<Size>,
+    ) {
+        let window_size = WindowSize {
+            columns_rows,
+            pixels,
+        };
+        assert_eq!(window_size.cell_size(), expected);
+    }
+
     #[test]
     fn clear_type_tostring() {
         assert_eq!(ClearType::All.to_string(), "All");
@@ -0,0 +1,165 @@
+//! This module provides [`ColorMode`], used by backends to adapt the colors of the cells to what
+//! the terminal supports.
+use std::{borrow::Cow, env};
+
+use strum::{Display, EnumString};
+
+use crate::{buffer::Cell, style::Color};
+
+/// The colors a terminal can display, which decides how backends emit the colors of the cells.
+///
+/// Colors that the terminal cannot display are replaced by their closest approximation before
+/// being drawn: [`Color::Rgb`] colors are approximated with [`Color::to_indexed`] in
+/// [`ColorMode::Ansi256`], and [`Color::Rgb`] and [`Color::Indexed`] colors with
+/// [`Color::to_ansi`] in [`ColorMode::Ansi16`]. [`ColorMode::NoColor`] drops all colors while
+/// keeping the modifiers, following the [`NO_COLOR`] convention. Backends that support color modes
+/// expose a `color_mode` method to configure them.
+///
+/// [`ColorMode::detect`] picks the mode from the environment of the application.
+///
+/// [`NO_COLOR`]: https://no-color.org
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::{
+///     backend::ColorMode,
+///     buffer::Cell,
+///     style::{Color, Style},
+/// };
+///
+/// let mut cell = Cell::new("a");
+/// cell.set_style(Style::new().fg(Color::Rgb(255, 0, 0)));
+/// assert_eq!(ColorMode::Ansi256.apply(&cell).fg, Color::Indexed(196));
+/// assert_eq!(ColorMode::Ansi16.apply(&cell).fg, Color::LightRed);
+/// assert_eq!(ColorMode::NoColor.apply(&cell).fg, Color::Reset);
+/// ```
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColorMode {
+    /// All colors are emitted as they are, including 24-bit RGB colors
+    #[default]
+    TrueColor,
+    /// RGB colors are replaced by the closest color of the 256 color palette
+    Ansi256,
+    /// RGB and indexed colors are replaced by the closest of the 16 ANSI colors
+    Ansi16,
+    /// No color is emitted, only the modifiers
+    NoColor,
+}
+
+impl ColorMode {
+    /// Detects the color mode from the environment variables.
+    ///
+    /// - [`ColorMode::NoColor`] when `NO_COLOR` is set to a non-empty value, or `TERM` is `dumb`
+    /// - [`ColorMode::TrueColor`] when `COLORTERM` is `truecolor` or `24bit`
+    /// - [`ColorMode::Ansi256`] when `TERM` contains `256color`
+    /// - [`ColorMode::TrueColor`] otherwise, so colors are emitted unchanged when the environment
+    ///   does not tell what the terminal supports
+    pub fn detect() -> Self {
+        Self::from_env(|name| env::var(name).ok())
+    }
+
+    /// Detects the color mode from the environment variables returned by `var`.
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Self::NoColor;
+        }
+        if var("COLORTERM").is_some_and(|value| value == "truecolor" || value == "24bit") {
+            return Self::TrueColor;
+        }
+        match var("TERM") {
+            Some(term) if term == "dumb" => Self::NoColor,
+            Some(term) if term.contains("256color") => Self::Ansi256,
+            _ => Self::TrueColor,
+        }
+    }
+
+    /// Returns the color that is displayed in this mode instead of `color`.
+    ///
+    /// Returns [`Color::Reset`] in [`ColorMode::NoColor`].
+    pub fn convert(self, color: Color) -> Color {
+        match self {
+            Self::TrueColor => color,
+            Self::Ansi256 => color.to_indexed(),
+            Self::Ansi16 => color.to_ansi(),
+            Self::NoColor => Color::Reset,
+        }
+    }
+
+    /// Returns the cell with its colors converted to this mode.
+    ///
+    /// The cell is only cloned when one of its colors changes.
+    pub fn apply(self, cell: &Cell) -> Cow<'_, Cell> {
+        let fg = self.convert(cell.fg);
+        let bg = self.convert(cell.bg);
+        #[cfg(feature = "underline-color")]
+        let underline_color = self.convert(cell.underline_color);
+        #[cfg(feature = "underline-color")]
+        let unchanged = fg == cell.fg && bg == cell.bg && underline_color == cell.underline_color;
+        #[cfg(not(feature = "underline-color"))]
+        let unchanged = fg == cell.fg && bg == cell.bg;
+        if unchanged {
+            return Cow::Borrowed(cell);
+        }
+        let mut cell = cell.clone();
+        cell.fg = fg;
+        cell.bg = bg;
+        #[cfg(feature = "underline-color")]
+        {
+            cell.underline_color = underline_color;
+        }
+        Cow::Owned(cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::{Modifier, Style, Stylize};
+
+    #[rstest]
+    #[case::nothing(&[], ColorMode::TrueColor)]
+    #[case::no_color(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")], ColorMode::NoColor)]
+    #[case::empty_no_color(&[("NO_COLOR", ""), ("TERM", "xterm-256color")], ColorMode::Ansi256)]
+    #[case::truecolor(&[("COLORTERM", "truecolor"), ("TERM", "xterm-256color")], ColorMode::TrueColor)]
+    #[case::colorterm_24bit(&[("COLORTERM", "24bit")], ColorMode::TrueColor)]
+    #[case::term_256color(&[("TERM", "screen-256color")], ColorMode::Ansi256)]
+    #[case::dumb(&[("TERM", "dumb")], ColorMode::NoColor)]
+    #[case::unknown_term(&[("TERM", "xterm")], ColorMode::TrueColor)]
+    fn from_env(#[case] vars: &[(&str, &str)], #[case] expected: ColorMode) {
+        let var = |name: &str| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        };
+        assert_eq!(ColorMode::from_env(var), expected);
+    }
+
+    #[rstest]
+    #[case::true_color(ColorMode::TrueColor, Color::Rgb(255, 128, 0))]
+    #[case::ansi256(ColorMode::Ansi256, Color::Indexed(208))]
+    #[case::ansi16(ColorMode::Ansi16, Color::Yellow)]
+    #[case::no_color(ColorMode::NoColor, Color::Reset)]
+    fn convert(#[case] mode: ColorMode, #[case] expected: Color) {
+        assert_eq!(mode.convert(Color::Rgb(255, 128, 0)), expected);
+    }
+
+    #[test]
+    fn apply_keeps_modifiers() {
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().red().on_blue().bold());
+        let cell = ColorMode::NoColor.apply(&cell);
+        assert_eq!(cell.fg, Color::Reset);
+        assert_eq!(cell.bg, Color::Reset);
+        assert_eq!(cell.modifier, Modifier::BOLD);
+    }
+
+    #[test]
+    fn apply_borrows_unchanged_cell() {
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().red());
+        assert!(matches!(ColorMode::Ansi16.apply(&cell), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,142 @@
+//! This module provides [`ModifierFallbacks`], used by backends to replace modifiers that a
+//! terminal does not render with an alternative style.
+
+use std::borrow::Cow;
+
+use crate::{
+    buffer::Cell,
+    style::{Modifier, Style},
+};
+
+/// A set of replacement styles for [`Modifier`]s that a terminal does not render.
+///
+/// Some terminals silently ignore modifiers such as [`Modifier::ITALIC`] or
+/// [`Modifier::CROSSED_OUT`]. When an application relies on these modifiers to convey meaning, a
+/// fallback can be configured to swap the modifier for a style the terminal does support (e.g.
+/// italic to underlined) at draw time. Backends that support fallbacks expose a
+/// `modifier_fallback` method to configure them.
+///
+/// Terminals do not reliably report which modifiers they support, so the fallbacks are applied
+/// whenever they are configured. It is up to the application to decide which ones to configure,
+/// for instance based on the `TERM` environment variable or on a user setting.
+///
+/// The fallbacks are applied in the order they were added. Without any fallback, cells are drawn
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::{
+///     backend::ModifierFallbacks,
+///     buffer::Cell,
+///     style::{Modifier, Style, Stylize},
+/// };
+///
+/// let mut fallbacks = ModifierFallbacks::new();
+/// fallbacks.insert(Modifier::ITALIC, Style::new().underlined());
+///
+/// let mut cell = Cell::new("a");
+/// cell.set_style(Style::new().italic().red());
+/// let cell = fallbacks.apply(&cell);
+/// assert_eq!(cell.modifier, Modifier::UNDERLINED);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ModifierFallbacks {
+    fallbacks: Vec<(Modifier, Style)>,
+}
+
+impl ModifierFallbacks {
+    /// Creates an empty set of fallbacks.
+    pub const fn new() -> Self {
+        Self {
+            fallbacks: Vec::new(),
+        }
+    }
+
+    /// Replaces `modifier` with `style` when drawing cells.
+    ///
+    /// The modifier is removed from the cell and the style is then patched onto it. If `modifier`
+    /// contains several modifiers, the fallback is used as soon as the cell has any of them.
+    /// Adding a fallback for a modifier that already has one replaces it.
+    pub fn insert(&mut self, modifier: Modifier, style: Style) {
+        if let Some(fallback) = self.fallbacks.iter_mut().find(|(m, _)| *m == modifier) {
+            fallback.1 = style;
+        } else {
+            self.fallbacks.push((modifier, style));
+        }
+    }
+
+    /// Returns `true` if no fallback is configured.
+    pub fn is_empty(&self) -> bool {
+        self.fallbacks.is_empty()
+    }
+
+    /// Returns the cell with the configured fallbacks applied.
+    ///
+    /// The cell is only cloned when a fallback applies to it.
+    pub fn apply<'a>(&self, cell: &'a Cell) -> Cow<'a, Cell> {
+        let mut cell = Cow::Borrowed(cell);
+        for &(modifier, style) in &self.fallbacks {
+            if cell.modifier.intersects(modifier) {
+                let cell = cell.to_mut();
+                cell.modifier.remove(modifier);
+                cell.set_style(style);
+            }
+        }
+        cell
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::{Color, Stylize};
+
+    #[rstest]
+    #[case::italic(Style::new().italic(), Style::new().underlined())]
+    #[case::crossed_out(Style::new().crossed_out(), Style::new().dim())]
+    #[case::both(Style::new().italic().crossed_out(), Style::new().underlined().dim())]
+    #[case::keeps_colors(Style::new().italic().red(), Style::new().red().underlined())]
+    fn apply(#[case] style: Style, #[case] expected: Style) {
+        let mut fallbacks = ModifierFallbacks::new();
+        fallbacks.insert(Modifier::ITALIC, Style::new().underlined());
+        fallbacks.insert(Modifier::CROSSED_OUT, Style::new().dim());
+        let mut cell = Cell::new("a");
+        cell.set_style(style);
+        let mut expected_cell = Cell::new("a");
+        expected_cell.set_style(expected);
+        assert_eq!(*fallbacks.apply(&cell), expected_cell);
+    }
+
+    #[test]
+    fn insert_replaces_fallback() {
+        let mut fallbacks = ModifierFallbacks::new();
+        fallbacks.insert(Modifier::ITALIC, Style::new().underlined());
+        fallbacks.insert(Modifier::ITALIC, Style::new().fg(Color::Blue));
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().italic().red());
+        let mut expected = Cell::new("a");
+        expected.set_style(Style::new().blue());
+        assert_eq!(*fallbacks.apply(&cell), expected);
+    }
+
+    #[test]
+    fn unaffected_cell_is_borrowed() {
+        let mut fallbacks = ModifierFallbacks::new();
+        fallbacks.insert(Modifier::ITALIC, Style::new().underlined());
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().bold());
+        assert!(matches!(fallbacks.apply(&cell), Cow::Borrowed(c) if *c == cell));
+    }
+
+    #[test]
+    fn empty() {
+        let fallbacks = ModifierFallbacks::default();
+        assert!(fallbacks.is_empty());
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().italic());
+        assert!(matches!(fallbacks.apply(&cell), Cow::Borrowed(c) if *c == cell));
+    }
+}
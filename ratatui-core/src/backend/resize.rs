@@ -0,0 +1,164 @@
+//! This module provides [`ResizeReceiver`], the channel returned by [`Backend::resize_rx`].
+//!
+//! [`Backend::resize_rx`]: crate::backend::Backend::resize_rx
+use std::{sync::mpsc, time::Duration};
+
+use crate::layout::Size;
+
+/// A channel that receives the new size of the terminal every time it is resized.
+///
+/// This is returned by [`Backend::resize_rx`] and [`Terminal::resize_rx`]. Backends usually
+/// listen for resizes on a background thread, which is stopped when the `ResizeReceiver` is
+/// dropped.
+///
+/// With the `resize-channel` feature on Unix, [`ResizeReceiver::from_sigwinch`] listens for the
+/// `SIGWINCH` signal, which backends can use to implement [`Backend::resize_rx`].
+///
+/// [`Backend::resize_rx`]: crate::backend::Backend::resize_rx
+/// [`Terminal::resize_rx`]: crate::terminal::Terminal::resize_rx
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::mpsc;
+///
+/// use ratatui_core::{backend::ResizeReceiver, layout::Size};
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let resizes = ResizeReceiver::new(receiver);
+/// sender.send(Size::new(80, 24)).unwrap();
+/// assert_eq!(resizes.recv(), Ok(Size::new(80, 24)));
+/// ```
+#[derive(Debug)]
+pub struct ResizeReceiver {
+    receiver: mpsc::Receiver<Size>,
+    /// Stops the signal listener when the receiver is dropped
+    #[cfg(all(unix, feature = "resize-channel"))]
+    signals: Option<signal_hook::iterator::Handle>,
+}
+
+impl ResizeReceiver {
+    /// Creates a `ResizeReceiver` from the receiving half of a channel.
+    pub const fn new(receiver: mpsc::Receiver<Size>) -> Self {
+        Self {
+            receiver,
+            #[cfg(all(unix, feature = "resize-channel"))]
+            signals: None,
+        }
+    }
+
+    /// Listens for the `SIGWINCH` signal on a background thread and sends the size returned by
+    /// `size` every time it is received.
+    ///
+    /// Sizes that cannot be read are skipped. The signal handler is removed and the thread stops
+    /// when the `ResizeReceiver` is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signal handler could not be registered or the thread could not be
+    /// spawned.
+    #[cfg(all(unix, feature = "resize-channel"))]
+    pub fn from_sigwinch<F>(size: F) -> std::io::Result<Self>
+    where
+        F: Fn() -> std::io::Result<Size> + Send + 'static,
+    {
+        use signal_hook::{consts::SIGWINCH, iterator::Signals};
+
+        let mut signals = Signals::new([SIGWINCH])?;
+        let handle = signals.handle();
+        let (sender, receiver) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("ratatui-resize".to_string())
+            .spawn(move || {
+                for _ in signals.forever() {
+                    let Ok(size) = size() else {
+                        continue;
+                    };
+                    if sender.send(size).is_err() {
+                        break;
+                    }
+                }
+            })?;
+        Ok(Self {
+            receiver,
+            signals: Some(handle),
+        })
+    }
+
+    /// Blocks until the terminal is resized and returns its new size.
+    ///
+    /// Returns an error when the sending half is gone, e.g. when the listener failed.
+    pub fn recv(&self) -> Result<Size, mpsc::RecvError> {
+        self.receiver.recv()
+    }
+
+    /// Returns the new size of the terminal if it was resized, without blocking.
+    pub fn try_recv(&self) -> Result<Size, mpsc::TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    /// Waits at most `timeout` for the terminal to be resized and returns its new size.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Size, mpsc::RecvTimeoutError> {
+        self.receiver.recv_timeout(timeout)
+    }
+
+    /// Returns an iterator over the sizes of the terminal, blocking until the next resize.
+    pub fn iter(&self) -> mpsc::Iter<'_, Size> {
+        self.receiver.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ResizeReceiver {
+    type Item = Size;
+    type IntoIter = mpsc::Iter<'a, Size>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(all(unix, feature = "resize-channel"))]
+impl Drop for ResizeReceiver {
+    fn drop(&mut self) {
+        if let Some(signals) = self.signals.take() {
+            signals.close();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let (sender, receiver) = mpsc::channel();
+        let resizes = ResizeReceiver::new(receiver);
+        assert_eq!(resizes.try_recv(), Err(mpsc::TryRecvError::Empty));
+        sender.send(Size::new(10, 5)).unwrap();
+        drop(sender);
+        assert_eq!(resizes.iter().collect::<Vec<_>>(), [Size::new(10, 5)]);
+    }
+
+    #[cfg(all(unix, feature = "resize-channel"))]
+    #[test]
+    fn from_sigwinch() {
+        use signal_hook::{consts::SIGWINCH, low_level::raise};
+
+        let resizes = ResizeReceiver::from_sigwinch(|| Ok(Size::new(80, 24))).unwrap();
+        raise(SIGWINCH).unwrap();
+        assert_eq!(
+            resizes.recv_timeout(Duration::from_secs(5)),
+            Ok(Size::new(80, 24))
+        );
+    }
+
+    #[cfg(all(unix, feature = "resize-channel"))]
+    #[test]
+    fn from_sigwinch_stops_when_dropped() {
+        let resizes = ResizeReceiver::from_sigwinch(|| Ok(Size::new(80, 24))).unwrap();
+        let signals = resizes.signals.clone().unwrap();
+        drop(resizes);
+        assert!(signals.is_closed());
+    }
+}
@@ -6,12 +6,12 @@ use std::{
     io, iter,
 };
 
-use unicode_width::UnicodeWidthStr;
-
 use crate::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{Backend, ClearType, ColorMode, ModifierFallbacks, WindowSize},
     buffer::{Buffer, Cell},
     layout::{Position, Rect, Size},
+    style::{Modifier, Style},
+    text::WidthMode,
 };
 
 /// A [`Backend`] implementation used for integration testing that renders to an memory buffer.
@@ -38,6 +38,51 @@ pub struct TestBackend {
     scrollback: Buffer,
     cursor: bool,
     pos: (u16, u16),
+    saved_pos: Option<(u16, u16)>,
+    raw: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ops: Option<Vec<BackendOp>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    modifier_fallbacks: ModifierFallbacks,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    color_mode: ColorMode,
+}
+
+/// An operation performed on a [`TestBackend`], recorded when [`TestBackend::recording_ops`] is
+/// used.
+///
+/// Only the operations that produce output are recorded, queries such as [`Backend::size`] or
+/// [`Backend::get_cursor_position`] are not.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum BackendOp {
+    /// [`Backend::draw`] with the drawn cells and their `(x, y)` positions
+    Draw(Vec<(u16, u16, Cell)>),
+    /// [`Backend::hide_cursor`]
+    HideCursor,
+    /// [`Backend::show_cursor`]
+    ShowCursor,
+    /// [`Backend::set_cursor_position`]
+    SetCursorPosition(Position),
+    /// [`Backend::save_cursor_position`]
+    SaveCursorPosition,
+    /// [`Backend::restore_cursor_position`]
+    RestoreCursorPosition,
+    /// [`Backend::clear`]
+    Clear,
+    /// [`Backend::clear_region`]
+    ClearRegion(ClearType),
+    /// [`Backend::append_lines`] with the number of lines
+    AppendLines(u16),
+    /// [`Backend::write_raw`] with the written bytes
+    WriteRaw(Vec<u8>),
+    /// [`Backend::flush`]
+    Flush,
+    /// `Backend::scroll_region_up` with the region and the number of lines to scroll by
+    #[cfg(feature = "scrolling-regions")]
+    ScrollRegionUp(std::ops::Range<u16>, u16),
+    /// `Backend::scroll_region_down` with the region and the number of lines to scroll by
+    #[cfg(feature = "scrolling-regions")]
+    ScrollRegionDown(std::ops::Range<u16>, u16),
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -48,6 +93,7 @@ pub struct TestBackend {
 /// displayed at the end of the line.
 fn buffer_view(buffer: &Buffer) -> String {
     let mut view = String::with_capacity(buffer.content.len() + buffer.area.height as usize * 3);
+    let width_mode = WidthMode::current();
     for cells in buffer.content.chunks(buffer.area.width as usize) {
         let mut overwritten = vec![];
         let mut skip: usize = 0;
@@ -58,7 +104,7 @@ fn buffer_view(buffer: &Buffer) -> String {
             } else {
                 overwritten.push((x, c.symbol()));
             }
-            skip = std::cmp::max(skip, c.symbol().width()).saturating_sub(1);
+            skip = std::cmp::max(skip, width_mode.width(c.symbol())).saturating_sub(1);
         }
         view.push('"');
         if !overwritten.is_empty() {
@@ -77,6 +123,11 @@ impl TestBackend {
             scrollback: Buffer::empty(Rect::new(0, 0, width, 0)),
             cursor: false,
             pos: (0, 0),
+            saved_pos: None,
+            raw: Vec::new(),
+            ops: None,
+            modifier_fallbacks: ModifierFallbacks::default(),
+            color_mode: ColorMode::TrueColor,
         }
     }
 
@@ -99,6 +150,11 @@ impl TestBackend {
             scrollback,
             cursor: false,
             pos: (0, 0),
+            saved_pos: None,
+            raw: Vec::new(),
+            ops: None,
+            modifier_fallbacks: ModifierFallbacks::default(),
+            color_mode: ColorMode::TrueColor,
         }
     }
 
@@ -107,6 +163,30 @@ impl TestBackend {
         &self.buffer
     }
 
+    /// Returns a copy of the cells of the internal buffer in the given `area`.
+    ///
+    /// The area is clipped to the buffer, and the returned buffer keeps the coordinates of the
+    /// cells, so it can be compared to a buffer created with `Buffer::with_lines` and moved to
+    /// the area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{backend::TestBackend, layout::Rect};
+    ///
+    /// let backend = TestBackend::with_lines(["abc", "def"]);
+    /// let region = backend.buffer_region(Rect::new(1, 1, 2, 1));
+    /// assert_eq!(region.content()[0].symbol(), "e");
+    /// ```
+    pub fn buffer_region(&self, area: Rect) -> Buffer {
+        let area = area.intersection(self.buffer.area);
+        let mut region = Buffer::empty(area);
+        for position in area.positions() {
+            region[position] = self.buffer[position].clone();
+        }
+        region
+    }
+
     /// Returns a reference to the internal scrollback buffer of the `TestBackend`.
     ///
     /// The scrollback buffer represents the part of the screen that is currently hidden from view,
@@ -124,6 +204,129 @@ impl TestBackend {
         &self.scrollback
     }
 
+    /// Returns the bytes written with [`Backend::write_raw`], in the order they were written.
+    ///
+    /// Raw bytes are not interpreted, so they have no effect on the buffer.
+    pub fn raw_output(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// Replaces `modifier` with `style` in the cells drawn from now on.
+    ///
+    /// This behaves like the `modifier_fallback` method of the terminal backends, which makes it
+    /// possible to check the effect of a fallback in tests. The substituted style is what ends up
+    /// in the buffer and in the recorded [`BackendOp::Draw`] operations. See
+    /// [`ModifierFallbacks`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     backend::{Backend, TestBackend},
+    ///     buffer::Cell,
+    ///     style::{Modifier, Style},
+    /// };
+    ///
+    /// let mut backend = TestBackend::new(1, 1).modifier_fallback(
+    ///     Modifier::ITALIC,
+    ///     Style::new().add_modifier(Modifier::UNDERLINED),
+    /// );
+    /// let mut cell = Cell::new("a");
+    /// cell.set_style(Style::new().add_modifier(Modifier::ITALIC));
+    /// backend.draw([(0, 0, &cell)].into_iter())?;
+    /// assert_eq!(backend.buffer()[(0, 0)].modifier, Modifier::UNDERLINED);
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn modifier_fallback(mut self, modifier: Modifier, style: Style) -> Self {
+        self.modifier_fallbacks.insert(modifier, style);
+        self
+    }
+
+    /// Converts the colors of the cells drawn from now on to the given [`ColorMode`].
+    ///
+    /// This behaves like the `color_mode` method of the terminal backends, except that the mode is
+    /// never detected from the environment: it is [`ColorMode::TrueColor`] by default, which keeps
+    /// the colors unchanged. The converted colors are what ends up in the buffer and in the
+    /// recorded [`BackendOp::Draw`] operations, so with [`ColorMode::NoColor`] no color is drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     backend::{Backend, ColorMode, TestBackend},
+    ///     buffer::Cell,
+    ///     style::{Color, Style},
+    /// };
+    ///
+    /// let mut backend = TestBackend::new(1, 1).color_mode(ColorMode::Ansi256);
+    /// let mut cell = Cell::new("a");
+    /// cell.set_style(Style::new().fg(Color::Rgb(255, 0, 0)));
+    /// backend.draw([(0, 0, &cell)].into_iter())?;
+    /// assert_eq!(backend.buffer()[(0, 0)].fg, Color::Indexed(196));
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
+
+    /// Records the operations performed on the backend from now on.
+    ///
+    /// The recorded [`BackendOp`]s can be inspected with [`TestBackend::ops`] and drained with
+    /// [`TestBackend::take_ops`]. This makes it possible to assert what was sent to the backend
+    /// and in which order (e.g. how many cells were drawn or whether the cursor was moved), rather
+    /// than only the resulting screen.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::backend::{Backend, BackendOp, TestBackend};
+    ///
+    /// let mut backend = TestBackend::new(10, 2).recording_ops();
+    /// backend.clear()?;
+    /// backend.set_cursor_position((1, 1))?;
+    /// backend.flush()?;
+    /// assert_eq!(
+    ///     backend.take_ops(),
+    ///     [
+    ///         BackendOp::Clear,
+    ///         BackendOp::SetCursorPosition((1, 1).into()),
+    ///         BackendOp::Flush,
+    ///     ]
+    /// );
+    /// assert!(backend.ops().is_empty());
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn recording_ops(mut self) -> Self {
+        self.ops.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// Returns the operations recorded since recording started or since the last call to
+    /// [`TestBackend::take_ops`].
+    ///
+    /// This is empty unless the backend was created with [`TestBackend::recording_ops`].
+    pub fn ops(&self) -> &[BackendOp] {
+        self.ops.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the recorded operations and clears the log.
+    ///
+    /// Recording continues afterwards. This is empty unless the backend was created with
+    /// [`TestBackend::recording_ops`].
+    pub fn take_ops(&mut self) -> Vec<BackendOp> {
+        self.ops.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    fn record(&mut self, op: BackendOp) {
+        if let Some(ops) = self.ops.as_mut() {
+            ops.push(op);
+        }
+    }
+
     /// Resizes the `TestBackend` to the specified width and height.
     pub fn resize(&mut self, width: u16, height: u16) {
         self.buffer.resize(Rect::new(0, 0, width, height));
@@ -211,6 +414,28 @@ impl TestBackend {
         self.assert_scrollback(&Buffer::with_lines(expected));
     }
 
+    /// Asserts that every cell of the `TestBackend`'s buffer in `area` has the expected style.
+    ///
+    /// The expected style is applied to a default cell before the comparison, like
+    /// [`Buffer::set_style`] does, so the colors and modifiers that it does not set are expected to
+    /// be reset. The area is clipped to the buffer.
+    ///
+    /// # Panics
+    ///
+    /// When a cell has another style, a panic occurs with a message showing the position of the
+    /// first mismatching cell and its actual style.
+    #[track_caller]
+    pub fn assert_styles(&self, area: Rect, expected: Style) {
+        let expected = Cell::EMPTY.clone().set_style(expected).style();
+        for position in area.intersection(self.buffer.area).positions() {
+            let actual = self.buffer[position].style();
+            assert_eq!(
+                actual, expected,
+                "style of the cell at {position} does not match"
+            );
+        }
+    }
+
     /// Asserts that the `TestBackend`'s cursor position is equal to the expected one.
     ///
     /// This is a shortcut for `assert_eq!(self.get_cursor_position().unwrap(), expected)`.
@@ -239,18 +464,29 @@ impl Backend for TestBackend {
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
+        let mut drawn = self.ops.as_ref().map(|_| Vec::new());
         for (x, y, c) in content {
-            self.buffer[(x, y)] = c.clone();
+            let c = self.modifier_fallbacks.apply(c);
+            let c = self.color_mode.apply(&c).into_owned();
+            if let Some(drawn) = drawn.as_mut() {
+                drawn.push((x, y, c.clone()));
+            }
+            self.buffer[(x, y)] = c;
+        }
+        if let Some(drawn) = drawn {
+            self.record(BackendOp::Draw(drawn));
         }
         Ok(())
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
+        self.record(BackendOp::HideCursor);
         self.cursor = false;
         Ok(())
     }
 
     fn show_cursor(&mut self) -> io::Result<()> {
+        self.record(BackendOp::ShowCursor);
         self.cursor = true;
         Ok(())
     }
@@ -260,18 +496,38 @@ impl Backend for TestBackend {
     }
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
-        self.pos = position.into().into();
+        let position = position.into();
+        self.record(BackendOp::SetCursorPosition(position));
+        self.pos = position.into();
+        Ok(())
+    }
+
+    fn save_cursor_position(&mut self) -> io::Result<()> {
+        self.record(BackendOp::SaveCursorPosition);
+        self.saved_pos = Some(self.pos);
+        Ok(())
+    }
+
+    /// Moves the cursor to the saved position, or to the top left corner if no position was saved.
+    fn restore_cursor_position(&mut self) -> io::Result<()> {
+        self.record(BackendOp::RestoreCursorPosition);
+        self.pos = self.saved_pos.unwrap_or_default();
         Ok(())
     }
 
     fn clear(&mut self) -> io::Result<()> {
+        self.record(BackendOp::Clear);
         self.buffer.reset();
         Ok(())
     }
 
     fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.record(BackendOp::ClearRegion(clear_type));
         let region = match clear_type {
-            ClearType::All => return self.clear(),
+            ClearType::All => {
+                self.buffer.reset();
+                return Ok(());
+            }
             ClearType::AfterCursor => {
                 let index = self.buffer.index_of(self.pos.0, self.pos.1) + 1;
                 &mut self.buffer.content[index..]
@@ -310,7 +566,8 @@ impl Backend for TestBackend {
     /// case but this limit is instead replaced with scrolling in most backend implementations) will
     /// be added after the current position and the cursor will be moved to the last row.
     fn append_lines(&mut self, line_count: u16) -> io::Result<()> {
-        let Position { x: cur_x, y: cur_y } = self.get_cursor_position()?;
+        self.record(BackendOp::AppendLines(line_count));
+        let (cur_x, cur_y) = self.pos;
         let Rect { width, height, .. } = self.buffer.area;
 
         // the next column ensuring that we don't go past the last column
@@ -341,7 +598,7 @@ impl Backend for TestBackend {
         }
 
         let new_cursor_y = cur_y.saturating_add(line_count).min(max_y);
-        self.set_cursor_position(Position::new(new_cursor_x, new_cursor_y))?;
+        self.pos = (new_cursor_x, new_cursor_y);
 
         Ok(())
     }
@@ -363,11 +620,19 @@ impl Backend for TestBackend {
     }
 
     fn flush(&mut self) -> io::Result<()> {
+        self.record(BackendOp::Flush);
+        Ok(())
+    }
+
+    fn write_raw(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.record(BackendOp::WriteRaw(bytes.to_vec()));
+        self.raw.extend_from_slice(bytes);
         Ok(())
     }
 
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, scroll_by: u16) -> io::Result<()> {
+        self.record(BackendOp::ScrollRegionUp(region.clone(), scroll_by));
         let width: usize = self.buffer.area.width.into();
         let cell_region_start = width * region.start.min(self.buffer.area.height) as usize;
         let cell_region_end = width * region.end.min(self.buffer.area.height) as usize;
@@ -418,6 +683,7 @@ impl Backend for TestBackend {
         region: std::ops::Range<u16>,
         scroll_by: u16,
     ) -> io::Result<()> {
+        self.record(BackendOp::ScrollRegionDown(region.clone(), scroll_by));
         let width: usize = self.buffer.area.width.into();
         let cell_region_start = width * region.start.min(self.buffer.area.height) as usize;
         let cell_region_end = width * region.end.min(self.buffer.area.height) as usize;
@@ -458,6 +724,7 @@ mod tests {
     use itertools::Itertools as _;
 
     use super::*;
+    use crate::style::Color;
 
     #[test]
     fn new() {
@@ -468,9 +735,20 @@ mod tests {
                 scrollback: Buffer::empty(Rect::new(0, 0, 10, 0)),
                 cursor: false,
                 pos: (0, 0),
+                saved_pos: None,
+                raw: Vec::new(),
+                ops: None,
+                modifier_fallbacks: ModifierFallbacks::default(),
+                color_mode: ColorMode::TrueColor,
             }
         );
     }
+
+    #[test]
+    fn is_tty() {
+        assert!(!TestBackend::new(10, 2).is_tty());
+    }
+
     #[test]
     fn test_buffer_view() {
         let buffer = Buffer::with_lines(["aaaa"; 2]);
@@ -523,6 +801,39 @@ mod tests {
         backend.assert_scrollback_lines(["aaaaaaaaaa"; 2]);
     }
 
+    #[test]
+    fn buffer_region() {
+        let backend = TestBackend::with_lines(["abcd", "efgh", "ijkl"]);
+        let mut expected = Buffer::with_lines(["fgh", "jkl"]);
+        expected.area = Rect::new(1, 1, 3, 2);
+        assert_eq!(backend.buffer_region(Rect::new(1, 1, 5, 5)), expected);
+    }
+
+    #[test]
+    fn assert_styles() {
+        let mut backend = TestBackend::new(4, 2);
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().fg(Color::Red).add_modifier(Modifier::BOLD));
+        backend
+            .draw([(1, 0, &cell), (2, 0, &cell)].into_iter())
+            .unwrap();
+        backend.assert_styles(
+            Rect::new(1, 0, 2, 1),
+            Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+        );
+        backend.assert_styles(Rect::new(0, 1, 10, 10), Style::new());
+    }
+
+    #[test]
+    #[should_panic = "style of the cell at (2, 0) does not match"]
+    fn assert_styles_panics() {
+        let mut backend = TestBackend::new(4, 2);
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().fg(Color::Red));
+        backend.draw([(1, 0, &cell)].into_iter()).unwrap();
+        backend.assert_styles(Rect::new(1, 0, 3, 1), Style::new().fg(Color::Red));
+    }
+
     #[test]
     fn display() {
         let backend = TestBackend::new(10, 2);
@@ -538,6 +849,68 @@ mod tests {
         backend.assert_buffer_lines(["a         "; 2]);
     }
 
+    #[test]
+    fn draw_with_modifier_fallback() {
+        let mut backend = TestBackend::new(2, 1)
+            .modifier_fallback(
+                Modifier::ITALIC,
+                Style::new().add_modifier(Modifier::UNDERLINED),
+            )
+            .modifier_fallback(
+                Modifier::CROSSED_OUT,
+                Style::new().add_modifier(Modifier::DIM),
+            )
+            .recording_ops();
+        let mut italic = Cell::new("a");
+        italic.set_style(Style::new().add_modifier(Modifier::ITALIC | Modifier::BOLD));
+        let mut crossed_out = Cell::new("b");
+        crossed_out.set_style(Style::new().add_modifier(Modifier::CROSSED_OUT));
+        backend
+            .draw([(0, 0, &italic), (1, 0, &crossed_out)].into_iter())
+            .unwrap();
+
+        let mut expected = Buffer::with_lines(["ab"]);
+        expected[(0, 0)].modifier = Modifier::UNDERLINED | Modifier::BOLD;
+        expected[(1, 0)].modifier = Modifier::DIM;
+        backend.assert_buffer(&expected);
+        assert_eq!(
+            backend.ops(),
+            [BackendOp::Draw(vec![
+                (0, 0, expected[(0, 0)].clone()),
+                (1, 0, expected[(1, 0)].clone()),
+            ])]
+        );
+    }
+
+    #[test]
+    fn draw_without_color() {
+        let mut backend = TestBackend::new(2, 1)
+            .color_mode(ColorMode::NoColor)
+            .recording_ops();
+        let mut red = Cell::new("a");
+        red.set_style(
+            Style::new()
+                .fg(Color::Rgb(255, 0, 0))
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut indexed = Cell::new("b");
+        indexed.set_style(Style::new().bg(Color::Indexed(42)));
+        backend
+            .draw([(0, 0, &red), (1, 0, &indexed)].into_iter())
+            .unwrap();
+
+        let mut expected = Buffer::with_lines(["ab"]);
+        expected[(0, 0)].modifier = Modifier::BOLD;
+        backend.assert_buffer(&expected);
+        assert_eq!(
+            backend.ops(),
+            [BackendOp::Draw(vec![
+                (0, 0, expected[(0, 0)].clone()),
+                (1, 0, expected[(1, 0)].clone()),
+            ])]
+        );
+    }
+
     #[test]
     fn hide_cursor() {
         let mut backend = TestBackend::new(10, 2);
@@ -564,6 +937,26 @@ mod tests {
         backend.assert_cursor_position(Position::ORIGIN);
     }
 
+    #[test]
+    fn save_and_restore_cursor_position() {
+        let mut backend = TestBackend::new(10, 10);
+        backend.restore_cursor_position().unwrap();
+        backend.assert_cursor_position(Position::ORIGIN);
+
+        backend.set_cursor_position((5, 5)).unwrap();
+        backend.save_cursor_position().unwrap();
+        backend.set_cursor_position((1, 2)).unwrap();
+        backend.restore_cursor_position().unwrap();
+        backend.assert_cursor_position((5, 5));
+
+        // saving again replaces the single saved position
+        backend.set_cursor_position((3, 4)).unwrap();
+        backend.save_cursor_position().unwrap();
+        backend.set_cursor_position(Position::ORIGIN).unwrap();
+        backend.restore_cursor_position().unwrap();
+        backend.assert_cursor_position((3, 4));
+    }
+
     #[test]
     fn set_cursor_position() {
         let mut backend = TestBackend::new(10, 10);
@@ -998,6 +1391,66 @@ mod tests {
         backend.flush().unwrap();
     }
 
+    #[test]
+    fn write_raw() {
+        let mut backend = TestBackend::new(10, 2);
+        backend.write_raw(b"\x1b]0;").unwrap();
+        backend.write_raw(b"title\x07").unwrap();
+        assert_eq!(backend.raw_output(), b"\x1b]0;title\x07");
+        backend.assert_buffer_lines(["          "; 2]);
+    }
+
+    #[test]
+    fn ops_are_not_recorded_by_default() {
+        let mut backend = TestBackend::new(10, 2);
+        backend.clear().unwrap();
+        backend.flush().unwrap();
+        assert!(backend.ops().is_empty());
+        assert!(backend.take_ops().is_empty());
+    }
+
+    #[test]
+    fn recording_ops() {
+        let mut backend = TestBackend::new(4, 2).recording_ops();
+        let cell = Cell::new("a");
+        backend.hide_cursor().unwrap();
+        backend
+            .draw([(0, 0, &cell), (1, 1, &cell)].into_iter())
+            .unwrap();
+        backend.clear_region(ClearType::All).unwrap();
+        backend.append_lines(1).unwrap();
+        backend.set_cursor_position((1, 0)).unwrap();
+        backend.show_cursor().unwrap();
+        backend.write_raw(b"\x07").unwrap();
+        backend.flush().unwrap();
+        // queries are not recorded
+        backend.size().unwrap();
+        backend.get_cursor_position().unwrap();
+        assert_eq!(
+            backend.ops(),
+            [
+                BackendOp::HideCursor,
+                BackendOp::Draw(vec![(0, 0, cell.clone()), (1, 1, cell)]),
+                BackendOp::ClearRegion(ClearType::All),
+                BackendOp::AppendLines(1),
+                BackendOp::SetCursorPosition(Position::new(1, 0)),
+                BackendOp::ShowCursor,
+                BackendOp::WriteRaw(b"\x07".to_vec()),
+                BackendOp::Flush,
+            ]
+        );
+    }
+
+    #[test]
+    fn take_ops_drains_the_log() {
+        let mut backend = TestBackend::new(4, 2).recording_ops();
+        backend.clear().unwrap();
+        assert_eq!(backend.take_ops(), [BackendOp::Clear]);
+        assert!(backend.ops().is_empty());
+        backend.flush().unwrap();
+        assert_eq!(backend.take_ops(), [BackendOp::Flush]);
+    }
+
     #[cfg(feature = "scrolling-regions")]
     mod scrolling_regions {
         use rstest::rstest;
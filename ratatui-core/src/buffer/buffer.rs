@@ -4,13 +4,12 @@ use std::{
 };
 
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 use crate::{
     buffer::Cell,
     layout::{Position, Rect},
     style::Style,
-    text::{Line, Span},
+    text::{Line, Span, TextOverflow, WidthMode},
 };
 
 /// A buffer that maps to the desired content of the terminal after the draw call
@@ -329,6 +328,9 @@ impl Buffer {
     /// Print at most the first n characters of a string if enough space is available
     /// until the end of the line. Skips zero-width graphemes and control characters.
     ///
+    /// Returns the position just past the last written cell, accounting for the display width of
+    /// the graphemes and for clipping. The returned `x` is clamped to the right edge of the buffer.
+    ///
     /// Use [`Buffer::set_string`] when the maximum amount of characters can be printed.
     pub fn set_stringn<T, S>(
         &mut self,
@@ -344,9 +346,10 @@ impl Buffer {
     {
         let max_width = max_width.try_into().unwrap_or(u16::MAX);
         let mut remaining_width = self.area.right().saturating_sub(x).min(max_width);
+        let width_mode = WidthMode::current();
         let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true)
             .filter(|symbol| !symbol.contains(char::is_control))
-            .map(|symbol| (symbol, symbol.width() as u16))
+            .map(|symbol| (symbol, width_mode.width(symbol) as u16))
             .filter(|(_symbol, width)| *width > 0)
             .map_while(|(symbol, width)| {
                 remaining_width = remaining_width.checked_sub(width)?;
@@ -363,10 +366,14 @@ impl Buffer {
                 x += 1;
             }
         }
-        (x, y)
+        (x.min(self.area.right()), y)
     }
 
     /// Print a line, starting at the position (x, y)
+    ///
+    /// Returns the position just past the last written cell, accounting for the display width of
+    /// the graphemes and for clipping. The returned `x` is clamped to the right edge of the buffer,
+    /// so it can be used as the starting position of the next piece of content on the same row.
     pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
         let mut remaining_width = max_width;
         let mut x = x;
@@ -388,7 +395,53 @@ impl Buffer {
         (x, y)
     }
 
+    /// Print a line, starting at the position (x, y), ending it with an ellipsis (`…`) if it does
+    /// not fit in `max_width` columns
+    ///
+    /// When the line is wider than `max_width`, the graphemes that fit in `max_width - 1` columns
+    /// are printed, followed by the ellipsis. The line is cut on a grapheme boundary, so the
+    /// ellipsis directly follows the last grapheme and may leave the last column empty when a
+    /// wide grapheme does not fit. The ellipsis uses the style of the last printed span. A line
+    /// that fits is printed as with [`Buffer::set_line`].
+    ///
+    /// `max_width` is first limited to the columns left before the right edge of the buffer, so
+    /// that a line clipped by the buffer also ends with the ellipsis.
+    ///
+    /// Returns the number of columns written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, text::Line};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+    /// let width = buffer.set_line_ellipsis(0, 0, &Line::from("Hello world"), 8);
+    /// assert_eq!(width, 8);
+    /// assert_eq!(buffer, Buffer::with_lines(["Hello w…"]));
+    /// ```
+    pub fn set_line_ellipsis(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> u16 {
+        let max_width = max_width.min(self.area.right().saturating_sub(x));
+        let line = TextOverflow::Ellipsis("…").truncate(line, max_width);
+        let (end, _) = self.set_line(x, y, &line, max_width);
+        end.saturating_sub(x)
+    }
+
     /// Print a span, starting at the position (x, y)
+    ///
+    /// Returns the position just past the last written cell, accounting for the display width of
+    /// the graphemes and for clipping. The returned `x` is clamped to the right edge of the buffer,
+    /// so it can be used as the starting position of the next piece of content on the same row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, style::Stylize, text::Span};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// let (x, y) = buffer.set_span(0, 0, &Span::raw("key: ").bold(), 10);
+    /// let (x, _) = buffer.set_span(x, y, &Span::raw("value"), 10 - x);
+    /// assert_eq!(x, 10);
+    /// ```
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
         self.set_stringn(x, y, &span.content, max_width as usize, span.style)
     }
@@ -460,9 +513,38 @@ impl Buffer {
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
     /// self to other.
     ///
+    /// This is what [`Terminal`] uses to only send the changed cells to the [`Backend`], and it
+    /// can be used to implement alternative renderers or to inspect what changed between two
+    /// frames.
+    ///
+    /// Each update is the `(x, y)` position of a changed cell and the new [`Cell`] from `other`.
+    /// The updates are returned in row-major order (left to right, then top to bottom), which is
+    /// the order a backend draws them in: consecutive cells of a row can be written without
+    /// moving the cursor. Unchanged cells are not returned, except for the cells following a
+    /// multi-width character that was replaced (see below).
+    ///
+    /// Both buffers are expected to have the same area. The positions are computed from the area
+    /// of `self`.
+    ///
+    /// See [`Buffer::diff_iter`] for a variant that returns the updates lazily instead of
+    /// allocating a vector.
+    ///
     /// We're assuming that buffers are well-formed, that is no double-width cell is followed by
     /// a non-blank cell.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    ///
+    /// let previous = Buffer::with_lines(["abc", "def"]);
+    /// let next = Buffer::with_lines(["abX", "Yef"]);
+    /// let updates = previous.diff(&next);
+    /// let positions: Vec<_> = updates.iter().map(|(x, y, _)| (*x, *y)).collect();
+    /// assert_eq!(positions, [(2, 0), (0, 1)]);
+    /// assert_eq!(updates[0].2.symbol(), "X");
+    /// ```
+    ///
     /// # Multi-width characters handling:
     ///
     /// ```text
@@ -485,28 +567,65 @@ impl Buffer {
     /// Next:    `aコ`
     /// Updates: `0: a, 1: コ` (double width symbol at index 1 - skip index 2)
     /// ```
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    /// [`Backend`]: crate::backend::Backend
     pub fn diff<'a>(&self, other: &'a Self) -> Vec<(u16, u16, &'a Cell)> {
-        let previous_buffer = &self.content;
-        let next_buffer = &other.content;
+        self.diff_iter(other).collect()
+    }
 
-        let mut updates: Vec<(u16, u16, &Cell)> = vec![];
+    /// Returns an iterator over the coordinates and Cells necessary to update the UI from self to
+    /// other.
+    ///
+    /// This yields the same updates as [`Buffer::diff`], in the same order, without allocating.
+    /// This is useful for custom backends that compare frames themselves, e.g. to stream the
+    /// changes over the network.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    ///
+    /// let previous = Buffer::with_lines(["a ", "bc"]);
+    /// let next = Buffer::with_lines(["コ", "bd"]);
+    /// let updates: Vec<_> = previous
+    ///     .diff_iter(&next)
+    ///     .map(|(x, y, cell)| (x, y, cell.symbol()))
+    ///     .collect();
+    /// // the cell following the double width symbol is skipped
+    /// assert_eq!(updates, [(0, 0, "コ"), (1, 1, "d")]);
+    /// ```
+    pub fn diff_iter<'s, 'a: 's>(
+        &'s self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = (u16, u16, &'a Cell)> + 's {
         // Cells invalidated by drawing/replacing preceding multi-width characters:
         let mut invalidated: usize = 0;
         // Cells from the current buffer to skip due to preceding multi-width characters taking
         // their place (the skipped cells should be blank anyway), or due to per-cell-skipping:
         let mut to_skip: usize = 0;
-        for (i, (current, previous)) in next_buffer.iter().zip(previous_buffer.iter()).enumerate() {
-            if !current.skip && (current != previous || invalidated > 0) && to_skip == 0 {
-                let (x, y) = self.pos_of(i);
-                updates.push((x, y, &next_buffer[i]));
-            }
-
-            to_skip = current.symbol().width().saturating_sub(1);
-
-            let affected_width = std::cmp::max(current.symbol().width(), previous.symbol().width());
-            invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
-        }
-        updates
+        let width_mode = WidthMode::current();
+        other
+            .content
+            .iter()
+            .zip(self.content.iter())
+            .enumerate()
+            .filter_map(move |(i, (current, previous))| {
+                let update =
+                    (!current.skip && (current != previous || invalidated > 0) && to_skip == 0)
+                        .then(|| {
+                            let (x, y) = self.pos_of(i);
+                            (x, y, current)
+                        });
+
+                let current_width = width_mode.width(current.symbol());
+                to_skip = current_width.saturating_sub(1);
+
+                let affected_width =
+                    std::cmp::max(current_width, width_mode.width(previous.symbol()));
+                invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
+                update
+            })
     }
 }
 
@@ -590,6 +709,7 @@ impl fmt::Debug for Buffer {
         f.write_str(",\n    content: [\n")?;
         let mut last_style = None;
         let mut styles = vec![];
+        let width_mode = WidthMode::current();
         for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
             let mut overwritten = vec![];
             let mut skip: usize = 0;
@@ -600,7 +720,7 @@ impl fmt::Debug for Buffer {
                 } else {
                     overwritten.push((x, c.symbol()));
                 }
-                skip = std::cmp::max(skip, c.symbol().width()).saturating_sub(1);
+                skip = std::cmp::max(skip, width_mode.width(c.symbol())).saturating_sub(1);
                 #[cfg(feature = "underline-color")]
                 {
                     let style = (c.fg, c.bg, c.underline_color, c.modifier);
@@ -650,6 +770,7 @@ mod tests {
 
     use itertools::Itertools;
     use rstest::{fixture, rstest};
+    use unicode_width::UnicodeWidthStr;
 
     use super::*;
     use crate::style::{Color, Modifier, Stylize};
@@ -795,6 +916,20 @@ mod tests {
         assert_eq!(buf.cell_mut(Position::new(10, 10)), None);
     }
 
+    #[rstest]
+    #[case::left((9, 12))]
+    #[case::above((12, 9))]
+    #[case::right((15, 12))]
+    #[case::below((12, 15))]
+    #[case::max((u16::MAX, u16::MAX))]
+    fn cell_outside_offset_area(#[case] position: (u16, u16)) {
+        let mut buf = Buffer::empty(Rect::new(10, 10, 5, 5));
+        assert_eq!(buf.cell(position), None);
+        assert_eq!(buf.cell_mut(position), None);
+        assert!(buf.cell((10, 10)).is_some());
+        assert!(buf.cell_mut((14, 14)).is_some());
+    }
+
     #[test]
     fn index() {
         let buf = Buffer::with_lines(["Hello", "World"]);
@@ -911,6 +1046,84 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["コン "]));
     }
 
+    #[rstest]
+    #[case::narrow(WidthMode::Narrow, (3, 0), ["①", "②", "③", " ", " "])]
+    #[case::wide(WidthMode::Wide, (4, 0), ["①", " ", "②", " ", " "])]
+    fn set_string_ambiguous_width(
+        #[case] mode: WidthMode,
+        #[case] expected_position: (u16, u16),
+        #[case] expected: [&str; 5],
+    ) {
+        WidthMode::set_current(mode);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let position = buffer.set_stringn(0, 0, "①②③", usize::MAX, Style::default());
+        WidthMode::set_current(WidthMode::Narrow);
+        let symbols = buffer.content.iter().map(Cell::symbol).collect_vec();
+        assert_eq!(position, expected_position);
+        assert_eq!(symbols, expected);
+    }
+
+    #[test]
+    fn set_span_chained() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let (x, y) = buffer.set_span(0, 0, &Span::raw("コン"), 8);
+        assert_eq!((x, y), (4, 0));
+        let (x, y) = buffer.set_span(x, y, &Span::raw("abcdef"), 8);
+        assert_eq!((x, y), (8, 0));
+        assert_eq!(buffer, Buffer::with_lines(["コンabcd"]));
+    }
+
+    #[rstest]
+    #[case::clipped_by_max_width(0, 3, 3)]
+    #[case::clipped_by_buffer(2, 10, 5)]
+    #[case::starting_past_the_edge(7, 10, 5)]
+    fn set_line_returns_next_x(#[case] x: u16, #[case] max_width: u16, #[case] expected: u16) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let line = Line::from(vec![Span::raw("ab"), Span::raw("cdef")]);
+        assert_eq!(buffer.set_line(x, 0, &line, max_width), (expected, 0));
+    }
+
+    #[rstest]
+    #[case::fits("abc", 5, "abc  ", 3)]
+    #[case::exact("abcde", 5, "abcde", 5)]
+    #[case::truncated("abcdef", 5, "abcd…", 5)]
+    #[case::wide_grapheme("abcコン", 5, "abc… ", 4)]
+    #[case::one_column("abcdef", 1, "…    ", 1)]
+    #[case::zero_width("abcdef", 0, "     ", 0)]
+    fn set_line_ellipsis(
+        mut small_one_line_buffer: Buffer,
+        #[case] content: &str,
+        #[case] max_width: u16,
+        #[case] expected: &str,
+        #[case] expected_width: u16,
+    ) {
+        let width = small_one_line_buffer.set_line_ellipsis(0, 0, &Line::raw(content), max_width);
+        assert_eq!(width, expected_width);
+        let mut expected_buffer = Buffer::empty(small_one_line_buffer.area);
+        expected_buffer.set_string(0, 0, expected, Style::default());
+        assert_eq!(small_one_line_buffer, expected_buffer);
+    }
+
+    #[test]
+    fn set_line_ellipsis_styled() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let line = Line::from(vec!["abc".red(), "def".blue()]).italic();
+        let width = buffer.set_line_ellipsis(0, 0, &line, 5);
+        assert_eq!(width, 5);
+        let mut expected = Buffer::with_lines(["abcd… "]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().red().italic());
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().blue().italic());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn set_line_ellipsis_clipped_by_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let width = buffer.set_line_ellipsis(3, 0, &Line::raw("abcdef"), 5);
+        assert_eq!(width, 2);
+        assert_eq!(buffer, Buffer::with_lines(["   a…"]));
+    }
+
     #[fixture]
     fn small_one_line_buffer() -> Buffer {
         Buffer::empty(Rect::new(0, 0, 5, 1))
@@ -1121,6 +1334,19 @@ mod tests {
         assert_eq!(diff, [(0, 0, &Cell::new("4"))],);
     }
 
+    #[rstest]
+    #[case::single_width(["┌Title─┐", "└──────┘"], ["┌TITLE─┐", "└──────┘"])]
+    #[case::multi_width(["┌Title─┐", "└──────┘"], ["┌称号──┐", "└──────┘"])]
+    #[case::multi_width_offset(["┌称号──┐", "└──────┘"], ["┌─称号─┐", "└──────┘"])]
+    #[case::multi_width_replaced(["称号", "ab"], ["abcd", "ab"])]
+    fn diff_iter(#[case] prev: [&str; 2], #[case] next: [&str; 2]) {
+        let prev = Buffer::with_lines(prev);
+        let next = Buffer::with_lines(next);
+        let updates: Vec<_> = prev.diff_iter(&next).collect();
+        assert_eq!(updates, prev.diff(&next));
+        assert!(!updates.is_empty());
+    }
+
     #[rstest]
     #[case(Rect::new(0, 0, 2, 2), Rect::new(0, 2, 2, 2), ["11", "11", "22", "22"])]
     #[case(Rect::new(2, 2, 2, 2), Rect::new(0, 0, 2, 2), ["22  ", "22  ", "  11", "  11"])]
@@ -21,6 +21,9 @@ use strum::EnumIs;
 /// 5. [`Constraint::Ratio`]
 /// 6. [`Constraint::Fill`]
 ///
+/// [`Constraint::FillMax`] combines two of these: its maximum size has the priority of
+/// [`Constraint::Max`], and it fills the excess space with the priority of [`Constraint::Fill`].
+///
 /// # Examples
 ///
 /// `Constraint` provides helper methods to create lists of constraints from various input formats.
@@ -48,7 +51,12 @@ use strum::EnumIs;
 pub enum Constraint {
     /// Applies a minimum size constraint to the element
     ///
-    /// The element size is set to at least the specified amount.
+    /// The element size is set to at least the specified amount. When the layout contains
+    /// [`Constraint::Fill`] elements, the element keeps its minimum size and the `Fill` elements
+    /// share the space left according to their scaling factors. Otherwise, the element grows like
+    /// a `Fill(1)` element (unless the layout uses [`Flex::Legacy`]).
+    ///
+    /// [`Flex::Legacy`]: crate::layout::Flex::Legacy
     ///
     /// # Examples
     ///
@@ -67,6 +75,14 @@ pub enum Constraint {
     /// │                 40 px                ││  10 px │
     /// └──────────────────────────────────────┘└────────┘
     /// ```
+    ///
+    /// `[Min(20), Fill(1), Fill(2)]`
+    ///
+    /// ```plain
+    /// ┌──────────────────┐┌────────┐┌──────────────────┐
+    /// │       20 px      ││  10 px ││       20 px      │
+    /// └──────────────────┘└────────┘└──────────────────┘
+    /// ```
     Min(u16),
 
     /// Applies a maximum size constraint to the element
@@ -171,7 +187,8 @@ pub enum Constraint {
     /// to fill excess space
     ///
     /// The element will only expand or fill into excess available space, proportionally matching
-    /// other [`Constraint::Fill`] elements while satisfying all other constraints.
+    /// other [`Constraint::Fill`] elements while satisfying all other constraints. In particular,
+    /// [`Constraint::Min`] elements keep their minimum size when there are `Fill` elements.
     ///
     /// # Examples
     ///
@@ -192,6 +209,34 @@ pub enum Constraint {
     /// └───────────┘└───────────────────────┘└──────────┘
     /// ```
     Fill(u16),
+
+    /// Fills excess space like [`Constraint::Fill`] with the given scaling factor, but never grows
+    /// past the given maximum size
+    ///
+    /// The element takes its share of the excess space proportionally to the other
+    /// [`Constraint::Fill`] and `FillMax` elements, up to the maximum size. Once the maximum is
+    /// reached, the remaining excess space is released to the other fill elements. The maximum is
+    /// honored with the same priority as [`Constraint::Max`], so it takes precedence over the
+    /// proportional sharing of the excess space.
+    ///
+    /// # Examples
+    ///
+    /// `[FillMax(1, 10), Fill(1)]`
+    ///
+    /// ```plain
+    /// ┌────────┐┌──────────────────────────────────────┐
+    /// │ 10 px  ││                40 px                 │
+    /// └────────┘└──────────────────────────────────────┘
+    /// ```
+    ///
+    /// `[FillMax(1, 40), FillMax(1, 10)]`
+    ///
+    /// ```plain
+    /// ┌──────────────────────────────────────┐┌────────┐
+    /// │                40 px                 ││ 10 px  │
+    /// └──────────────────────────────────────┘└────────┘
+    /// ```
+    FillMax(u16, u16),
 }
 
 impl Constraint {
@@ -214,7 +259,7 @@ impl Constraint {
                 (percentage * length).min(length) as u16
             }
             Self::Length(l) | Self::Fill(l) => length.min(l),
-            Self::Max(m) => length.min(m),
+            Self::Max(m) | Self::FillMax(_, m) => length.min(m),
             Self::Min(m) => length.max(m),
         }
     }
@@ -374,6 +419,7 @@ impl fmt::Display for Constraint {
             Self::Ratio(n, d) => write!(f, "Ratio({n}, {d})"),
             Self::Length(l) => write!(f, "Length({l})"),
             Self::Fill(l) => write!(f, "Fill({l})"),
+            Self::FillMax(l, m) => write!(f, "FillMax({l}, {m})"),
             Self::Max(m) => write!(f, "Max({m})"),
             Self::Min(m) => write!(f, "Min({m})"),
         }
@@ -396,6 +442,7 @@ mod tests {
         assert_eq!(Constraint::Length(10).to_string(), "Length(10)");
         assert_eq!(Constraint::Max(10).to_string(), "Max(10)");
         assert_eq!(Constraint::Min(10).to_string(), "Min(10)");
+        assert_eq!(Constraint::FillMax(1, 10).to_string(), "FillMax(1, 10)");
     }
 
     #[test]
@@ -178,6 +178,7 @@ pub struct Layout {
     margin: Margin,
     flex: Flex,
     spacing: Spacing,
+    snap: u16,
 }
 
 impl Layout {
@@ -200,6 +201,7 @@ impl Layout {
     /// - `margin`: 0, 0
     /// - `flex`: [`Flex::Start`]
     /// - `spacing`: 0
+    /// - `snap_to`: 0 (no snapping)
     ///
     /// # Examples
     ///
@@ -498,6 +500,37 @@ impl Layout {
         self
     }
 
+    /// Snaps the boundaries between items in the layout to multiples of `cells`.
+    ///
+    /// After the layout is solved, each boundary between segments and spacers is rounded to the
+    /// nearest multiple of `cells`, measured from the start of the area (inside the margin). The
+    /// end of the area is never moved, so the last item absorbs the rounding remainder and the
+    /// items still cover the whole area. This aligns the items of separate layouts to a common
+    /// grid, e.g. columns of monospaced data.
+    ///
+    /// Snapping happens after the constraints are solved, so constraints are only honored
+    /// approximately: each boundary moves by at most half of `cells`, which means a
+    /// [`Length`](Constraint::Length) segment may grow or shrink by up to `cells - 1`, and may
+    /// even collapse to zero if it is shorter than `cells`.
+    ///
+    /// A value of `0` or `1` disables snapping, which is the default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint::*, Layout, Rect};
+    ///
+    /// let layout = Layout::horizontal([Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)]).snap_to(4);
+    /// let [a, b, c] = layout.areas(Rect::new(0, 0, 30, 1));
+    /// assert_eq!([a.x, b.x, c.x], [0, 12, 20]);
+    /// assert_eq!(c.right(), 30);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn snap_to(mut self, cells: u16) -> Self {
+        self.snap = cells;
+        self
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -748,7 +781,10 @@ impl Layout {
         }
 
         // `solver.fetch_changes()` can only be called once per solve
-        let changes: HashMap<Variable, f64> = solver.fetch_changes().iter().copied().collect();
+        let mut changes: HashMap<Variable, f64> = solver.fetch_changes().iter().copied().collect();
+        if self.snap > 1 {
+            snap_changes(&mut changes, area_start, area_end, self.snap);
+        }
         // debug_elements(&segments, &changes);
         // debug_elements(&spacers, &changes);
 
@@ -810,6 +846,7 @@ fn configure_constraints(
     constraints: &[Constraint],
     flex: Flex,
 ) -> Result<(), AddConstraintError> {
+    let min_grows = min_grows(constraints, flex);
     for (&constraint, &segment) in constraints.iter().zip(segments.iter()) {
         match constraint {
             Constraint::Max(max) => {
@@ -818,10 +855,10 @@ fn configure_constraints(
             }
             Constraint::Min(min) => {
                 solver.add_constraint(segment.has_min_size(min as i16, MIN_SIZE_GE))?;
-                if flex.is_legacy() {
-                    solver.add_constraint(segment.has_int_size(min, MIN_SIZE_EQ))?;
-                } else {
+                if min_grows {
                     solver.add_constraint(segment.has_size(area, FILL_GROW))?;
+                } else {
+                    solver.add_constraint(segment.has_int_size(min, MIN_SIZE_EQ))?;
                 }
             }
             Constraint::Length(length) => {
@@ -840,6 +877,11 @@ fn configure_constraints(
                 // given no other constraints, this segment will grow as much as possible.
                 solver.add_constraint(segment.has_size(area, FILL_GROW))?;
             }
+            Constraint::FillMax(_, max) => {
+                // the maximum is enforced like `Max`, and the segment grows like `Fill` below it.
+                solver.add_constraint(segment.has_max_size(max, MAX_SIZE_LE))?;
+                solver.add_constraint(segment.has_size(area, FILL_GROW))?;
+            }
         }
     }
     Ok(())
@@ -931,11 +973,13 @@ fn configure_flex_constraints(
 /// │abcdef││abcdef│
 /// └──────┘└──────┘
 ///
-/// [Min(0), Fill(2)]
+/// [Fill(1), Fill(2)]
 /// ┌──────┐┌────────────┐
 /// │abcdef││abcdefabcdef│
 /// └──────┘└────────────┘
 ///
+/// `Min` constraints take part like `Fill(1)` when they grow (see [`min_grows`]).
+///
 /// `size == base_element * scaling_factor`
 fn configure_fill_constraints(
     solver: &mut Solver,
@@ -943,19 +987,20 @@ fn configure_fill_constraints(
     constraints: &[Constraint],
     flex: Flex,
 ) -> Result<(), AddConstraintError> {
+    let min_grows = min_grows(constraints, flex);
     for ((&left_constraint, &left_segment), (&right_constraint, &right_segment)) in constraints
         .iter()
         .zip(segments.iter())
-        .filter(|(c, _)| c.is_fill() || (!flex.is_legacy() && c.is_min()))
+        .filter(|(c, _)| c.is_fill() || c.is_fill_max() || (min_grows && c.is_min()))
         .tuple_combinations()
     {
         let left_scaling_factor = match left_constraint {
-            Constraint::Fill(scale) => f64::from(scale).max(1e-6),
+            Constraint::Fill(scale) | Constraint::FillMax(scale, _) => f64::from(scale).max(1e-6),
             Constraint::Min(_) => 1.0,
             _ => unreachable!(),
         };
         let right_scaling_factor = match right_constraint {
-            Constraint::Fill(scale) => f64::from(scale).max(1e-6),
+            Constraint::Fill(scale) | Constraint::FillMax(scale, _) => f64::from(scale).max(1e-6),
             Constraint::Min(_) => 1.0,
             _ => unreachable!(),
         };
@@ -968,6 +1013,30 @@ fn configure_fill_constraints(
     Ok(())
 }
 
+/// Returns whether the `Min` segments grow like `Fill(1)` segments to fill the excess space.
+///
+/// They don't with [`Flex::Legacy`], nor when there are `Fill` segments, which then share all the
+/// space left after the minimums according to their weights.
+fn min_grows(constraints: &[Constraint], flex: Flex) -> bool {
+    !flex.is_legacy() && !constraints.iter().any(|c| c.is_fill() || c.is_fill_max())
+}
+
+/// Rounds the solved positions to multiples of `snap` cells from `area_start`, leaving positions at
+/// `area_end` in place.
+fn snap_changes(changes: &mut HashMap<Variable, f64>, area_start: f64, area_end: f64, snap: u16) {
+    let start = (area_start / FLOAT_PRECISION_MULTIPLIER).round();
+    let end = (area_end / FLOAT_PRECISION_MULTIPLIER).round();
+    let snap = f64::from(snap);
+    for value in changes.values_mut() {
+        let position = (value.round() / FLOAT_PRECISION_MULTIPLIER).round();
+        if position >= end {
+            continue;
+        }
+        let offset = ((position - start) / snap).round() * snap;
+        *value = (start + offset).min(end) * FLOAT_PRECISION_MULTIPLIER;
+    }
+}
+
 fn changes_to_rects(
     changes: &HashMap<Variable, f64>,
     elements: &[Element],
@@ -1212,6 +1281,7 @@ mod tests {
                 constraints: vec![],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                snap: 0,
             }
         );
     }
@@ -1257,6 +1327,7 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                snap: 0,
             }
         );
     }
@@ -1271,6 +1342,7 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                snap: 0,
             }
         );
     }
@@ -2213,6 +2285,46 @@ mod tests {
             assert_eq!(ranges, expected);
         }
 
+        #[rstest]
+        #[case::below_cap(vec![FillMax(1, 60), Fill(1)], vec![0..50, 50..100])]
+        #[case::capped(vec![FillMax(1, 20), Fill(1)], vec![0..20, 20..100])]
+        #[case::one_capped(vec![FillMax(1, 20), FillMax(1, 100)], vec![0..20, 20..100])]
+        #[case::both_capped(vec![FillMax(1, 20), FillMax(1, 30), Fill(0)], vec![0..20, 20..50, 50..100])]
+        #[case::weighted(vec![FillMax(1, 100), FillMax(3, 100)], vec![0..25, 25..100])]
+        #[case::weighted_capped(vec![FillMax(1, 10), Fill(1), Fill(2)], vec![0..10, 10..40, 40..100])]
+        #[case::with_length(vec![FillMax(1, 30), Length(50), Fill(1)], vec![0..25, 25..75, 75..100])]
+        #[case::cap_over_length(vec![FillMax(1, 10), Length(95)], vec![0..5, 5..100])]
+        fn fill_max(#[case] constraints: Vec<Constraint>, #[case] expected: Vec<Range<u16>>) {
+            let rect = Rect::new(0, 0, 100, 1);
+            let ranges = Layout::horizontal(constraints)
+                .split(rect)
+                .iter()
+                .map(|r| r.left()..r.right())
+                .collect_vec();
+            assert_eq!(ranges, expected);
+        }
+
+        #[rstest]
+        #[case::min_first(vec![Min(10), Fill(1), Fill(2)], vec![0..10, 10..20, 20..40])]
+        #[case::min_last(vec![Fill(1), Fill(2), Min(10)], vec![0..10, 10..30, 30..40])]
+        #[case::min_between(vec![Fill(1), Min(10), Fill(2)], vec![0..10, 10..20, 20..40])]
+        #[case::large_min(vec![Min(34), Fill(1), Fill(2)], vec![0..34, 34..36, 36..40])]
+        #[case::two_mins(vec![Min(5), Min(5), Fill(1), Fill(3)], vec![0..5, 5..10, 10..18, 18..40])]
+        #[case::min_over_area(vec![Min(50), Fill(1), Fill(2)], vec![0..40, 40..40, 40..40])]
+        #[case::small_min(vec![Min(5), Fill(1), Fill(2)], vec![0..5, 5..17, 17..40])]
+        #[case::fill_max(vec![Min(10), FillMax(1, 5), Fill(1)], vec![0..10, 10..15, 15..40])]
+        #[case::only_mins(vec![Min(5), Min(10)], vec![0..20, 20..40])]
+        #[case::large_min_and_fills(vec![Min(20), Fill(1), Fill(2)], vec![0..20, 20..27, 27..40])]
+        fn min_fill(#[case] constraints: Vec<Constraint>, #[case] expected: Vec<Range<u16>>) {
+            let rect = Rect::new(0, 0, 40, 1);
+            let ranges = Layout::horizontal(constraints)
+                .split(rect)
+                .iter()
+                .map(|r| r.left()..r.right())
+                .collect_vec();
+            assert_eq!(ranges, expected);
+        }
+
         #[rstest]
         #[case::min_percentage(vec![Min(0), Percentage(20)], vec![0..80, 80..100])]
         #[case::max_percentage(vec![Max(0), Percentage(20)], vec![0..0, 0..100])]
@@ -2654,6 +2766,30 @@ mod tests {
             assert_eq!(result, expected);
         }
 
+        #[rstest]
+        #[case::ratios(vec![(0, 12), (12, 8), (20, 10)], vec![Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)], 0, 0)]
+        #[case::offset_area(vec![(13, 12), (25, 8), (33, 10)], vec![Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)], 0, 13)]
+        #[case::lengths(vec![(0, 4), (4, 8), (12, 18)], vec![Length(5), Length(5), Fill(1)], 0, 0)]
+        #[case::spacing(vec![(0, 8), (8, 4)], vec![Length(6), Length(6)], 1, 0)]
+        #[case::short_length_collapses(vec![(0, 0), (0, 30)], vec![Length(1), Fill(1)], 0, 0)]
+        fn snap_to(
+            #[case] expected: Vec<(u16, u16)>,
+            #[case] constraints: Vec<Constraint>,
+            #[case] spacing: u16,
+            #[case] x: u16,
+        ) {
+            let rect = Rect::new(x, 0, 30, 1);
+            let r = Layout::horizontal(constraints)
+                .spacing(spacing)
+                .snap_to(4)
+                .split(rect);
+            let result = r
+                .iter()
+                .map(|r| (r.x, r.width))
+                .collect::<Vec<(u16, u16)>>();
+            assert_eq!(result, expected);
+        }
+
         #[rstest]
         #[case::compare(vec![(0, 90), (90, 10)], vec![Min(10), Length(10)], Flex::Legacy)]
         #[case::compare(vec![(0, 90), (90, 10)], vec![Min(10), Length(10)], Flex::Start)]
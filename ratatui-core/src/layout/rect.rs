@@ -96,6 +96,67 @@ impl Rect {
         }
     }
 
+    /// Creates a new `Rect`, or returns `None` if the right or bottom coordinate would not fit in a
+    /// `u16`.
+    ///
+    /// Unlike [`Rect::new`], which clamps the width and height, this can be used to detect that
+    /// the computed bounds of a `Rect` overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// assert_eq!(Rect::try_new(1, 2, 3, 4), Some(Rect::new(1, 2, 3, 4)));
+    /// assert_eq!(Rect::try_new(u16::MAX, 0, 1, 1), None);
+    /// ```
+    pub const fn try_new(x: u16, y: u16, width: u16, height: u16) -> Option<Self> {
+        if x.checked_add(width).is_none() || y.checked_add(height).is_none() {
+            return None;
+        }
+        Some(Self {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a new `Rect` from signed coordinates, clipped to the range that fits in a `u16`.
+    ///
+    /// This is useful when the position and size of a `Rect` are computed with signed arithmetic,
+    /// e.g. from mouse coordinates. The `Rect` spans from `x` to `x + width` horizontally and from
+    /// `y` to `y + height` vertically, and each of these bounds is clamped between `0` and
+    /// `u16::MAX`. The parts of the `Rect` that are outside of this range are cut off, so a `Rect`
+    /// starting at a negative coordinate keeps its right or bottom edge. A negative width or height
+    /// results in an empty `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// assert_eq!(Rect::from_clamped(1, 2, 3, 4), Rect::new(1, 2, 3, 4));
+    /// assert_eq!(Rect::from_clamped(-2, 0, 5, 1), Rect::new(0, 0, 3, 1));
+    /// assert_eq!(
+    ///     Rect::from_clamped(65_530, 0, 100, 1),
+    ///     Rect::new(65_530, 0, 5, 1)
+    /// );
+    /// ```
+    pub fn from_clamped(x: i32, y: i32, width: i32, height: i32) -> Self {
+        let clamp = |value: i64| value.clamp(0, i64::from(u16::MAX)) as u16;
+        let (left, top) = (i64::from(x), i64::from(y));
+        let right = left + i64::from(width.max(0));
+        let bottom = top + i64::from(height.max(0));
+        let (x, y) = (clamp(left), clamp(top));
+        Self {
+            x,
+            y,
+            width: clamp(right) - x,
+            height: clamp(bottom) - y,
+        }
+    }
+
     /// The area of the `Rect`. If the area is larger than the maximum value of `u16`, it will be
     /// clamped to `u16::MAX`.
     pub const fn area(self) -> u32 {
@@ -268,6 +329,91 @@ impl Rect {
         Self::new(x, y, width, height)
     }
 
+    /// Returns the top `percent` of the `Rect`, spanning its whole width.
+    ///
+    /// This is a cheap alternative to a [`Layout`](crate::layout::Layout) for a quick split. The
+    /// height is rounded down and `percent` is clamped to 100.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 20, 10);
+    /// assert_eq!(area.percentage_top(33), Rect::new(0, 0, 20, 3));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn percentage_top(self, percent: u16) -> Self {
+        Self {
+            height: percentage_of(self.height, percent),
+            ..self
+        }
+    }
+
+    /// Returns the bottom `percent` of the `Rect`, spanning its whole width.
+    ///
+    /// The height is rounded down and `percent` is clamped to 100. See [`Rect::percentage_top`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 20, 10);
+    /// assert_eq!(area.percentage_bottom(33), Rect::new(0, 7, 20, 3));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn percentage_bottom(self, percent: u16) -> Self {
+        let height = percentage_of(self.height, percent);
+        Self {
+            y: self.y + (self.height - height),
+            height,
+            ..self
+        }
+    }
+
+    /// Returns the left `percent` of the `Rect`, spanning its whole height.
+    ///
+    /// The width is rounded down and `percent` is clamped to 100. See [`Rect::percentage_top`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 10, 20);
+    /// assert_eq!(area.percentage_left(33), Rect::new(0, 0, 3, 20));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn percentage_left(self, percent: u16) -> Self {
+        Self {
+            width: percentage_of(self.width, percent),
+            ..self
+        }
+    }
+
+    /// Returns the right `percent` of the `Rect`, spanning its whole height.
+    ///
+    /// The width is rounded down and `percent` is clamped to 100. See [`Rect::percentage_top`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 10, 20);
+    /// assert_eq!(area.percentage_right(33), Rect::new(7, 0, 3, 20));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn percentage_right(self, percent: u16) -> Self {
+        let width = percentage_of(self.width, percent);
+        Self {
+            x: self.x + (self.width - width),
+            width,
+            ..self
+        }
+    }
+
     /// An iterator over rows within the `Rect`.
     ///
     /// # Example
@@ -370,6 +516,13 @@ impl From<(Position, Size)> for Rect {
     }
 }
 
+/// Returns `percent` of `length`, rounded down, with `percent` clamped to 100.
+const fn percentage_of(length: u16, percent: u16) -> u16 {
+    let percent = if percent > 100 { 100 } else { percent };
+    // the result is at most `length`, so it fits in a u16
+    (length as u32 * percent as u32 / 100) as u16
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -395,6 +548,34 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::valid((1, 2, 3, 4), Some(Rect::new(1, 2, 3, 4)))]
+    #[case::max_right((u16::MAX - 3, 0, 3, 1), Some(Rect::new(u16::MAX - 3, 0, 3, 1)))]
+    #[case::max_bottom((0, 1, 1, u16::MAX - 1), Some(Rect::new(0, 1, 1, u16::MAX - 1)))]
+    #[case::overflowing_width((1, 0, u16::MAX, 1), None)]
+    #[case::overflowing_height((0, u16::MAX, 1, 1), None)]
+    fn try_new(
+        #[case] (x, y, width, height): (u16, u16, u16, u16),
+        #[case] expected: Option<Rect>,
+    ) {
+        assert_eq!(Rect::try_new(x, y, width, height), expected);
+    }
+
+    #[rstest]
+    #[case::valid((1, 2, 3, 4), Rect::new(1, 2, 3, 4))]
+    #[case::negative_x((-2, 1, 5, 1), Rect::new(0, 1, 3, 1))]
+    #[case::negative_y((1, -3, 1, 5), Rect::new(1, 0, 1, 2))]
+    #[case::left_of_origin((-10, 0, 5, 1), Rect::new(0, 0, 0, 1))]
+    #[case::negative_size((1, 2, -3, -4), Rect::new(1, 2, 0, 0))]
+    #[case::overflowing_width((65_530, 0, 100, 1), Rect::new(65_530, 0, 5, 1))]
+    #[case::overflowing_height((0, 65_530, 1, 100), Rect::new(0, 65_530, 1, 5))]
+    #[case::beyond_max((70_000, 80_000, 1, 1), Rect::new(u16::MAX, u16::MAX, 0, 0))]
+    #[case::extremes((i32::MIN, i32::MIN, i32::MAX, i32::MAX), Rect::ZERO)]
+    #[case::full((0, 0, i32::MAX, i32::MAX), Rect::new(0, 0, u16::MAX, u16::MAX))]
+    fn from_clamped(#[case] (x, y, width, height): (i32, i32, i32, i32), #[case] expected: Rect) {
+        assert_eq!(Rect::from_clamped(x, y, width, height), expected);
+    }
+
     #[test]
     fn area() {
         assert_eq!(Rect::new(1, 2, 3, 4).area(), 12);
@@ -498,6 +679,50 @@ mod tests {
         assert!(!Rect::new(1, 2, 3, 4).intersects(Rect::new(5, 6, 7, 8)));
     }
 
+    #[rstest]
+    #[case::overlapping(Rect::new(1, 2, 3, 4), Rect::new(2, 3, 4, 5), Rect::new(1, 2, 5, 6))]
+    #[case::non_overlapping(Rect::new(0, 0, 2, 2), Rect::new(5, 5, 2, 2), Rect::new(0, 0, 7, 7))]
+    #[case::touching(Rect::new(0, 0, 2, 2), Rect::new(2, 0, 2, 2), Rect::new(0, 0, 4, 2))]
+    #[case::nested(
+        Rect::new(0, 0, 10, 10),
+        Rect::new(2, 3, 4, 5),
+        Rect::new(0, 0, 10, 10)
+    )]
+    #[case::max(
+        Rect::new(u16::MAX - 2, 0, 2, 1),
+        Rect::new(0, 0, 1, 1),
+        Rect::new(0, 0, u16::MAX, 1)
+    )]
+    fn union_cases(#[case] rect: Rect, #[case] other: Rect, #[case] expected: Rect) {
+        assert_eq!(rect.union(other), expected);
+        assert_eq!(other.union(rect), expected);
+    }
+
+    #[rstest]
+    #[case::overlapping(Rect::new(1, 2, 3, 4), Rect::new(2, 3, 4, 5), Rect::new(2, 3, 2, 3))]
+    #[case::non_overlapping(Rect::new(0, 0, 2, 2), Rect::new(5, 5, 2, 2), Rect::new(5, 5, 0, 0))]
+    #[case::touching(Rect::new(0, 0, 2, 2), Rect::new(2, 0, 2, 2), Rect::new(2, 0, 0, 2))]
+    #[case::nested(Rect::new(0, 0, 10, 10), Rect::new(2, 3, 4, 5), Rect::new(2, 3, 4, 5))]
+    #[case::max(
+        Rect::new(u16::MAX - 2, 0, 2, 1),
+        Rect::new(0, 0, u16::MAX, 1),
+        Rect::new(u16::MAX - 2, 0, 2, 1)
+    )]
+    fn intersection_cases(#[case] rect: Rect, #[case] other: Rect, #[case] expected: Rect) {
+        assert_eq!(rect.intersection(other), expected);
+        assert_eq!(other.intersection(rect), expected);
+    }
+
+    #[rstest]
+    #[case::overlapping(Rect::new(1, 2, 3, 4), Rect::new(2, 3, 4, 5), true)]
+    #[case::non_overlapping(Rect::new(0, 0, 2, 2), Rect::new(5, 5, 2, 2), false)]
+    #[case::touching(Rect::new(0, 0, 2, 2), Rect::new(2, 0, 2, 2), false)]
+    #[case::nested(Rect::new(0, 0, 10, 10), Rect::new(2, 3, 4, 5), true)]
+    fn intersects_cases(#[case] rect: Rect, #[case] other: Rect, #[case] expected: bool) {
+        assert_eq!(rect.intersects(other), expected);
+        assert_eq!(other.intersects(rect), expected);
+    }
+
     // the bounds of this rect are x: [1..=3], y: [2..=5]
     #[rstest]
     #[case::inside_top_left(Rect::new(1, 2, 3, 4), Position { x: 1, y: 2 }, true)]
@@ -653,4 +878,43 @@ mod tests {
             }
         );
     }
+
+    #[rstest]
+    #[case::zero(0, Rect::new(1, 2, 0, 10), Rect::new(11, 2, 0, 10))]
+    #[case::non_divisible(33, Rect::new(1, 2, 3, 10), Rect::new(8, 2, 3, 10))]
+    #[case::rounded_down(99, Rect::new(1, 2, 9, 10), Rect::new(2, 2, 9, 10))]
+    #[case::full(100, Rect::new(1, 2, 10, 10), Rect::new(1, 2, 10, 10))]
+    #[case::clamped(150, Rect::new(1, 2, 10, 10), Rect::new(1, 2, 10, 10))]
+    fn percentage_horizontal(
+        #[case] percent: u16,
+        #[case] expected_left: Rect,
+        #[case] expected_right: Rect,
+    ) {
+        let rect = Rect::new(1, 2, 10, 10);
+        assert_eq!(rect.percentage_left(percent), expected_left);
+        assert_eq!(rect.percentage_right(percent), expected_right);
+    }
+
+    #[rstest]
+    #[case::zero(0, Rect::new(2, 1, 10, 0), Rect::new(2, 11, 10, 0))]
+    #[case::non_divisible(33, Rect::new(2, 1, 10, 3), Rect::new(2, 8, 10, 3))]
+    #[case::rounded_down(99, Rect::new(2, 1, 10, 9), Rect::new(2, 2, 10, 9))]
+    #[case::full(100, Rect::new(2, 1, 10, 10), Rect::new(2, 1, 10, 10))]
+    #[case::clamped(150, Rect::new(2, 1, 10, 10), Rect::new(2, 1, 10, 10))]
+    fn percentage_vertical(
+        #[case] percent: u16,
+        #[case] expected_top: Rect,
+        #[case] expected_bottom: Rect,
+    ) {
+        let rect = Rect::new(2, 1, 10, 10);
+        assert_eq!(rect.percentage_top(percent), expected_top);
+        assert_eq!(rect.percentage_bottom(percent), expected_bottom);
+    }
+
+    #[test]
+    fn percentage_of_max_size() {
+        let rect = Rect::new(0, 0, u16::MAX, u16::MAX);
+        assert_eq!(rect.percentage_top(50).height, u16::MAX / 2);
+        assert_eq!(rect.percentage_right(100), rect);
+    }
 }
@@ -243,8 +243,21 @@ impl fmt::Debug for Modifier {
 ///     buffer[(0, 0)].style(),
 /// );
 /// ```
+///
+/// With the `serde` feature, a `Style` is (de)serialized in a compact format that is easy to write
+/// by hand, e.g. in a theme file. Only the properties that are set are written: the colors use the
+/// [`Color`] string representation and the modifiers are lists of names under the `mod` (added)
+/// and `sub_mod` (removed) keys. For example in TOML:
+///
+/// ```toml
+/// title = { fg = "red", bg = "#222222", mod = ["bold", "italic"] }
+/// ```
+///
+/// Modifier names are case insensitive and can use `-` or `_` between words (e.g. `crossed-out`).
+/// Unknown names and unknown fields are a deserialization error. The previous format, with
+/// `add_modifier` and `sub_modifier` fields, can still be deserialized. Formats that are not human
+/// readable (e.g. `bincode`) keep writing all the fields, as in previous versions.
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     /// The foreground color.
     pub fg: Option<Color>,
@@ -340,6 +353,35 @@ impl Style {
         self
     }
 
+    /// Blends a color over the background color.
+    ///
+    /// `alpha` is the opacity of the blended color and is clamped to `0.0..=1.0`. See
+    /// [`Color::blend`] for how the colors are blended. A style without a background color is
+    /// treated like [`Color::Reset`], so its background is only replaced when `alpha` is `1.0`.
+    ///
+    /// This is useful to draw translucent panels over other content.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Style};
+    ///
+    /// let style = Style::default().bg(Color::Rgb(0, 0, 0));
+    /// assert_eq!(
+    ///     style.blend_bg(Color::Rgb(255, 255, 255), 0.25),
+    ///     Style::default().bg(Color::Rgb(64, 64, 64))
+    /// );
+    /// ```
+    #[must_use = "`blend_bg` returns the modified style without modifying the original"]
+    pub fn blend_bg(mut self, color: Color, alpha: f32) -> Self {
+        self.bg = match self.bg {
+            Some(bg) => Some(bg.blend(color, alpha)),
+            None if alpha >= 1.0 => Some(color),
+            None => None,
+        };
+        self
+    }
+
     /// Changes the underline color. The text must be underlined with a modifier for this to work.
     ///
     /// This uses a non-standard ANSI escape sequence. It is supported by most terminal emulators,
@@ -456,6 +498,48 @@ impl Style {
         self
     }
 
+    /// Interpolates between this style and another style.
+    ///
+    /// This is useful for animating transitions between two styles, e.g. by deriving `t` from a
+    /// frame counter. `t` is the interpolation factor, where `0.0` returns this style and `1.0`
+    /// returns the other style. Values outside of this range are clamped.
+    ///
+    /// - Colors that are set on both styles are blended using [`Color::blend`].
+    /// - Colors that are set on only one of the styles are taken from that style, and colors that
+    ///   are set on neither style stay `None`.
+    /// - Modifiers cannot be interpolated, so they are taken from this style below `t = 0.5` and
+    ///   from the other style from there on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Style};
+    ///
+    /// let from = Style::new().fg(Color::Rgb(0, 0, 0));
+    /// let to = Style::new().fg(Color::Rgb(200, 100, 0)).bg(Color::Blue);
+    /// assert_eq!(
+    ///     from.lerp(to, 0.5),
+    ///     Style::new().fg(Color::Rgb(100, 50, 0)).bg(Color::Blue)
+    /// );
+    /// ```
+    #[must_use = "`lerp` returns a new style without modifying the original"]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let blend = |from: Option<Color>, to: Option<Color>| match (from, to) {
+            (Some(from), Some(to)) => Some(from.blend(to, t)),
+            (from, to) => from.or(to),
+        };
+        let modifiers = if t >= 0.5 { other } else { self };
+        Self {
+            fg: blend(self.fg, other.fg),
+            bg: blend(self.bg, other.bg),
+            #[cfg(feature = "underline-color")]
+            underline_color: blend(self.underline_color, other.underline_color),
+            add_modifier: modifiers.add_modifier,
+            sub_modifier: modifiers.sub_modifier,
+        }
+    }
+
     /// Formats the style in a way that can be copy-pasted into code using the style shorthands.
     ///
     /// This is useful for debugging and for generating code snippets.
@@ -640,6 +724,212 @@ impl From<(Color, Color, Modifier, Modifier)> for Style {
     }
 }
 
+/// The compact serde representation of a [`Style`], see the [`Style`] documentation.
+///
+/// This is used for human readable formats. The `add_modifier` and `sub_modifier` fields accept
+/// the format of previous versions, where the modifiers use their `bitflags` representation (e.g.
+/// `"BOLD | ITALIC"`), and are combined with the `mod` and `sub_mod` fields.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompactStyle {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fg: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bg: Option<Color>,
+    #[cfg(feature = "underline-color")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    underline_color: Option<Color>,
+    #[serde(
+        default,
+        rename = "mod",
+        skip_serializing_if = "Modifier::is_empty",
+        with = "modifier_names"
+    )]
+    add_modifier: Modifier,
+    #[serde(
+        default,
+        rename = "sub_mod",
+        skip_serializing_if = "Modifier::is_empty",
+        with = "modifier_names"
+    )]
+    sub_modifier: Modifier,
+    #[serde(default, rename = "add_modifier", skip_serializing)]
+    legacy_add_modifier: Modifier,
+    #[serde(default, rename = "sub_modifier", skip_serializing)]
+    legacy_sub_modifier: Modifier,
+}
+
+/// The serde representation of a [`Style`] for formats that are not human readable.
+///
+/// All the fields are always written, as these formats are usually not self-describing.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FullStyle {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    #[cfg(feature = "underline-color")]
+    underline_color: Option<Color>,
+    add_modifier: Modifier,
+    sub_modifier: Modifier,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Style {
+    /// Serializes the style in the compact format described in the [`Style`] documentation when
+    /// the format is human readable, and with all its fields otherwise.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            CompactStyle {
+                fg: self.fg,
+                bg: self.bg,
+                #[cfg(feature = "underline-color")]
+                underline_color: self.underline_color,
+                add_modifier: self.add_modifier,
+                sub_modifier: self.sub_modifier,
+                legacy_add_modifier: Modifier::empty(),
+                legacy_sub_modifier: Modifier::empty(),
+            }
+            .serialize(serializer)
+        } else {
+            FullStyle {
+                fg: self.fg,
+                bg: self.bg,
+                #[cfg(feature = "underline-color")]
+                underline_color: self.underline_color,
+                add_modifier: self.add_modifier,
+                sub_modifier: self.sub_modifier,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Style {
+    /// Deserializes a style from the compact format described in the [`Style`] documentation, or
+    /// from the format of previous versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Modifier, Style};
+    ///
+    /// # fn get_theme() -> Result<(), serde_json::Error> {
+    /// let style: Style =
+    ///     serde_json::from_str(r##"{"fg": "red", "bg": "#222222", "mod": ["bold", "italic"]}"##)?;
+    /// assert_eq!(
+    ///     style,
+    ///     Style::new()
+    ///         .fg(Color::Red)
+    ///         .bg(Color::Rgb(0x22, 0x22, 0x22))
+    ///         .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+    /// );
+    ///
+    /// let err = serde_json::from_str::<Style>(r#"{"mod": ["blinky"]}"#).unwrap_err();
+    /// assert!(err.to_string().starts_with("unknown modifier `blinky`"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            let style = FullStyle::deserialize(deserializer)?;
+            return Ok(Self {
+                fg: style.fg,
+                bg: style.bg,
+                #[cfg(feature = "underline-color")]
+                underline_color: style.underline_color,
+                add_modifier: style.add_modifier,
+                sub_modifier: style.sub_modifier,
+            });
+        }
+        let style = CompactStyle::deserialize(deserializer)?;
+        Ok(Self {
+            fg: style.fg,
+            bg: style.bg,
+            #[cfg(feature = "underline-color")]
+            underline_color: style.underline_color,
+            add_modifier: style.add_modifier | style.legacy_add_modifier,
+            sub_modifier: style.sub_modifier | style.legacy_sub_modifier,
+        })
+    }
+}
+
+/// (De)serializes a [`Modifier`] as a list of lowercase modifier names, e.g. `["bold", "italic"]`.
+#[cfg(feature = "serde")]
+mod modifier_names {
+    use std::fmt;
+
+    use serde::{
+        de::{self, SeqAccess, Visitor},
+        ser::SerializeSeq,
+        Deserializer, Serializer,
+    };
+
+    use super::Modifier;
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // the signature is required by serde
+    pub fn serialize<S>(modifier: &Modifier, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(modifier.iter().count()))?;
+        for (name, _) in modifier.iter_names() {
+            seq.serialize_element(&name.to_lowercase())?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Modifier, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ModifierVisitor)
+    }
+
+    /// Parses a modifier name, ignoring case and accepting `-`, `_` or a space between words.
+    fn parse_name<E: de::Error>(name: &str) -> Result<Modifier, E> {
+        let normalized = name.trim().to_uppercase().replace(['-', ' '], "_");
+        Modifier::from_name(&normalized).ok_or_else(|| {
+            let expected = Modifier::all()
+                .iter_names()
+                .map(|(name, _)| name.to_lowercase())
+                .collect::<Vec<_>>()
+                .join(", ");
+            E::custom(format!(
+                "unknown modifier `{name}`, expected one of {expected}"
+            ))
+        })
+    }
+
+    struct ModifierVisitor;
+
+    impl<'de> Visitor<'de> for ModifierVisitor {
+        type Value = Modifier;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a list of modifier names")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut modifier = Modifier::empty();
+            while let Some(name) = seq.next_element::<String>()? {
+                modifier |= parse_name(&name)?;
+            }
+            Ok(modifier)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -660,6 +950,44 @@ mod tests {
         assert_eq!(format!("{style:?}"), expected);
     }
 
+    #[rstest]
+    #[case::start(0.0, Style::new().fg(Color::Rgb(0, 0, 0)).bg(Color::Reset).bold())]
+    #[case::middle(0.5, Style::new().fg(Color::Rgb(100, 50, 128)).bg(Color::Reset).italic())]
+    #[case::end(1.0, Style::new().fg(Color::Rgb(200, 100, 255)).bg(Color::Blue).italic())]
+    fn lerp(#[case] t: f32, #[case] expected: Style) {
+        let from = Style::new().fg(Color::Rgb(0, 0, 0)).bg(Color::Reset).bold();
+        let to = Style::new()
+            .fg(Color::Rgb(200, 100, 255))
+            .bg(Color::Blue)
+            .italic();
+        assert_eq!(from.lerp(to, t), expected);
+    }
+
+    #[rstest]
+    #[case::start(0.0)]
+    #[case::middle(0.5)]
+    #[case::end(1.0)]
+    fn lerp_unset_colors(#[case] t: f32) {
+        let from = Style::new().fg(Color::Green);
+        let to = Style::new().bg(Color::Rgb(1, 2, 3));
+        let lerped = from.lerp(to, t);
+        assert_eq!(lerped.fg, Some(Color::Green));
+        assert_eq!(lerped.bg, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(Style::new().lerp(Style::new(), t), Style::new());
+    }
+
+    #[rstest]
+    #[case::transparent(Style::new().bg(Color::Red), 0.0, Style::new().bg(Color::Red))]
+    #[case::translucent(Style::new().bg(Color::Red), 0.5, Style::new().bg(Color::Rgb(103, 0, 119)))]
+    #[case::opaque(Style::new().bg(Color::Red), 1.0, Style::new().bg(Color::Blue))]
+    #[case::clamped(Style::new().bg(Color::Red), -1.0, Style::new().bg(Color::Red))]
+    #[case::reset(Style::new().bg(Color::Reset), 0.5, Style::new().bg(Color::Reset))]
+    #[case::unset(Style::new().red(), 0.5, Style::new().red())]
+    #[case::unset_opaque(Style::new().red(), 1.0, Style::new().red().on_blue())]
+    fn blend_bg(#[case] style: Style, #[case] alpha: f32, #[case] expected: Style) {
+        assert_eq!(style.blend_bg(Color::Blue, alpha), expected);
+    }
+
     #[test]
     fn combined_patch_gives_same_result_as_individual_patch() {
         let styles = [
@@ -909,4 +1237,76 @@ mod tests {
                 .remove_modifier(Modifier::DIM)
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_compact() -> Result<(), serde_json::Error> {
+        assert_eq!(serde_json::to_string(&Style::new())?, "{}");
+        let style = Style::new().red().on_black().bold().crossed_out().not_dim();
+        assert_eq!(
+            serde_json::to_string(&style)?,
+            r#"{"fg":"Red","bg":"Black","mod":["bold","crossed_out"],"sub_mod":["dim"]}"#
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::empty(Style::new())]
+    #[case::reset(Style::reset())]
+    #[case::colors(Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Indexed(42)))]
+    #[case::modifiers(Style::new().bold().italic().not_underlined().not_hidden())]
+    #[case::all(Style::new().light_blue().on_gray().dim().not_bold())]
+    fn serialize_then_deserialize(#[case] style: Style) -> Result<(), serde_json::Error> {
+        let json = serde_json::to_string(&style)?;
+        assert_eq!(serde_json::from_str::<Style>(&json)?, style);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::lowercase(r#"{"mod": ["bold", "crossed_out"]}"#)]
+    #[case::uppercase(r#"{"mod": ["BOLD", "CROSSED_OUT"]}"#)]
+    #[case::dashed(r#"{"mod": ["Bold", "crossed-out"]}"#)]
+    #[case::legacy(
+        r#"{"fg": null, "bg": null, "add_modifier": "BOLD | CROSSED_OUT", "sub_modifier": ""}"#
+    )]
+    #[case::legacy_and_names(r#"{"add_modifier": "BOLD", "mod": ["crossed-out"]}"#)]
+    fn deserialize_modifier_names(#[case] json: &str) -> Result<(), serde_json::Error> {
+        assert_eq!(
+            serde_json::from_str::<Style>(json)?,
+            Style::new().bold().crossed_out()
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::empty(Style::new())]
+    #[case::modifiers(Style::new().bold().italic().not_dim())]
+    fn serialize_then_deserialize_binary(#[case] style: Style) -> Result<(), bincode::Error> {
+        let bytes = bincode::serialize(&style)?;
+        assert_eq!(bincode::deserialize::<Style>(&bytes)?, style);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_unknown_field() {
+        let err =
+            serde_json::from_str::<Style>(r#"{"fg": "red", "modifiers": ["bold"]}"#).unwrap_err();
+        assert!(err.to_string().starts_with("unknown field `modifiers`"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_unknown_modifier() {
+        let err = serde_json::from_str::<Style>(r#"{"mod": ["bold", "blinky"]}"#).unwrap_err();
+        assert!(err.is_data());
+        assert_eq!(
+            err.to_string(),
+            "unknown modifier `blinky`, expected one of bold, dim, italic, underlined, slow_blink, \
+             rapid_blink, reversed, hidden, crossed_out at line 1 column 26"
+        );
+    }
 }
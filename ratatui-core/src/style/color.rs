@@ -135,6 +135,299 @@ impl Color {
         let b = u as u8;
         Self::Rgb(r, g, b)
     }
+
+    /// Blends another color over this color.
+    ///
+    /// `alpha` is the opacity of the other color, where `0.0` returns this color and `1.0` returns
+    /// the other color. Values outside of this range are clamped.
+    ///
+    /// Each channel is linearly interpolated. Named and [`Color::Indexed`] colors are first
+    /// converted to their approximate RGB value (see [`Color::to_rgb`]), so the result of blending
+    /// is always a [`Color::Rgb`] unless `alpha` is `0.0` or `1.0`. [`Color::Reset`] has no RGB
+    /// value and is treated as opaque: when either color is `Reset`, this color is returned
+    /// unless `alpha` is `1.0`.
+    ///
+    /// This is useful to draw translucent overlays, e.g. by blending a highlight color over the
+    /// background of the cells below.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let black = Color::Rgb(0, 0, 0);
+    /// let white = Color::Rgb(255, 255, 255);
+    /// assert_eq!(black.blend(white, 0.5), Color::Rgb(128, 128, 128));
+    /// assert_eq!(Color::Black.blend(Color::Red, 0.5), Color::Rgb(103, 0, 0));
+    /// assert_eq!(Color::Reset.blend(Color::Red, 0.5), Color::Reset);
+    /// ```
+    #[must_use = "`blend` returns a new color without modifying the original"]
+    pub fn blend(self, other: Self, alpha: f32) -> Self {
+        let alpha = alpha.clamp(0.0, 1.0);
+        if alpha <= 0.0 {
+            return self;
+        }
+        if alpha >= 1.0 {
+            return other;
+        }
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return self;
+        };
+        let lerp = |a: u8, b: u8| {
+            (f32::from(b) - f32::from(a))
+                .mul_add(alpha, f32::from(a))
+                .round() as u8
+        };
+        Self::Rgb(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2))
+    }
+
+    /// Returns the approximate RGB value of this color.
+    ///
+    /// The named colors and the first 16 [`Color::Indexed`] colors are mapped to the default xterm
+    /// palette. Indexed colors 16-231 are mapped to the 6x6x6 color cube and 232-255 to the
+    /// grayscale ramp. The actual colors shown depend on the terminal's configured palette, so
+    /// these values are only an approximation.
+    ///
+    /// Returns `None` for [`Color::Reset`] as it depends on the terminal's default colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Red.to_rgb(), Some((205, 0, 0)));
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let rgb = match self {
+            Self::Reset => return None,
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Indexed(index) => return Some(indexed_to_rgb(index)),
+            Self::Black => ANSI_PALETTE[0],
+            Self::Red => ANSI_PALETTE[1],
+            Self::Green => ANSI_PALETTE[2],
+            Self::Yellow => ANSI_PALETTE[3],
+            Self::Blue => ANSI_PALETTE[4],
+            Self::Magenta => ANSI_PALETTE[5],
+            Self::Cyan => ANSI_PALETTE[6],
+            Self::Gray => ANSI_PALETTE[7],
+            Self::DarkGray => ANSI_PALETTE[8],
+            Self::LightRed => ANSI_PALETTE[9],
+            Self::LightGreen => ANSI_PALETTE[10],
+            Self::LightYellow => ANSI_PALETTE[11],
+            Self::LightBlue => ANSI_PALETTE[12],
+            Self::LightMagenta => ANSI_PALETTE[13],
+            Self::LightCyan => ANSI_PALETTE[14],
+            Self::White => ANSI_PALETTE[15],
+        };
+        Some(rgb)
+    }
+
+    /// Returns the bright variant of this color.
+    ///
+    /// The 8 base ANSI colors are mapped to their bright counterpart (e.g. [`Color::Red`] to
+    /// [`Color::LightRed`], [`Color::Black`] to [`Color::DarkGray`] and [`Color::Gray`] to
+    /// [`Color::White`]), and the indexed colors 0-7 to the indexed colors 8-15. Bright colors,
+    /// other indexed colors, RGB colors and [`Color::Reset`] are returned unchanged.
+    ///
+    /// This is useful to derive hover or active states from a base theme color. See
+    /// [`Color::dim`] for the reverse mapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Red.bright(), Color::LightRed);
+    /// assert_eq!(Color::LightRed.bright(), Color::LightRed);
+    /// assert_eq!(Color::Indexed(4).bright(), Color::Indexed(12));
+    /// assert_eq!(Color::Rgb(1, 2, 3).bright(), Color::Rgb(1, 2, 3));
+    /// ```
+    #[must_use = "`bright` returns a new color without modifying the original"]
+    pub const fn bright(self) -> Self {
+        match self {
+            Self::Black => Self::DarkGray,
+            Self::Red => Self::LightRed,
+            Self::Green => Self::LightGreen,
+            Self::Yellow => Self::LightYellow,
+            Self::Blue => Self::LightBlue,
+            Self::Magenta => Self::LightMagenta,
+            Self::Cyan => Self::LightCyan,
+            Self::Gray => Self::White,
+            Self::Indexed(index @ 0..=7) => Self::Indexed(index + 8),
+            color => color,
+        }
+    }
+
+    /// Returns the dim (non-bright) variant of this color.
+    ///
+    /// This is the reverse of [`Color::bright`]: the 8 bright ANSI colors are mapped to their base
+    /// counterpart (e.g. [`Color::LightRed`] to [`Color::Red`]), and the indexed colors 8-15 to
+    /// the indexed colors 0-7. Base colors, other indexed colors, RGB colors and
+    /// [`Color::Reset`] are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::LightRed.dim(), Color::Red);
+    /// assert_eq!(Color::Red.dim(), Color::Red);
+    /// assert_eq!(Color::Indexed(12).dim(), Color::Indexed(4));
+    /// ```
+    #[must_use = "`dim` returns a new color without modifying the original"]
+    pub const fn dim(self) -> Self {
+        match self {
+            Self::DarkGray => Self::Black,
+            Self::LightRed => Self::Red,
+            Self::LightGreen => Self::Green,
+            Self::LightYellow => Self::Yellow,
+            Self::LightBlue => Self::Blue,
+            Self::LightMagenta => Self::Magenta,
+            Self::LightCyan => Self::Cyan,
+            Self::White => Self::Gray,
+            Self::Indexed(index @ 8..=15) => Self::Indexed(index - 8),
+            color => color,
+        }
+    }
+
+    /// Returns the closest color of the xterm 256 color palette.
+    ///
+    /// [`Color::Rgb`] colors are mapped to the closest color of the 6x6x6 color cube or of the
+    /// grayscale ramp (the indexed colors 16-255), as the first 16 colors of the palette depend on
+    /// the terminal's configuration. Other colors are returned unchanged.
+    ///
+    /// This is useful to render RGB colors on terminals that only support 256 colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(255, 0, 0).to_indexed(), Color::Indexed(196));
+    /// assert_eq!(Color::Rgb(128, 128, 128).to_indexed(), Color::Indexed(244));
+    /// assert_eq!(Color::Red.to_indexed(), Color::Red);
+    /// ```
+    #[must_use = "`to_indexed` returns a new color without modifying the original"]
+    pub fn to_indexed(self) -> Self {
+        let Self::Rgb(r, g, b) = self else {
+            return self;
+        };
+        let cube_index = |value: u8| match value {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (value - 35) / 40,
+        };
+        let cube = 16 + 36 * cube_index(r) + 6 * cube_index(g) + cube_index(b);
+        let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+        let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+        let [cube, gray] = [cube, gray].map(|index| {
+            let distance = color_distance((r, g, b), indexed_to_rgb(index));
+            (distance, index)
+        });
+        Self::Indexed(cube.min(gray).1)
+    }
+
+    /// Returns the closest of the 16 named ANSI colors.
+    ///
+    /// [`Color::Rgb`] and [`Color::Indexed`] colors are mapped to the named color whose default
+    /// xterm value (see [`Color::to_rgb`]) is the closest. Named colors and [`Color::Reset`] are
+    /// returned unchanged.
+    ///
+    /// This is useful to render RGB or indexed colors on terminals that only support 16 colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(250, 10, 10).to_ansi(), Color::LightRed);
+    /// assert_eq!(Color::Indexed(21).to_ansi(), Color::Blue);
+    /// assert_eq!(Color::Indexed(9).to_ansi(), Color::LightRed);
+    /// ```
+    #[must_use = "`to_ansi` returns a new color without modifying the original"]
+    pub fn to_ansi(self) -> Self {
+        let rgb = match self {
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Indexed(index) => indexed_to_rgb(index),
+            color => return color,
+        };
+        ANSI_COLORS
+            .into_iter()
+            .zip(ANSI_PALETTE)
+            .min_by_key(|&(_, value)| color_distance(rgb, value))
+            .map_or(self, |(color, _)| color)
+    }
+}
+
+/// The named colors, in the order of the ANSI color codes.
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// Returns the squared euclidean distance between two RGB values.
+fn color_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+/// The default xterm values of the 16 ANSI colors.
+const ANSI_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The channel intensities used by the xterm 6x6x6 color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Maps an index in the xterm 256 color palette to its RGB value.
+const fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_PALETTE[index as usize],
+        16..=231 => {
+            let index = (index - 16) as usize;
+            (
+                CUBE_LEVELS[index / 36],
+                CUBE_LEVELS[(index / 6) % 6],
+                CUBE_LEVELS[index % 6],
+            )
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -480,13 +773,137 @@ mod tests {
 
     #[cfg(feature = "palette")]
     use palette::{Hsl, Hsluv};
-    #[cfg(feature = "palette")]
     use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
 
     use super::*;
 
+    #[test]
+    fn blend_rgb() {
+        let from = Color::Rgb(0, 100, 255);
+        let to = Color::Rgb(255, 0, 255);
+        assert_eq!(from.blend(to, 0.0), from);
+        assert_eq!(from.blend(to, 0.5), Color::Rgb(128, 50, 255));
+        assert_eq!(from.blend(to, 1.0), to);
+        assert_eq!(from.blend(to, -1.0), from);
+        assert_eq!(from.blend(to, 2.0), to);
+    }
+
+    #[rstest]
+    #[case::named(Color::Black, Color::Red, 0.5, Color::Rgb(103, 0, 0))]
+    #[case::indexed(Color::Indexed(16), Color::Indexed(231), 0.25, Color::Rgb(64, 64, 64))]
+    #[case::named_transparent(Color::Red, Color::Blue, 0.0, Color::Red)]
+    #[case::named_opaque(Color::Red, Color::Blue, 1.0, Color::Blue)]
+    #[case::reset_below(Color::Reset, Color::Red, 0.5, Color::Reset)]
+    #[case::reset_above(Color::Red, Color::Reset, 0.99, Color::Red)]
+    #[case::reset_opaque(Color::Indexed(1), Color::Reset, 1.0, Color::Reset)]
+    #[case::reset_clamped(Color::Red, Color::Reset, 2.0, Color::Reset)]
+    fn blend_non_rgb(
+        #[case] color: Color,
+        #[case] other: Color,
+        #[case] alpha: f32,
+        #[case] expected: Color,
+    ) {
+        assert_eq!(color.blend(other, alpha), expected);
+    }
+
+    #[rstest]
+    #[case::reset(Color::Reset, None)]
+    #[case::rgb(Color::Rgb(1, 2, 3), Some((1, 2, 3)))]
+    #[case::red(Color::Red, Some((205, 0, 0)))]
+    #[case::dark_gray(Color::DarkGray, Some((127, 127, 127)))]
+    #[case::white(Color::White, Some((255, 255, 255)))]
+    #[case::indexed_ansi(Color::Indexed(12), Some((92, 92, 255)))]
+    #[case::cube_start(Color::Indexed(16), Some((0, 0, 0)))]
+    #[case::cube_red(Color::Indexed(196), Some((255, 0, 0)))]
+    #[case::cube_mixed(Color::Indexed(67), Some((95, 135, 175)))]
+    #[case::cube_end(Color::Indexed(231), Some((255, 255, 255)))]
+    #[case::grayscale_start(Color::Indexed(232), Some((8, 8, 8)))]
+    #[case::grayscale_mid(Color::Indexed(244), Some((128, 128, 128)))]
+    #[case::grayscale_end(Color::Indexed(255), Some((238, 238, 238)))]
+    fn to_rgb(#[case] color: Color, #[case] expected: Option<(u8, u8, u8)>) {
+        assert_eq!(color.to_rgb(), expected);
+    }
+
+    #[rstest]
+    #[case::black(Color::Black, Color::DarkGray)]
+    #[case::red(Color::Red, Color::LightRed)]
+    #[case::green(Color::Green, Color::LightGreen)]
+    #[case::yellow(Color::Yellow, Color::LightYellow)]
+    #[case::blue(Color::Blue, Color::LightBlue)]
+    #[case::magenta(Color::Magenta, Color::LightMagenta)]
+    #[case::cyan(Color::Cyan, Color::LightCyan)]
+    #[case::gray(Color::Gray, Color::White)]
+    #[case::indexed_first(Color::Indexed(0), Color::Indexed(8))]
+    #[case::indexed_last(Color::Indexed(7), Color::Indexed(15))]
+    fn bright_and_dim(#[case] base: Color, #[case] bright: Color) {
+        assert_eq!(base.bright(), bright);
+        assert_eq!(bright.dim(), base);
+        assert_eq!(bright.bright(), bright);
+        assert_eq!(base.dim(), base);
+    }
+
+    #[rstest]
+    #[case::reset(Color::Reset)]
+    #[case::rgb(Color::Rgb(1, 2, 3))]
+    #[case::indexed(Color::Indexed(16))]
+    fn bright_and_dim_unchanged(#[case] color: Color) {
+        assert_eq!(color.bright(), color);
+        assert_eq!(color.dim(), color);
+    }
+
+    #[rstest]
+    #[case::cube(Color::Rgb(255, 128, 0), Color::Indexed(208))]
+    #[case::cube_exact(Color::Rgb(95, 135, 175), Color::Indexed(67))]
+    #[case::grayscale(Color::Rgb(120, 122, 121), Color::Indexed(243))]
+    #[case::black(Color::Rgb(0, 0, 0), Color::Indexed(16))]
+    #[case::white(Color::Rgb(255, 255, 255), Color::Indexed(231))]
+    #[case::indexed(Color::Indexed(3), Color::Indexed(3))]
+    #[case::named(Color::Blue, Color::Blue)]
+    #[case::reset(Color::Reset, Color::Reset)]
+    fn to_indexed(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.to_indexed(), expected);
+    }
+
+    #[rstest]
+    #[case::orange(Color::Rgb(255, 128, 0), Color::Yellow)]
+    #[case::dark_red(Color::Rgb(150, 20, 10), Color::Red)]
+    #[case::gray(Color::Rgb(120, 122, 121), Color::DarkGray)]
+    #[case::cube(Color::Indexed(208), Color::Yellow)]
+    #[case::grayscale(Color::Indexed(255), Color::Gray)]
+    #[case::indexed_ansi(Color::Indexed(12), Color::LightBlue)]
+    #[case::named(Color::Magenta, Color::Magenta)]
+    #[case::reset(Color::Reset, Color::Reset)]
+    fn to_ansi(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.to_ansi(), expected);
+    }
+
+    #[test]
+    fn named_colors_match_indexed() {
+        let named = [
+            Color::Black,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::Gray,
+            Color::DarkGray,
+            Color::LightRed,
+            Color::LightGreen,
+            Color::LightYellow,
+            Color::LightBlue,
+            Color::LightMagenta,
+            Color::LightCyan,
+            Color::White,
+        ];
+        for (index, color) in (0..).zip(named) {
+            assert_eq!(color.to_rgb(), Color::Indexed(index).to_rgb());
+        }
+    }
+
     #[cfg(feature = "palette")]
     #[rstest]
     #[case::black(Hsl::new(0.0, 0.0, 0.0), Color::Rgb(0, 0, 0))]
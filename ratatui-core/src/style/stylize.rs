@@ -192,7 +192,8 @@ macro_rules! modifier {
 ///
 /// This trait implements a provided method for every color as both foreground and background
 /// (prefixed by `on_`), and all modifiers as both an additive and subtractive modifier (prefixed
-/// by `not_`). The `reset()` method is also provided to reset the style.
+/// by `not_`). The `reset()` method (also available as `reset_style()`) is also provided to reset
+/// the style.
 ///
 /// # Examples
 /// ```ignore
@@ -217,6 +218,14 @@ pub trait Stylize<'a, T>: Sized {
     fn fg<C: Into<Color>>(self, color: C) -> T;
     #[must_use = "`reset` returns the modified style without modifying the original"]
     fn reset(self) -> T;
+    /// Resets the style, same as [`Stylize::reset`].
+    ///
+    /// This is useful on types that have their own `reset` method, which would otherwise shadow
+    /// the one from this trait.
+    #[must_use = "`reset_style` returns the modified style without modifying the original"]
+    fn reset_style(self) -> T {
+        self.reset()
+    }
     #[must_use = "`add_modifier` returns the modified style without modifying the original"]
     fn add_modifier(self, modifier: Modifier) -> T;
     #[must_use = "`remove_modifier` returns the modified style without modifying the original"]
@@ -433,6 +442,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reset_style() {
+        assert_eq!("hello".red().bold().reset_style(), "hello".reset());
+    }
+
+    #[test]
+    fn not_modifier_removes_the_modifier() {
+        let span = "hello".bold().italic().not_bold();
+        assert!(!span.style.add_modifier.contains(Modifier::BOLD));
+        assert!(span.style.sub_modifier.contains(Modifier::BOLD));
+        assert_eq!(span.style.add_modifier, Modifier::ITALIC);
+
+        let style = Style::new().bold().not_bold();
+        assert!(!style.add_modifier.contains(Modifier::BOLD));
+    }
+
     #[test]
     fn fg() {
         let cyan_fg = Style::default().fg(Color::Cyan);
@@ -49,3 +49,85 @@ pub const NINE_LEVELS: Set = Set {
     one_eighth: ONE_EIGHTH,
     empty: " ",
 };
+
+/// Ramps of eighth blocks, ordered from empty to full, used to draw smooth fills.
+///
+/// The [`HORIZONTAL`](ramp::HORIZONTAL) ramp grows from the left of the cell (`▏` to `█`) and is
+/// suited to horizontal bars and gauges, while the [`VERTICAL`](ramp::VERTICAL) ramp grows from
+/// the bottom of the cell (`▁` to `█`) and is suited to vertical bars and sparklines.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::symbols::block::ramp;
+///
+/// assert_eq!(ramp::HORIZONTAL[4], "▌");
+/// assert_eq!(ramp::glyph(&ramp::VERTICAL, 0.5), "▄");
+/// assert_eq!(ramp::glyph(&ramp::HORIZONTAL, 1.0), "█");
+/// ```
+pub mod ramp {
+    use crate::symbols::{bar, block};
+
+    /// Eighth blocks growing from the left of the cell, from empty to full.
+    pub const HORIZONTAL: [&str; 9] = [
+        " ",
+        block::ONE_EIGHTH,
+        block::ONE_QUARTER,
+        block::THREE_EIGHTHS,
+        block::HALF,
+        block::FIVE_EIGHTHS,
+        block::THREE_QUARTERS,
+        block::SEVEN_EIGHTHS,
+        block::FULL,
+    ];
+
+    /// Eighth blocks growing from the bottom of the cell, from empty to full.
+    pub const VERTICAL: [&str; 9] = [
+        " ",
+        bar::ONE_EIGHTH,
+        bar::ONE_QUARTER,
+        bar::THREE_EIGHTHS,
+        bar::HALF,
+        bar::FIVE_EIGHTHS,
+        bar::THREE_QUARTERS,
+        bar::SEVEN_EIGHTHS,
+        bar::FULL,
+    ];
+
+    /// Returns the glyph of `ramp` that best represents a cell filled by `fraction`.
+    ///
+    /// `fraction` is rounded to the nearest eighth. Values below `0.0` (and `NaN`) give the empty
+    /// glyph and values above `1.0` give the full glyph.
+    pub fn glyph(ramp: &[&'static str; 9], fraction: f64) -> &'static str {
+        let index = (fraction.clamp(0.0, 1.0) * 8.0).round() as usize;
+        ramp[index]
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use rstest::rstest;
+
+        use super::*;
+
+        #[rstest]
+        #[case::empty(0.0, " ")]
+        #[case::below_half_an_eighth(0.06, " ")]
+        #[case::one_eighth(0.125, "▏")]
+        #[case::rounded_to_half(0.52, "▌")]
+        #[case::full(1.0, "█")]
+        #[case::negative(-1.0, " ")]
+        #[case::above_one(2.0, "█")]
+        #[case::nan(f64::NAN, " ")]
+        fn horizontal(#[case] fraction: f64, #[case] expected: &str) {
+            assert_eq!(glyph(&HORIZONTAL, fraction), expected);
+        }
+
+        #[test]
+        fn vertical() {
+            let glyphs: String = (0..=8)
+                .map(|i| glyph(&VERTICAL, f64::from(i) / 8.0))
+                .collect();
+            assert_eq!(glyphs, " ▁▂▃▄▅▆▇█");
+        }
+    }
+}
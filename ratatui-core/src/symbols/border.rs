@@ -1,4 +1,7 @@
-use crate::symbols::{block, line};
+use crate::{
+    symbols::{block, line},
+    text::WidthMode,
+};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct Set {
@@ -18,6 +21,102 @@ impl Default for Set {
     }
 }
 
+impl Set {
+    /// Creates a new border set from its corner and edge symbols.
+    ///
+    /// The corners are given in the order top left, top right, bottom left, bottom right and the
+    /// edges in the order left, right, top, bottom (matching the order of the fields of [`Set`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the symbols is not exactly one column wide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::symbols::border;
+    ///
+    /// let set = border::Set::from_corners_and_edges(["+", "+", "+", "+"], ["|", "|", "-", "-"]);
+    /// assert_eq!(set.top_left, "+");
+    /// assert_eq!(set.horizontal_top, "-");
+    /// ```
+    pub fn from_corners_and_edges(corners: [&'static str; 4], edges: [&'static str; 4]) -> Self {
+        PLAIN.with_corners(corners).with_edges(edges)
+    }
+
+    /// Replaces the corner symbols of this border set.
+    ///
+    /// The corners are given in the order top left, top right, bottom left, bottom right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the symbols is not exactly one column wide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::symbols::border;
+    ///
+    /// // a plain border with rounded corners
+    /// let set = border::PLAIN.with_corners(["╭", "╮", "╰", "╯"]);
+    /// assert_eq!(set, border::ROUNDED);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_corners(self, corners: [&'static str; 4]) -> Self {
+        assert_single_column(&corners);
+        let [top_left, top_right, bottom_left, bottom_right] = corners;
+        Self {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            ..self
+        }
+    }
+
+    /// Replaces the edge symbols of this border set.
+    ///
+    /// The edges are given in the order left, right, top, bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the symbols is not exactly one column wide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::symbols::border;
+    ///
+    /// // a border that only draws the corners
+    /// let corners_only = border::PLAIN.with_edges([" "; 4]);
+    /// assert_eq!(corners_only.top_left, "┌");
+    /// assert_eq!(corners_only.horizontal_top, " ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_edges(self, edges: [&'static str; 4]) -> Self {
+        assert_single_column(&edges);
+        let [vertical_left, vertical_right, horizontal_top, horizontal_bottom] = edges;
+        Self {
+            vertical_left,
+            vertical_right,
+            horizontal_top,
+            horizontal_bottom,
+            ..self
+        }
+    }
+}
+
+fn assert_single_column(symbols: &[&str]) {
+    for symbol in symbols {
+        // borders are drawn with one symbol per cell, so the box drawing characters, which are
+        // ambiguous width, are measured as narrow regardless of the current width mode
+        assert!(
+            WidthMode::Narrow.width(symbol) == 1,
+            "border symbol {symbol:?} must be exactly one column wide"
+        );
+    }
+}
+
 // Helper function to convert a line set to a border set
 const fn from_line_set(line_set: line::Set) -> Set {
     Set {
@@ -374,6 +473,56 @@ mod tests {
         assert_eq!(Set::default(), PLAIN);
     }
 
+    #[test]
+    fn from_corners_and_edges() {
+        let set = Set::from_corners_and_edges(["a", "b", "c", "d"], ["e", "f", "g", "h"]);
+        assert_eq!(
+            set,
+            Set {
+                top_left: "a",
+                top_right: "b",
+                bottom_left: "c",
+                bottom_right: "d",
+                vertical_left: "e",
+                vertical_right: "f",
+                horizontal_top: "g",
+                horizontal_bottom: "h",
+            }
+        );
+    }
+
+    #[test]
+    fn with_corners() {
+        assert_eq!(PLAIN.with_corners(["╭", "╮", "╰", "╯"]), ROUNDED);
+    }
+
+    #[test]
+    fn with_edges() {
+        assert_eq!(
+            render(PLAIN.with_edges([" "; 4])),
+            indoc!(
+                "░░░░░░
+                 ░┌  ┐░
+                 ░ ░░ ░
+                 ░ ░░ ░
+                 ░└  ┘░
+                 ░░░░░░"
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic = "border symbol \"\" must be exactly one column wide"]
+    fn with_edges_empty_symbol() {
+        let _ = PLAIN.with_edges(["", " ", " ", " "]);
+    }
+
+    #[test]
+    #[should_panic = "border symbol \"ab\" must be exactly one column wide"]
+    fn with_corners_wide_symbol() {
+        let _ = PLAIN.with_corners(["ab", "+", "+", "+"]);
+    }
+
     /// A helper function to render a border set to a string.
     ///
     /// '░' (U+2591 Light Shade) is used as a placeholder for empty space to make it easier to see
@@ -44,3 +44,32 @@ pub const HORIZONTAL: Set = Set {
     begin: "←",
     end: "→",
 };
+
+/// Partial thumb glyphs of a smooth scrollbar
+///
+/// `eighths[n - 1]` fills `n` eighths of a cell, starting from the bottom edge of the cell for
+/// vertical scrollbars and from the left edge for horizontal ones.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct SmoothSet {
+    /// The glyphs filling one to seven eighths of a cell, in that order
+    ///
+    /// A cell covered by the whole thumb uses the `thumb` symbol of the [`Set`] instead.
+    pub eighths: [&'static str; 7],
+}
+
+impl SmoothSet {
+    /// Takes the partial glyphs of an eighth block [`ramp`](block::ramp), leaving out its empty
+    /// and full glyphs.
+    const fn from_ramp(ramp: &[&'static str; 9]) -> Self {
+        let [_, one, two, three, four, five, six, seven, _] = *ramp;
+        Self {
+            eighths: [one, two, three, four, five, six, seven],
+        }
+    }
+}
+
+/// Partial thumb glyphs of a smooth vertical scrollbar, from `▁` to `▇`
+pub const SMOOTH_VERTICAL: SmoothSet = SmoothSet::from_ramp(&block::ramp::VERTICAL);
+
+/// Partial thumb glyphs of a smooth horizontal scrollbar, from `▏` to `▉`
+pub const SMOOTH_HORIZONTAL: SmoothSet = SmoothSet::from_ramp(&block::ramp::HORIZONTAL);
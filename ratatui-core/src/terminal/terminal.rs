@@ -1,7 +1,7 @@
 use std::io;
 
 use crate::{
-    backend::{Backend, ClearType},
+    backend::{Backend, ClearType, ResizeReceiver, WindowSize},
     buffer::{Buffer, Cell},
     layout::{Position, Rect, Size},
     terminal::{CompletedFrame, Frame, TerminalOptions, Viewport},
@@ -66,6 +66,8 @@ where
     current: usize,
     /// Whether the cursor is currently hidden
     hidden_cursor: bool,
+    /// Whether a visible cursor is hidden while the frame is written to the backend
+    hide_cursor_during_draw: bool,
     /// Viewport
     viewport: Viewport,
     /// Area of the viewport
@@ -159,6 +161,7 @@ where
             buffers: [Buffer::empty(viewport_area), Buffer::empty(viewport_area)],
             current: 0,
             hidden_cursor: false,
+            hide_cursor_during_draw: false,
             viewport: options.viewport,
             viewport_area,
             last_known_area: area,
@@ -178,6 +181,48 @@ where
         }
     }
 
+    /// Renders a frame into a new off-screen [`Buffer`] covering `area`, without drawing it.
+    ///
+    /// The closure is given a [`Frame`] whose [`area`](Frame::area) is `area`, exactly like the
+    /// one given to [`Terminal::draw`], so widgets render identically. Neither the backend nor the
+    /// terminal buffers are touched, and the cursor position set in the frame is ignored.
+    ///
+    /// The returned buffer can be presented later, for instance by merging it into the buffer of
+    /// a frame with [`Buffer::merge`]. Rendering several screens off-screen and compositing them
+    /// in a single [`Terminal::draw`] call makes it possible to implement transitions such as
+    /// wipes or slides.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{backend::TestBackend, layout::Rect, terminal::Terminal, text::Line};
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(10, 2))?;
+    /// let area = Rect::new(0, 1, 10, 1);
+    /// let offscreen = terminal.render_offscreen(area, |frame| {
+    ///     frame.render_widget(Line::from("Hello"), frame.area());
+    /// });
+    /// terminal.draw(|frame| frame.buffer_mut().merge(&offscreen))?;
+    /// terminal
+    ///     .backend()
+    ///     .assert_buffer_lines(["          ", "Hello     "]);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn render_offscreen<F>(&self, area: Rect, draw_fn: F) -> Buffer
+    where
+        F: FnOnce(&mut Frame),
+    {
+        let mut buffer = Buffer::empty(area);
+        let mut frame = Frame {
+            cursor_position: None,
+            viewport_area: area,
+            buffer: &mut buffer,
+            count: self.frame_count,
+        };
+        draw_fn(&mut frame);
+        buffer
+    }
+
     /// Gets the current buffer as a mutable reference.
     pub fn current_buffer_mut(&mut self) -> &mut Buffer {
         &mut self.buffers[self.current]
@@ -233,6 +278,99 @@ where
         Ok(())
     }
 
+    /// Returns the size of the terminal as of the last resize.
+    ///
+    /// Unlike [`Terminal::size`], this does not query the backend. The value is updated whenever
+    /// the terminal is resized, either explicitly with [`Terminal::resize`] or by
+    /// [`Terminal::autoresize`], and when the viewport is replaced with [`Terminal::set_viewport`].
+    ///
+    /// To react to a resize before the frame that observes it is rendered, call
+    /// [`Terminal::resize_if_needed`] before [`Terminal::draw`].
+    pub const fn last_known_size(&self) -> Size {
+        self.last_known_area.as_size()
+    }
+
+    /// Returns whether the cursor is hidden while a frame is written to the backend.
+    ///
+    /// See [`Terminal::set_hide_cursor_during_draw`] for more information.
+    pub const fn hides_cursor_during_draw(&self) -> bool {
+        self.hide_cursor_during_draw
+    }
+
+    /// Sets whether the cursor is hidden while a frame is written to the backend.
+    ///
+    /// Writing a frame moves the cursor to every changed cell. When the cursor is visible, some
+    /// terminals show it jumping across the screen while this happens. When this is enabled,
+    /// [`Terminal::draw`] hides a visible cursor before writing the changes, and then moves it to
+    /// the position set in the [`Frame`] before showing it again. If the frame does not set a
+    /// cursor position, the cursor stays hidden as usual.
+    ///
+    /// This is disabled by default, as it writes extra hide and show sequences on every frame.
+    pub fn set_hide_cursor_during_draw(&mut self, hide: bool) {
+        self.hide_cursor_during_draw = hide;
+    }
+
+    /// Returns the current viewport.
+    pub const fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Replaces the viewport of the terminal at runtime.
+    ///
+    /// This makes it possible to switch between inline and fullscreen viewports (e.g. to enter a
+    /// temporary "peek" mode) without constructing a new [`Terminal`]. The internal buffers are
+    /// reallocated to match the new viewport area and the next call to [`Terminal::draw`] will
+    /// redraw everything.
+    ///
+    /// The area used by the previous viewport is always cleared first. What happens next depends
+    /// on the new viewport:
+    ///
+    /// - [`Viewport::Fullscreen`]: the whole screen is cleared, including any content that was
+    ///   printed above a previous inline viewport.
+    /// - [`Viewport::Inline`]: the new viewport starts at the top row of the previous viewport (the
+    ///   top of the screen when switching from fullscreen) and lines are appended below it if there
+    ///   is not enough room. Content above the viewport is preserved.
+    /// - [`Viewport::Fixed`]: the rows of the new fixed area are cleared. Content outside of the
+    ///   area is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ratatui::{backend::TestBackend, Terminal, TerminalOptions, Viewport};
+    ///
+    /// let backend = TestBackend::new(10, 10);
+    /// let options = TerminalOptions {
+    ///     viewport: Viewport::Inline(3),
+    /// };
+    /// let mut terminal = Terminal::with_options(backend, options)?;
+    /// terminal.set_viewport(Viewport::Fullscreen)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_viewport(&mut self, viewport: Viewport) -> io::Result<()> {
+        self.clear()?;
+
+        let area = match viewport {
+            Viewport::Fullscreen | Viewport::Inline(_) => {
+                Rect::from((Position::ORIGIN, self.backend.size()?))
+            }
+            Viewport::Fixed(area) => area,
+        };
+        let (viewport_area, cursor_pos) = match viewport {
+            Viewport::Fullscreen => (area, Position::ORIGIN),
+            Viewport::Inline(height) => {
+                let top = Position::new(0, self.viewport_area.top());
+                self.backend.set_cursor_position(top)?;
+                compute_inline_size(&mut self.backend, height, area.as_size(), 0)?
+            }
+            Viewport::Fixed(area) => (area, area.as_position()),
+        };
+        self.viewport = viewport;
+        self.set_viewport_area(viewport_area);
+        self.last_known_area = area;
+        self.last_known_cursor_pos = cursor_pos;
+        self.clear()
+    }
+
     fn set_viewport_area(&mut self, area: Rect) {
         self.buffers[self.current].resize(area);
         self.buffers[1 - self.current].resize(area);
@@ -240,17 +378,48 @@ where
     }
 
     /// Queries the backend for size and resizes if it doesn't match the previous size.
+    ///
+    /// See [`Terminal::resize_if_needed`] to know whether a resize happened.
     pub fn autoresize(&mut self) -> io::Result<()> {
-        // fixed viewports do not get autoresized
-        if matches!(self.viewport, Viewport::Fullscreen | Viewport::Inline(_)) {
-            let area = Rect::from((Position::ORIGIN, self.size()?));
-            if area != self.last_known_area {
-                self.resize(area)?;
-            }
-        };
+        self.resize_if_needed()?;
         Ok(())
     }
 
+    /// Queries the backend for size and resizes if it doesn't match the previous size, returning
+    /// whether a resize happened.
+    ///
+    /// The internal buffers are only reallocated (and the screen cleared) when the size of the
+    /// terminal differs from [`Terminal::last_known_size`], so calling this on every resize event
+    /// of a burst only does the work once the size actually changes. The return value can be used
+    /// to skip recomputing layouts that depend on the size of the terminal.
+    ///
+    /// Fixed viewports are never resized, so this always returns `false` for them. Inline
+    /// viewports are resized when the size of the whole terminal changes: the viewport takes the
+    /// new width, keeps its requested height (clamped to the new terminal height) and is placed
+    /// again relative to the cursor.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if terminal.resize_if_needed()? {
+    ///     let size = terminal.last_known_size();
+    ///     // recompute layout caches for the new size
+    /// }
+    /// terminal.draw(|frame| { /* ... */ })?;
+    /// ```
+    pub fn resize_if_needed(&mut self) -> io::Result<bool> {
+        // fixed viewports do not get autoresized
+        if matches!(self.viewport, Viewport::Fixed(_)) {
+            return Ok(false);
+        }
+        let area = Rect::from((Position::ORIGIN, self.size()?));
+        if area == self.last_known_area {
+            return Ok(false);
+        }
+        self.resize(area)?;
+        Ok(true)
+    }
+
     /// Draws a single frame to the terminal.
     ///
     /// Returns a [`CompletedFrame`] if successful, otherwise a [`std::io::Error`].
@@ -266,6 +435,7 @@ where
     ///
     /// - autoresize the terminal if necessary
     /// - call the render callback, passing it a [`Frame`] reference to render to
+    /// - hide the cursor if it is visible and [`Terminal::set_hide_cursor_during_draw`] is enabled
     /// - flush the current internal state by copying the current buffer to the backend
     /// - move the cursor to the last known position if it was set during the rendering closure
     /// - return a [`CompletedFrame`] with the current buffer and the area of the terminal
@@ -328,6 +498,7 @@ where
     ///
     /// - autoresize the terminal if necessary
     /// - call the render callback, passing it a [`Frame`] reference to render to
+    /// - hide the cursor if it is visible and [`Terminal::set_hide_cursor_during_draw`] is enabled
     /// - flush the current internal state by copying the current buffer to the backend
     /// - move the cursor to the last known position if it was set during the rendering closure
     /// - return a [`CompletedFrame`] with the current buffer and the area of the terminal
@@ -394,11 +565,22 @@ where
         // Buffer. Thus, we're taking the important data out of the Frame and dropping it.
         let cursor_position = frame.cursor_position;
 
+        // Hide the cursor while it jumps between the changed cells to avoid flickering
+        let hidden_during_draw = self.hide_cursor_during_draw && !self.hidden_cursor;
+        if hidden_during_draw {
+            self.hide_cursor()?;
+        }
+
         // Draw to stdout
         self.flush()?;
 
         match cursor_position {
+            None if hidden_during_draw => {}
             None => self.hide_cursor()?,
+            Some(position) if hidden_during_draw => {
+                self.set_cursor_position(position)?;
+                self.show_cursor()?;
+            }
             Some(position) => {
                 self.show_cursor()?;
                 self.set_cursor_position(position)?;
@@ -500,6 +682,64 @@ where
         self.backend.size()
     }
 
+    /// Queries the backend for the size of the terminal in columns and rows, and in pixels.
+    ///
+    /// See [`WindowSize`] for more information.
+    pub fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.backend.window_size()
+    }
+
+    /// Queries the backend for the size of a single cell in pixels.
+    ///
+    /// Returns `Ok(None)` when the terminal does not report its size in pixels. See
+    /// [`WindowSize::cell_size`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{backend::TestBackend, layout::Size, terminal::Terminal};
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(80, 24))?;
+    /// let cell_size = terminal.cell_size()?.unwrap_or(Size::new(1, 2));
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn cell_size(&mut self) -> io::Result<Option<Size>> {
+        Ok(self.window_size()?.cell_size())
+    }
+
+    /// Returns a channel that receives the new size of the terminal every time it is resized.
+    ///
+    /// This lets an event loop wait for resizes alongside its input events instead of querying
+    /// the [`size`](Self::size) of the terminal on every frame. The terminal is not resized by
+    /// this method, so the next [`draw`](Self::draw) call still picks up the new size through
+    /// [`autoresize`](Self::autoresize).
+    ///
+    /// The Crossterm and Termion backends support this on Unix when their `resize-channel`
+    /// feature is enabled, by listening for the `SIGWINCH` signal on a background thread. See
+    /// [`Backend::resize_rx`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::Unsupported`] error when the backend or platform does not
+    /// support resize notifications.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui_core::{backend::TestBackend, terminal::Terminal};
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(80, 24))?;
+    /// if let Ok(resizes) = terminal.resize_rx() {
+    ///     while let Ok(size) = resizes.recv() {
+    ///         println!("resized to {size}");
+    ///     }
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn resize_rx(&mut self) -> io::Result<ResizeReceiver> {
+        self.backend.resize_rx()
+    }
+
     /// Insert some content before the current inline viewport. This has no effect when the
     /// viewport is not inline.
     ///
@@ -48,6 +48,9 @@
 //! ]);
 //! ```
 
+mod direction;
+pub use direction::TextDirection;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
@@ -57,8 +60,14 @@ pub use line::{Line, ToLine};
 mod masked;
 pub use masked::Masked;
 
+mod overflow;
+pub use overflow::TextOverflow;
+
 mod span;
 pub use span::{Span, ToSpan};
 
 mod text;
 pub use text::{Text, ToText};
+
+mod width;
+pub use width::WidthMode;
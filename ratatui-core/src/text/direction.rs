@@ -0,0 +1,95 @@
+use strum::{Display, EnumString};
+use unicode_bidi::{Level, ParagraphBidiInfo};
+
+use crate::text::WidthMode;
+
+/// The base direction of a line of text.
+///
+/// In a right-to-left line, such as Arabic or Hebrew text, the first character is rendered at the
+/// right end of the line. The text is stored in logical order and reordered for display using the
+/// Unicode Bidirectional Algorithm, so runs of left-to-right text (e.g. Latin words or numbers)
+/// keep their order within the line. When the line does not fit in the available width, the start
+/// of the line is kept visible and its end is truncated.
+///
+/// Mirrored characters such as brackets are not replaced by their mirrored glyph.
+///
+/// See [`Line::direction`](crate::text::Line::direction).
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextDirection {
+    /// The text is placed from left to right
+    #[default]
+    Ltr,
+    /// The text is placed from right to left
+    Rtl,
+}
+
+impl TextDirection {
+    /// Returns the column of each grapheme of a row from the start of the row, once the row is
+    /// reordered for display in this direction.
+    ///
+    /// Returns `None` for [`TextDirection::Ltr`], where the graphemes are displayed in the order
+    /// they are given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::text::TextDirection;
+    ///
+    /// let columns = TextDirection::Rtl.visual_columns(["א", "ב", " ", "1", "2"]);
+    /// assert_eq!(columns, Some(vec![4, 3, 2, 0, 1]));
+    /// ```
+    pub fn visual_columns<'s, I>(self, graphemes: I) -> Option<Vec<u16>>
+    where
+        I: IntoIterator<Item = &'s str>,
+    {
+        if self == Self::Ltr {
+            return None;
+        }
+        let width_mode = WidthMode::current();
+        let mut text = String::new();
+        let mut starts = Vec::new();
+        let mut widths = Vec::new();
+        for symbol in graphemes {
+            starts.push(text.len());
+            widths.push(u16::try_from(width_mode.width(symbol)).unwrap_or(u16::MAX));
+            text.push_str(symbol);
+        }
+        let info = ParagraphBidiInfo::new(&text, Some(Level::rtl()));
+        let byte_levels = info.reordered_levels(0..text.len());
+        let levels: Vec<Level> = starts
+            .iter()
+            .map(|&start| byte_levels.get(start).copied().unwrap_or_else(Level::rtl))
+            .collect();
+        let mut columns = vec![0; starts.len()];
+        let mut x: u16 = 0;
+        for index in ParagraphBidiInfo::reorder_visual(&levels) {
+            columns[index] = x;
+            x = x.saturating_add(widths[index]);
+        }
+        Some(columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::ltr(TextDirection::Ltr, &["a", "b"], None)]
+    #[case::rtl(TextDirection::Rtl, &["א", "ב"], Some(vec![1, 0]))]
+    #[case::latin_run(TextDirection::Rtl, &["א", " ", "a", "b"], Some(vec![3, 2, 0, 1]))]
+    #[case::wide(TextDirection::Rtl, &["א", "表"], Some(vec![2, 0]))]
+    #[case::empty(TextDirection::Rtl, &[], Some(vec![]))]
+    fn visual_columns(
+        #[case] direction: TextDirection,
+        #[case] graphemes: &[&str],
+        #[case] expected: Option<Vec<u16>>,
+    ) {
+        assert_eq!(
+            direction.visual_columns(graphemes.iter().copied()),
+            expected
+        );
+    }
+}
@@ -1,14 +1,14 @@
 #![deny(missing_docs)]
 #![warn(clippy::pedantic, clippy::nursery, clippy::arithmetic_side_effects)]
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
 
-use unicode_truncate::UnicodeTruncateStr;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
     buffer::Buffer,
     layout::{Alignment, Rect},
     style::{Style, Styled},
-    text::{Span, StyledGrapheme, Text},
+    text::{Span, StyledGrapheme, Text, TextDirection, WidthMode},
     widgets::Widget,
 };
 
@@ -46,6 +46,7 @@ use crate::{
 /// - [`Line::left_aligned`] sets the alignment of the line to [`Alignment::Left`].
 /// - [`Line::centered`] sets the alignment of the line to [`Alignment::Center`].
 /// - [`Line::right_aligned`] sets the alignment of the line to [`Alignment::Right`].
+/// - [`Line::direction`] sets the base direction of the line.
 ///
 /// # Iteration Methods
 ///
@@ -189,6 +190,9 @@ pub struct Line<'a> {
     /// The alignment of this line of text.
     pub alignment: Option<Alignment>,
 
+    /// The base direction of this line of text.
+    pub direction: Option<TextDirection>,
+
     /// The spans that make up this line of text.
     pub spans: Vec<Span<'a>>,
 }
@@ -212,9 +216,14 @@ impl fmt::Debug for Line<'_> {
         }
         self.style.fmt_stylize(f)?;
         match self.alignment {
-            Some(Alignment::Left) => write!(f, ".left_aligned()"),
-            Some(Alignment::Center) => write!(f, ".centered()"),
-            Some(Alignment::Right) => write!(f, ".right_aligned()"),
+            Some(Alignment::Left) => f.write_str(".left_aligned()")?,
+            Some(Alignment::Center) => f.write_str(".centered()")?,
+            Some(Alignment::Right) => f.write_str(".right_aligned()")?,
+            None => {}
+        }
+        match self.direction {
+            Some(TextDirection::Ltr) => f.write_str(".direction(TextDirection::Ltr)"),
+            Some(TextDirection::Rtl) => f.write_str(".direction(TextDirection::Rtl)"),
             None => Ok(()),
         }
     }
@@ -375,6 +384,30 @@ impl<'a> Line<'a> {
         }
     }
 
+    /// Sets the base direction of this line of text.
+    ///
+    /// In a [`TextDirection::Rtl`] line, the text is reordered for display using the Unicode
+    /// Bidirectional Algorithm, so the first character is at the right end of the line. See
+    /// [`TextDirection`] for more information.
+    ///
+    /// When the direction is not set, the line is rendered left to right, or in the direction of
+    /// the parent widget (e.g. a `Paragraph`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Line, Span, TextDirection};
+    ///
+    /// let line = Line::from(vec![Span::raw("שלום"), Span::raw(" "), Span::raw("עולם")])
+    ///     .direction(TextDirection::Rtl)
+    ///     .right_aligned();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
     /// Left-aligns this line of text.
     ///
     /// Convenience shortcut for `Line::alignment(Alignment::Left)`.
@@ -543,6 +576,110 @@ impl<'a> Line<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Patches the style of the part of the line that covers the given range of display columns.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// Columns are counted from the start of the line, ignoring its alignment. Spans that are
+    /// partially covered by the range are split at grapheme boundaries, and the style of the
+    /// covered part is patched with `style` (see [`Span::patch_style`]). The style of the line
+    /// itself is left unchanged.
+    ///
+    /// A wide grapheme that is only partially covered by the range (e.g. a range ending in the
+    /// middle of a full-width character) is styled as a whole: a grapheme is styled if any of
+    /// its columns is in the range. Zero-width graphemes are styled if the column they are
+    /// attached to is in the range.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// Highlight a search match in an existing line:
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Style, Stylize},
+    ///     text::{Line, Span},
+    /// };
+    ///
+    /// let line = Line::from(vec![Span::raw("Hello "), Span::raw("world")]);
+    /// let line = line.styled_range(3..8, Style::new().yellow());
+    /// assert_eq!(
+    ///     line.spans,
+    ///     vec![
+    ///         Span::raw("Hel"),
+    ///         Span::raw("lo ").yellow(),
+    ///         Span::raw("wo").yellow(),
+    ///         Span::raw("rld"),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn styled_range<S: Into<Style>>(mut self, range: Range<u16>, style: S) -> Self {
+        let style = style.into();
+        let (start, end) = (usize::from(range.start), usize::from(range.end));
+        if start >= end {
+            return self;
+        }
+        let mut spans = Vec::with_capacity(self.spans.len().saturating_add(2));
+        let mut column = 0_usize;
+        let width_mode = WidthMode::current();
+        for span in self.spans {
+            let span_start = column;
+            let span_end = span_start.saturating_add(span.width());
+            column = span_end;
+            if span_end <= start || span_start >= end {
+                spans.push(span);
+                continue;
+            }
+            // split the span into runs of graphemes that are all inside or all outside the range
+            let mut x = span_start;
+            let mut run: Option<(bool, usize)> = None;
+            let mut runs = Vec::new();
+            for (offset, grapheme) in span.content.grapheme_indices(true) {
+                let grapheme_width = width_mode.width(grapheme);
+                let width = grapheme_width.max(1);
+                let covered = x < end && x.saturating_add(width) > start;
+                x = x.saturating_add(grapheme_width);
+                match run {
+                    Some((run_covered, _)) if run_covered == covered => {}
+                    Some((run_covered, run_start)) => {
+                        runs.push((run_covered, run_start..offset));
+                        run = Some((covered, offset));
+                    }
+                    None => run = Some((covered, offset)),
+                }
+            }
+            if let Some((covered, run_start)) = run {
+                runs.push((covered, run_start..span.content.len()));
+            }
+            if let [(true, _)] = runs.as_slice() {
+                spans.push(span.patch_style(style));
+                continue;
+            }
+            for (covered, range) in runs {
+                let content = match &span.content {
+                    // the ranges are grapheme boundaries, so they are always valid
+                    Cow::Borrowed(content) => Cow::Borrowed(content.get(range).unwrap_or_default()),
+                    Cow::Owned(content) => {
+                        Cow::Owned(content.get(range).unwrap_or_default().to_string())
+                    }
+                };
+                let part = Span::styled(content, span.style);
+                spans.push(if covered {
+                    part.patch_style(style)
+                } else {
+                    part
+                });
+            }
+        }
+        self.spans = spans;
+        self
+    }
+
     /// Returns an iterator over the spans of this line.
     pub fn iter(&self) -> std::slice::Iter<Span<'a>> {
         self.spans.iter()
@@ -715,6 +852,10 @@ impl Line<'_> {
         buf.set_style(area, self.style);
 
         let alignment = self.alignment.or(parent_alignment);
+        if self.direction == Some(TextDirection::Rtl) {
+            self.render_rtl(area, buf, alignment, line_width);
+            return;
+        }
 
         let area_width = usize::from(area.width);
         let can_render_complete_line = line_width <= area_width;
@@ -738,6 +879,57 @@ impl Line<'_> {
             render_spans(&self.spans, area, buf, skip_width);
         };
     }
+
+    /// Renders a right-to-left line, reordered with [`TextDirection::visual_columns`].
+    ///
+    /// The line starts at its right end, so when it does not fit in the area its left end is
+    /// truncated to keep the start visible.
+    fn render_rtl(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        alignment: Option<Alignment>,
+        line_width: usize,
+    ) {
+        let graphemes: Vec<StyledGrapheme> = self
+            .spans
+            .iter()
+            .flat_map(|span| span.styled_graphemes(Style::default()))
+            .collect();
+        let columns = TextDirection::Rtl
+            .visual_columns(graphemes.iter().map(|grapheme| grapheme.symbol))
+            .unwrap_or_default();
+        let area_width = usize::from(area.width);
+        let (indent, skip) = if line_width <= area_width {
+            let indent = match alignment {
+                Some(Alignment::Center) => area_width.saturating_sub(line_width) / 2,
+                Some(Alignment::Right) => area_width.saturating_sub(line_width),
+                Some(Alignment::Left) | None => 0,
+            };
+            (indent, 0)
+        } else {
+            let skip = match alignment {
+                Some(Alignment::Center) => line_width.saturating_sub(area_width) / 2,
+                _ => line_width.saturating_sub(area_width),
+            };
+            (0, skip)
+        };
+        let width_mode = WidthMode::current();
+        for (grapheme, column) in graphemes.iter().zip(columns) {
+            let width = width_mode.width(grapheme.symbol);
+            let Some(x) = usize::from(column).checked_sub(skip) else {
+                continue;
+            };
+            if width == 0 || x.saturating_add(width) > area_width {
+                continue;
+            }
+            let x = u16::try_from(indent.saturating_add(x)).unwrap_or(u16::MAX);
+            let x = area.x.saturating_add(x);
+            buf[(x, area.y)]
+                .set_symbol(grapheme.symbol)
+                .set_style(grapheme.style);
+        }
+    }
 }
 
 /// Renders all the spans of the line that should be visible.
@@ -759,9 +951,10 @@ fn spans_after_width<'a>(
     spans: &'a [Span],
     mut skip_width: usize,
 ) -> impl Iterator<Item = (Span<'a>, usize, u16)> {
+    let width_mode = WidthMode::current();
     spans
         .iter()
-        .map(|span| (span, span.width()))
+        .map(move |span| (span, width_mode.width(&span.content)))
         // Filter non visible spans out.
         .filter_map(move |(span, span_width)| {
             // Ignore spans that are completely before the offset. Decrement `span_skip_width` by
@@ -777,14 +970,14 @@ fn spans_after_width<'a>(
             skip_width = 0; // ensure the next span is rendered in full
             Some((span, span_width, available_width))
         })
-        .map(|(span, span_width, available_width)| {
+        .map(move |(span, span_width, available_width)| {
             if span_width <= available_width {
                 // Span is fully visible. Clone here is fast as the underlying content is `Cow`.
                 return (span.clone(), span_width, 0u16);
             }
             // Span is only partially visible. As the end is truncated by the area width, only
             // truncate the start of the span.
-            let (content, actual_width) = span.content.unicode_truncate_start(available_width);
+            let (content, actual_width) = width_mode.truncate_start(&span.content, available_width);
 
             // When the first grapheme of the span was truncated, start rendering from a position
             // that takes that into account by indenting the start of the area
@@ -965,6 +1158,47 @@ mod tests {
         assert_eq!(Style::reset(), line.style);
     }
 
+    #[rstest]
+    #[case::inside_one_span(
+        2..4,
+        vec![Span::raw("ab"), Span::raw("cd").red(), Span::raw("ef")],
+    )]
+    #[case::whole_line(0..6, vec![Span::raw("abcdef").red()])]
+    #[case::past_the_end(4..100, vec![Span::raw("abcd"), Span::raw("ef").red()])]
+    #[case::empty(3..3, vec![Span::raw("abcdef")])]
+    fn styled_range(#[case] range: Range<u16>, #[case] expected: Vec<Span>) {
+        let line = Line::from("abcdef").styled_range(range, Color::Red);
+        assert_eq!(line.spans, expected);
+    }
+
+    #[test]
+    fn styled_range_across_spans() {
+        let line = Line::from(vec![
+            Span::styled("abc", Modifier::BOLD),
+            Span::raw(String::from("def")),
+            Span::raw("ghi"),
+        ])
+        .styled_range(1..7, Color::Red);
+        assert_eq!(
+            line.spans,
+            vec![
+                Span::styled("a", Modifier::BOLD),
+                Span::styled("bc", Modifier::BOLD).red(),
+                Span::raw("def").red(),
+                Span::raw("g").red(),
+                Span::raw("hi"),
+            ]
+        );
+    }
+
+    #[test]
+    fn styled_range_wide_graphemes() {
+        // "你" covers columns 0-1, "好" columns 2-3 and "吗" columns 4-5. The range 1..3 only
+        // covers half of the first two graphemes, which are styled as a whole.
+        let line = Line::from("你好吗").styled_range(1..3, Color::Red);
+        assert_eq!(line.spans, vec![Span::raw("你好").red(), Span::raw("吗")]);
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(Line::default().green().style, Color::Green.into());
@@ -1054,6 +1288,7 @@ mod tests {
                 spans: vec![Span::raw("Red"), Span::raw("blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                direction: None,
             },
         );
     }
@@ -1080,6 +1315,7 @@ mod tests {
                 spans: vec![Span::raw("Red"), Span::raw("Blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                direction: None,
             },
         );
     }
@@ -1253,6 +1489,42 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_rtl_right_aligned() {
+            let line = Line::from(vec![Span::styled("אב ", BLUE), Span::styled("גד", GREEN)])
+                .style(ITALIC)
+                .direction(TextDirection::Rtl)
+                .alignment(Alignment::Right);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            line.render(buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["   דג בא"]);
+            expected.set_style(Rect::new(0, 0, 8, 1), ITALIC);
+            expected.set_style(Rect::new(3, 0, 2, 1), GREEN);
+            expected.set_style(Rect::new(5, 0, 3, 1), BLUE);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_rtl_keeps_latin_runs() {
+            let line = Line::from("שלום abc 123").direction(TextDirection::Rtl);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+            line.render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["abc 123 םולש"]));
+        }
+
+        #[rstest]
+        #[case::left(Alignment::Left, "דג בא")]
+        #[case::center(Alignment::Center, "והדג ")]
+        #[case::right(Alignment::Right, "דג בא")]
+        fn render_rtl_truncates_end(#[case] alignment: Alignment, #[case] expected: &str) {
+            let line = Line::from(vec![Span::raw("אב "), Span::raw("גד"), Span::raw("הו ")])
+                .direction(TextDirection::Rtl)
+                .alignment(alignment);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            line.render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines([expected]));
+        }
+
         #[test]
         fn render_truncates() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
@@ -1665,6 +1937,10 @@ mod tests {
         Line::from("Hello, world!").right_aligned(),
         r#"Line::from("Hello, world!").right_aligned()"#
     )]
+    #[case::rtl(
+        Line::from("Hello, world!").right_aligned().direction(TextDirection::Rtl),
+        r#"Line::from("Hello, world!").right_aligned().direction(TextDirection::Rtl)"#
+    )]
     fn debug(#[case] line: Line, #[case] expected: &str) {
         assert_eq!(format!("{line:?}"), expected);
     }
@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::text::{Line, Span, WidthMode};
+
+/// Defines how truncating widgets indicate that some text did not fit in the available width.
+///
+/// Widgets that truncate their content (e.g. `Tabs` titles and `Table` cells) accept a
+/// `TextOverflow` through their `overflow` setter. The default, [`TextOverflow::Clip`], keeps the
+/// existing behavior of cutting the text at the edge of the available area.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{Line, TextOverflow};
+///
+/// let overflow = TextOverflow::Ellipsis("…");
+/// let line = Line::from("Hello world");
+/// assert_eq!(overflow.truncate(&line, 6).to_string(), "Hello…");
+/// assert_eq!(*overflow.truncate(&line, 20), line);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextOverflow<'a> {
+    /// Cut the text at the edge of the available area
+    #[default]
+    Clip,
+    /// Replace the end of the text that does not fit with the given symbol
+    ///
+    /// The symbol is counted in the available width, so the truncated text and the symbol never
+    /// overflow the area, even when the symbol is wider than one column. If the symbol itself does
+    /// not fit in the available width, the text is clipped instead.
+    Ellipsis(&'a str),
+}
+
+impl<'a> TextOverflow<'a> {
+    /// Returns `line` truncated to fit in `width` columns according to this overflow setting.
+    ///
+    /// A line that fits is borrowed unchanged. With [`TextOverflow::Clip`] the line is also
+    /// borrowed unchanged, and is clipped when rendered. With [`TextOverflow::Ellipsis`] the
+    /// graphemes that fit in `width` minus the width of the symbol are kept and the symbol is
+    /// appended, using the style of the last span that was kept (or of the first span if nothing
+    /// was kept).
+    ///
+    /// The style and alignment of the line are preserved.
+    pub fn truncate<'l>(&self, line: &'l Line<'l>, width: u16) -> Cow<'l, Line<'l>>
+    where
+        'a: 'l,
+    {
+        let Self::Ellipsis(symbol) = *self else {
+            return Cow::Borrowed(line);
+        };
+        let width = usize::from(width);
+        let width_mode = WidthMode::current();
+        let symbol_width = width_mode.width(symbol);
+        if line.width() <= width || symbol_width > width {
+            return Cow::Borrowed(line);
+        }
+        let mut remaining = width - symbol_width;
+        let mut spans = Vec::with_capacity(line.spans.len() + 1);
+        let mut ellipsis_style = line
+            .spans
+            .first()
+            .map(|span| span.style)
+            .unwrap_or_default();
+        for span in &line.spans {
+            let content = span.content.as_ref();
+            let mut end = 0;
+            for grapheme in content.graphemes(true) {
+                let grapheme_width = width_mode.width(grapheme);
+                if grapheme_width > remaining {
+                    remaining = 0;
+                    break;
+                }
+                remaining -= grapheme_width;
+                end += grapheme.len();
+            }
+            if end > 0 {
+                spans.push(Span::styled(content.split_at(end).0, span.style));
+                ellipsis_style = span.style;
+            }
+            if remaining == 0 {
+                break;
+            }
+        }
+        spans.push(Span::styled(symbol, ellipsis_style));
+        Cow::Owned(Line {
+            style: line.style,
+            alignment: line.alignment,
+            direction: line.direction,
+            spans,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{layout::Alignment, style::Stylize};
+
+    #[rstest]
+    #[case::fits("Hello", 5, "Hello")]
+    #[case::truncated("Hello world", 6, "Hello…")]
+    #[case::symbol_only("Hello", 1, "…")]
+    #[case::no_room_for_symbol("Hello", 0, "Hello")]
+    #[case::wide_graphemes("コンピュータ", 6, "コン…")]
+    fn ellipsis(#[case] content: &str, #[case] width: u16, #[case] expected: &str) {
+        let overflow = TextOverflow::Ellipsis("…");
+        let line = Line::from(content);
+        assert_eq!(overflow.truncate(&line, width).to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::fits("abc", 5, "abc")]
+    #[case::truncated("abcdef", 5, "ab...")]
+    #[case::too_narrow_for_symbol("abcdef", 2, "abcdef")]
+    fn wide_ellipsis(#[case] content: &str, #[case] width: u16, #[case] expected: &str) {
+        let overflow = TextOverflow::Ellipsis("...");
+        let line = Line::from(content);
+        assert_eq!(overflow.truncate(&line, width).to_string(), expected);
+    }
+
+    #[test]
+    fn ellipsis_never_overflows() {
+        let overflow = TextOverflow::Ellipsis("……");
+        let line = Line::from("コンピュータ");
+        for width in 2..12 {
+            let truncated = overflow.truncate(&line, width);
+            assert!(truncated.width() <= usize::from(width), "width {width}");
+        }
+    }
+
+    #[test]
+    fn ellipsis_keeps_styles() {
+        let overflow = TextOverflow::Ellipsis("…");
+        let line = Line::from(vec!["Hello ".red(), "world".blue()])
+            .bold()
+            .centered();
+        let expected = Line::from(vec!["Hello ".red(), "w".blue(), "…".blue()])
+            .bold()
+            .centered();
+        assert_eq!(*overflow.truncate(&line, 8), expected);
+    }
+
+    #[test]
+    fn clip_is_unchanged() {
+        let line = Line::from("Hello world").alignment(Alignment::Right);
+        assert!(matches!(TextOverflow::Clip.truncate(&line, 5), Cow::Borrowed(l) if *l == line));
+    }
+
+    #[test]
+    fn ellipsis_borrows_lines_that_fit() {
+        let overflow = TextOverflow::Ellipsis("…");
+        let line = Line::from("Hello");
+        assert!(matches!(overflow.truncate(&line, 5), Cow::Borrowed(l) if *l == line));
+        assert!(matches!(overflow.truncate(&line, 4), Cow::Owned(_)));
+    }
+}
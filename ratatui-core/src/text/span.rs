@@ -1,13 +1,12 @@
 use std::{borrow::Cow, fmt};
 
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 use crate::{
     buffer::Buffer,
     layout::Rect,
     style::{Style, Styled},
-    text::{Line, StyledGrapheme},
+    text::{Line, StyledGrapheme, WidthMode},
     widgets::Widget,
 };
 
@@ -278,7 +277,7 @@ impl<'a> Span<'a> {
 
     /// Returns the unicode width of the content held by this span.
     pub fn width(&self) -> usize {
-        self.content.width()
+        WidthMode::current().width(&self.content)
     }
 
     /// Returns an iterator over the graphemes held by this span.
@@ -429,8 +428,9 @@ impl Widget for &Span<'_> {
             return;
         }
         let Rect { mut x, y, .. } = area;
+        let width_mode = WidthMode::current();
         for (i, grapheme) in self.styled_graphemes(Style::default()).enumerate() {
-            let symbol_width = grapheme.symbol.width();
+            let symbol_width = width_mode.width(grapheme.symbol);
             let next_x = x.saturating_add(symbol_width as u16);
             if next_x > area.right() {
                 break;
@@ -627,6 +627,16 @@ mod tests {
         assert_eq!(Span::raw("test\ncontent").width(), 12);
     }
 
+    #[rstest]
+    #[case::narrow(WidthMode::Narrow, 3)]
+    #[case::wide(WidthMode::Wide, 6)]
+    fn width_ambiguous(#[case] mode: WidthMode, #[case] expected: usize) {
+        WidthMode::set_current(mode);
+        let width = Span::raw("±①○").width();
+        WidthMode::set_current(WidthMode::Narrow);
+        assert_eq!(width, expected);
+    }
+
     #[test]
     fn stylize() {
         let span = Span::raw("test content").green();
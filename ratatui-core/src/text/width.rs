@@ -0,0 +1,136 @@
+use std::cell::Cell;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+thread_local! {
+    static WIDTH_MODE: Cell<WidthMode> = const { Cell::new(WidthMode::Narrow) };
+}
+
+/// Defines how many columns East Asian ambiguous width characters occupy.
+///
+/// Some characters, such as `±`, `①`, `○` or the box drawing characters, are classified as
+/// [ambiguous width] by Unicode. Terminals render them either 1 column wide (the common default)
+/// or 2 columns wide (common in CJK locales, and usually configurable in the terminal settings).
+/// When the width used by Ratatui differs from the one used by the terminal, text that contains
+/// these characters is misaligned.
+///
+/// The width mode is consulted by the code that measures text: [`Span::width`], [`Line::width`],
+/// [`Buffer::set_string`] and the wrapping and truncation of text in widgets. It defaults to
+/// [`WidthMode::Narrow`].
+///
+/// The current mode is stored per thread, so it should be set on the thread that renders the UI,
+/// typically once at startup before the first draw.
+///
+/// [ambiguous width]: https://www.unicode.org/reports/tr11/#Ambiguous
+/// [`Span::width`]: crate::text::Span::width
+/// [`Line::width`]: crate::text::Line::width
+/// [`Buffer::set_string`]: crate::buffer::Buffer::set_string
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{Span, WidthMode};
+///
+/// let span = Span::raw("①②③");
+/// assert_eq!(span.width(), 3);
+///
+/// WidthMode::set_current(WidthMode::Wide);
+/// assert_eq!(span.width(), 6);
+/// # WidthMode::set_current(WidthMode::Narrow);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WidthMode {
+    /// Ambiguous width characters occupy a single column
+    #[default]
+    Narrow,
+    /// Ambiguous width characters occupy two columns
+    Wide,
+}
+
+impl WidthMode {
+    /// Returns the width mode used to measure text on the current thread.
+    pub fn current() -> Self {
+        WIDTH_MODE.with(Cell::get)
+    }
+
+    /// Sets the width mode used to measure text on the current thread.
+    pub fn set_current(mode: Self) {
+        WIDTH_MODE.with(|cell| cell.set(mode));
+    }
+
+    /// Returns the number of columns that `s` occupies when displayed with this width mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::WidthMode;
+    ///
+    /// assert_eq!(WidthMode::Narrow.width("±1"), 2);
+    /// assert_eq!(WidthMode::Wide.width("±1"), 3);
+    /// ```
+    pub fn width(self, s: &str) -> usize {
+        match self {
+            Self::Narrow => s.width(),
+            Self::Wide => s.width_cjk(),
+        }
+    }
+
+    /// Removes graphemes from the start of `s` until it is at most `max_width` columns wide.
+    ///
+    /// Returns the remaining end of `s` and its width.
+    pub(crate) fn truncate_start(self, s: &str, max_width: usize) -> (&str, usize) {
+        let mut width = 0;
+        let mut start = s.len();
+        for (index, grapheme) in s.grapheme_indices(true).rev() {
+            let grapheme_width = self.width(grapheme);
+            if width + grapheme_width > max_width {
+                break;
+            }
+            width += grapheme_width;
+            start = index;
+        }
+        (s.split_at(start).1, width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::ascii("abc", 3, 3)]
+    #[case::ambiguous("±①", 2, 4)]
+    #[case::wide("コン", 4, 4)]
+    fn width(#[case] s: &str, #[case] narrow: usize, #[case] wide: usize) {
+        assert_eq!(WidthMode::Narrow.width(s), narrow);
+        assert_eq!(WidthMode::Wide.width(s), wide);
+    }
+
+    #[rstest]
+    #[case::fits(WidthMode::Narrow, "abc", 3, ("abc", 3))]
+    #[case::truncated(WidthMode::Narrow, "abc", 2, ("bc", 2))]
+    #[case::empty(WidthMode::Narrow, "abc", 0, ("", 0))]
+    #[case::wide_char(WidthMode::Narrow, "aコン", 3, ("ン", 2))]
+    #[case::ambiguous_narrow(WidthMode::Narrow, "①②", 1, ("②", 1))]
+    #[case::ambiguous_wide(WidthMode::Wide, "①②", 3, ("②", 2))]
+    fn truncate_start(
+        #[case] mode: WidthMode,
+        #[case] s: &str,
+        #[case] max_width: usize,
+        #[case] expected: (&str, usize),
+    ) {
+        assert_eq!(mode.truncate_start(s, max_width), expected);
+    }
+
+    #[test]
+    fn current() {
+        assert_eq!(WidthMode::current(), WidthMode::Narrow);
+        WidthMode::set_current(WidthMode::Wide);
+        assert_eq!(WidthMode::current(), WidthMode::Wide);
+        WidthMode::set_current(WidthMode::Narrow);
+        assert_eq!(WidthMode::current(), WidthMode::Narrow);
+    }
+}
@@ -13,23 +13,24 @@
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
 
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 
 pub use crossterm;
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
 use crossterm::{
-    cursor::{Hide, MoveTo, Show},
+    cursor::{Hide, MoveTo, RestorePosition, SavePosition, Show},
+    event::MouseEvent,
     execute, queue,
     style::{
         Attribute as CrosstermAttribute, Attributes as CrosstermAttributes,
         Color as CrosstermColor, Colors as CrosstermColors, ContentStyle, Print, SetAttribute,
         SetBackgroundColor, SetColors, SetForegroundColor,
     },
-    terminal::{self, Clear},
+    terminal::{self, Clear, WindowSize as CrosstermWindowSize},
 };
 use ratatui_core::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{Backend, ClearType, ColorMode, ModifierFallbacks, WindowSize},
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -93,6 +94,12 @@ use ratatui_core::{
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
     writer: W,
+    /// The styles replacing the modifiers the terminal does not render.
+    modifier_fallbacks: ModifierFallbacks,
+    /// The colors the terminal can display.
+    color_mode: ColorMode,
+    /// Whether the writer was detected to be a terminal.
+    is_tty: bool,
 }
 
 impl<W> CrosstermBackend<W>
@@ -106,6 +113,10 @@ where
     ///
     /// [FAQ]: https://ratatui.rs/faq/#should-i-use-stdout-or-stderr
     ///
+    /// The writer can be any type implementing [`Write`], so the backend does not know whether it
+    /// is a terminal and [`Backend::is_tty`] returns `false`. Call [`CrosstermBackend::detect_tty`]
+    /// for writers that implement [`IsTerminal`] (such as `stdout` and `stderr`).
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -116,7 +127,75 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            modifier_fallbacks: ModifierFallbacks::new(),
+            color_mode: ColorMode::TrueColor,
+            is_tty: false,
+        }
+    }
+
+    /// Replaces `modifier` with `style` when drawing to the terminal.
+    ///
+    /// Some terminals do not render modifiers such as [`Modifier::ITALIC`] or
+    /// [`Modifier::CROSSED_OUT`]. When the meaning of the content depends on these modifiers, a
+    /// fallback swaps the modifier for a style the terminal supports, so that the information is
+    /// not silently lost. The modifier is removed from each drawn cell that has it and `style` is
+    /// patched onto the cell instead.
+    ///
+    /// Terminals do not reliably report which modifiers they support, so it is up to the
+    /// application to decide which fallbacks to configure (e.g. based on the `TERM` environment
+    /// variable or on a user setting). Without any fallback, cells are drawn unchanged. See
+    /// [`ModifierFallbacks`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::{
+    ///     backend::CrosstermBackend,
+    ///     style::{Modifier, Style, Stylize},
+    /// };
+    ///
+    /// let backend = CrosstermBackend::new(stdout())
+    ///     .modifier_fallback(Modifier::ITALIC, Style::new().underlined())
+    ///     .modifier_fallback(Modifier::CROSSED_OUT, Style::new().dim());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn modifier_fallback(mut self, modifier: Modifier, style: Style) -> Self {
+        self.modifier_fallbacks.insert(modifier, style);
+        self
+    }
+
+    /// Sets the colors the terminal can display.
+    ///
+    /// The colors of the drawn cells that the terminal cannot display are replaced by their
+    /// closest approximation, and no color is emitted at all with [`ColorMode::NoColor`] (the
+    /// modifiers are still emitted). See [`ColorMode`] for more details.
+    ///
+    /// The mode is [`ColorMode::TrueColor`] by default, which emits the colors unchanged. Pass
+    /// [`ColorMode::detect()`] to pick the mode from the `NO_COLOR`, `COLORTERM` and `TERM`
+    /// environment variables instead.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::{ColorMode, CrosstermBackend};
+    ///
+    /// let backend = CrosstermBackend::new(stdout()).color_mode(ColorMode::Ansi256);
+    /// let backend = CrosstermBackend::new(stdout()).color_mode(ColorMode::detect());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
     }
 
     /// Gets the writer.
@@ -141,6 +220,35 @@ where
     }
 }
 
+impl<W> CrosstermBackend<W>
+where
+    W: Write + IsTerminal,
+{
+    /// Detects whether the writer is a terminal, which is then reported by [`Backend::is_tty`].
+    ///
+    /// The detection happens once, when this method is called.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::{Backend, CrosstermBackend};
+    ///
+    /// let backend = CrosstermBackend::new(stdout()).detect_tty();
+    /// if !backend.is_tty() {
+    ///     println!("stdout is not a terminal");
+    /// }
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn detect_tty(mut self) -> Self {
+        self.is_tty = self.writer.is_terminal();
+        self
+    }
+}
+
 impl<W> Write for CrosstermBackend<W>
 where
     W: Write,
@@ -170,7 +278,10 @@ where
         let mut underline_color = Color::Reset;
         let mut modifier = Modifier::empty();
         let mut last_pos: Option<Position> = None;
+        let color_mode = self.color_mode;
         for (x, y, cell) in content {
+            let cell = self.modifier_fallbacks.apply(cell);
+            let cell = color_mode.apply(&cell);
             // Move the cursor if the previous location was not (x - 1, y)
             if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
                 queue!(self.writer, MoveTo(x, y))?;
@@ -205,6 +316,10 @@ where
             queue!(self.writer, Print(cell.symbol()))?;
         }
 
+        if color_mode == ColorMode::NoColor {
+            return queue!(self.writer, SetAttribute(CrosstermAttribute::Reset));
+        }
+
         #[cfg(feature = "underline-color")]
         return queue!(
             self.writer,
@@ -237,8 +352,16 @@ where
     }
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
-        let Position { x, y } = position.into();
-        execute!(self.writer, MoveTo(x, y))
+        let move_to: MoveTo = position.into().into_crossterm();
+        execute!(self.writer, move_to)
+    }
+
+    fn save_cursor_position(&mut self) -> io::Result<()> {
+        execute!(self.writer, SavePosition)
+    }
+
+    fn restore_cursor_position(&mut self) -> io::Result<()> {
+        execute!(self.writer, RestorePosition)
     }
 
     fn clear(&mut self) -> io::Result<()> {
@@ -271,25 +394,36 @@ where
     }
 
     fn window_size(&mut self) -> io::Result<WindowSize> {
-        let crossterm::terminal::WindowSize {
-            columns,
-            rows,
-            width,
-            height,
-        } = terminal::window_size()?;
-        Ok(WindowSize {
-            columns_rows: Size {
-                width: columns,
-                height: rows,
-            },
-            pixels: Size { width, height },
+        terminal::window_size().map(WindowSize::from_crossterm)
+    }
+
+    #[cfg(all(unix, feature = "resize-channel"))]
+    fn resize_rx(&mut self) -> io::Result<ratatui_core::backend::ResizeReceiver> {
+        ratatui_core::backend::ResizeReceiver::from_sigwinch(|| {
+            let (width, height) = terminal::size()?;
+            Ok(Size { width, height })
         })
     }
 
+    /// Returns whether the writer is a terminal.
+    ///
+    /// [`CrosstermBackend::new`] accepts any writer, so this is `false` unless the backend was
+    /// created with [`CrosstermBackend::detect_tty`]. [`ratatui::init`] and the other
+    /// initialization functions of `ratatui` call it.
+    ///
+    /// [`ratatui::init`]: https://docs.rs/ratatui/latest/ratatui/fn.init.html
+    fn is_tty(&self) -> bool {
+        self.is_tty
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         self.writer.flush()
     }
 
+    fn write_raw(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.writer.write_all(bytes)
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
@@ -545,6 +679,38 @@ impl FromCrossterm<ContentStyle> for Style {
     }
 }
 
+/// Converts the location of a mouse event to a position in terminal columns and rows.
+impl FromCrossterm<MouseEvent> for Position {
+    fn from_crossterm(value: MouseEvent) -> Self {
+        Self {
+            x: value.column,
+            y: value.row,
+        }
+    }
+}
+
+/// Converts a position to the command moving the cursor to it.
+impl IntoCrossterm<MoveTo> for Position {
+    fn into_crossterm(self) -> MoveTo {
+        MoveTo(self.x, self.y)
+    }
+}
+
+impl FromCrossterm<CrosstermWindowSize> for WindowSize {
+    fn from_crossterm(value: CrosstermWindowSize) -> Self {
+        Self {
+            columns_rows: Size {
+                width: value.columns,
+                height: value.rows,
+            },
+            pixels: Size {
+                width: value.width,
+                height: value.height,
+            },
+        }
+    }
+}
+
 /// A command that scrolls the terminal screen a given number of rows up in a specific scrolling
 /// region.
 ///
@@ -647,6 +813,108 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn position_from_crossterm_mouse_event() {
+        use crossterm::event::{KeyModifiers, MouseEventKind};
+
+        let event = MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: 3,
+            row: 4,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(Position::from_crossterm(event), Position::new(3, 4));
+    }
+
+    #[test]
+    fn position_into_crossterm_move_to() {
+        let move_to: MoveTo = Position::new(3, 4).into_crossterm();
+        assert_eq!(move_to, MoveTo(3, 4));
+    }
+
+    #[test]
+    fn window_size_from_crossterm() {
+        let window_size = CrosstermWindowSize {
+            rows: 24,
+            columns: 80,
+            width: 640,
+            height: 480,
+        };
+        assert_eq!(
+            WindowSize::from_crossterm(window_size),
+            WindowSize {
+                columns_rows: Size::new(80, 24),
+                pixels: Size::new(640, 480),
+            }
+        );
+    }
+
+    #[test]
+    fn is_tty() {
+        let backend = CrosstermBackend::new(Vec::new());
+        assert!(!backend.is_tty());
+
+        // a file is not a terminal
+        let file = tempfile::tempfile().unwrap();
+        let backend = CrosstermBackend::new(file).detect_tty();
+        assert!(!backend.is_tty());
+    }
+
+    #[test]
+    fn draw_with_modifier_fallback() {
+        let mut backend = CrosstermBackend::new(Vec::new()).modifier_fallback(
+            Modifier::ITALIC,
+            Style::new().add_modifier(Modifier::UNDERLINED),
+        );
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().add_modifier(Modifier::ITALIC));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains("\x1b[4m"), "{output:?}");
+        assert!(!output.contains("\x1b[3m"), "{output:?}");
+    }
+
+    #[rstest]
+    #[case::true_color(ColorMode::TrueColor, "\x1b[38;2;255;128;0;")]
+    #[case::ansi256(ColorMode::Ansi256, "\x1b[38;5;208;")]
+    #[case::ansi16(ColorMode::Ansi16, "\x1b[38;5;3;")]
+    fn draw_with_color_mode(#[case] color_mode: ColorMode, #[case] expected: &str) {
+        // crossterm drops the colors itself when `NO_COLOR` is set in the environment
+        crossterm::style::Colored::set_ansi_color_disabled(false);
+        let mut backend = CrosstermBackend::new(Vec::new()).color_mode(color_mode);
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().fg(Color::Rgb(255, 128, 0)));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains(expected), "{output:?}");
+    }
+
+    #[test]
+    fn draw_without_color() {
+        let mut backend = CrosstermBackend::new(Vec::new()).color_mode(ColorMode::NoColor);
+        let mut cell = Cell::new("a");
+        cell.set_style(
+            Style::new()
+                .fg(Color::Rgb(255, 128, 0))
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        );
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert_eq!(output, "\x1b[1;1H\x1b[1ma\x1b[0m");
+    }
+
+    #[test]
+    fn draw_without_modifier_fallback() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().add_modifier(Modifier::ITALIC));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains("\x1b[3m"), "{output:?}");
+        assert!(!output.contains("\x1b[4m"), "{output:?}");
+    }
+
     #[rstest]
     #[case(CrosstermColor::Reset, Color::Reset)]
     #[case(CrosstermColor::Black, Color::Black)]
@@ -814,4 +1082,26 @@ mod tests {
             Style::default().underline_color(Color::Red)
         );
     }
+
+    #[test]
+    fn write_raw() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.write_raw(b"\x1b]0;title\x07").unwrap();
+        assert_eq!(backend.writer(), b"\x1b]0;title\x07");
+    }
+
+    #[test]
+    fn save_and_restore_cursor_position() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.save_cursor_position().unwrap();
+        backend.restore_cursor_position().unwrap();
+        assert_eq!(backend.writer(), b"\x1b7\x1b8");
+    }
+
+    #[cfg(all(unix, feature = "resize-channel"))]
+    #[test]
+    fn resize_rx() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        assert!(backend.resize_rx().is_ok());
+    }
 }
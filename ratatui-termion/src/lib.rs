@@ -187,6 +187,16 @@ where
         self.writer.flush()
     }
 
+    fn save_cursor_position(&mut self) -> io::Result<()> {
+        write!(self.writer, "{}", termion::cursor::Save)?;
+        self.writer.flush()
+    }
+
+    fn restore_cursor_position(&mut self) -> io::Result<()> {
+        write!(self.writer, "{}", termion::cursor::Restore)?;
+        self.writer.flush()
+    }
+
     fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
@@ -247,10 +257,22 @@ where
         })
     }
 
+    #[cfg(all(unix, feature = "resize-channel"))]
+    fn resize_rx(&mut self) -> io::Result<ratatui_core::backend::ResizeReceiver> {
+        ratatui_core::backend::ResizeReceiver::from_sigwinch(|| {
+            let (width, height) = termion::terminal_size()?;
+            Ok(Size::new(width, height))
+        })
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         self.writer.flush()
     }
 
+    fn write_raw(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.writer.write_all(bytes)
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         write!(
@@ -275,6 +297,7 @@ where
         self.writer.flush()
     }
 }
+
 struct Fg(Color);
 
 struct Bg(Color);
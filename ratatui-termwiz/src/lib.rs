@@ -208,12 +208,8 @@ impl Backend for TermwizBackend {
     }
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
-        let Position { x, y } = position.into();
-        self.buffered_terminal.add_change(Change::CursorPosition {
-            x: TermwizPosition::Absolute(x as usize),
-            y: TermwizPosition::Absolute(y as usize),
-        });
-
+        self.buffered_terminal
+            .add_change(position.into().into_termwiz());
         Ok(())
     }
 
@@ -229,26 +225,17 @@ impl Backend for TermwizBackend {
     }
 
     fn window_size(&mut self) -> io::Result<WindowSize> {
-        let ScreenSize {
-            cols,
-            rows,
-            xpixel,
-            ypixel,
-        } = self
+        let screen_size = self
             .buffered_terminal
             .terminal()
             .get_screen_size()
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-        Ok(WindowSize {
-            columns_rows: Size {
-                width: u16_max(cols),
-                height: u16_max(rows),
-            },
-            pixels: Size {
-                width: u16_max(xpixel),
-                height: u16_max(ypixel),
-            },
-        })
+        Ok(screen_size.into_ratatui())
+    }
+
+    fn is_tty(&self) -> bool {
+        // a `SystemTerminal` is always attached to a terminal
+        true
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -494,6 +481,40 @@ impl FromTermwiz<LinearRgba> for Color {
     }
 }
 
+/// Converts the screen size reported by Termwiz, clamping each dimension to `u16::MAX`.
+impl FromTermwiz<ScreenSize> for WindowSize {
+    fn from_termwiz(value: ScreenSize) -> Self {
+        Self {
+            columns_rows: value.into_ratatui(),
+            pixels: Size {
+                width: u16_max(value.xpixel),
+                height: u16_max(value.ypixel),
+            },
+        }
+    }
+}
+
+/// Converts the number of columns and rows of the screen size reported by Termwiz, clamping each
+/// dimension to `u16::MAX`.
+impl FromTermwiz<ScreenSize> for Size {
+    fn from_termwiz(value: ScreenSize) -> Self {
+        Self {
+            width: u16_max(value.cols),
+            height: u16_max(value.rows),
+        }
+    }
+}
+
+/// Converts a position to the change moving the cursor to it.
+impl IntoTermwiz<Change> for Position {
+    fn into_termwiz(self) -> Change {
+        Change::CursorPosition {
+            x: TermwizPosition::Absolute(self.x as usize),
+            y: TermwizPosition::Absolute(self.y as usize),
+        }
+    }
+}
+
 #[inline]
 fn u16_max(i: usize) -> u16 {
     u16::try_from(i).unwrap_or(u16::MAX)
@@ -855,4 +876,37 @@ mod tests {
             STYLE.underline_color(Color::Indexed(9))
         );
     }
+
+    mod geometry {
+        use super::*;
+
+        #[test]
+        fn from_screen_size() {
+            let screen_size = ScreenSize {
+                cols: 80,
+                rows: 100_000,
+                xpixel: 640,
+                ypixel: 480,
+            };
+            assert_eq!(Size::from_termwiz(screen_size), Size::new(80, u16::MAX));
+            assert_eq!(
+                WindowSize::from_termwiz(screen_size),
+                WindowSize {
+                    columns_rows: Size::new(80, u16::MAX),
+                    pixels: Size::new(640, 480),
+                }
+            );
+        }
+
+        #[test]
+        fn position_into_change() {
+            assert_eq!(
+                Position::new(3, 4).into_termwiz(),
+                Change::CursorPosition {
+                    x: TermwizPosition::Absolute(3),
+                    y: TermwizPosition::Absolute(4),
+                }
+            );
+        }
+    }
 }
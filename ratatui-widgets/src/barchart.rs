@@ -2,7 +2,7 @@
 
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Direction, Rect},
+    layout::{Alignment, Direction, Rect},
     style::{Style, Styled},
     symbols::{self},
     text::Line,
@@ -106,6 +106,34 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// direction of the bars
     direction: Direction,
+    /// Value and style of the reference line drawn across the bars
+    reference_line: Option<(u64, Style)>,
+    /// Number of value labels drawn along the axis of vertical bars
+    y_axis_labels: u16,
+    /// Where the value of each bar is printed
+    value_placement: ValuePlacement,
+}
+
+/// Where the value of a [`Bar`] is printed in a [`BarChart`].
+///
+/// For vertical bars, "above" means the row over the top of the bar. For horizontal bars, it
+/// means the cells right after the end of the bar.
+///
+/// See [`BarChart::value_placement`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ValuePlacement {
+    /// The value is printed inside the bar, at its base. This is the **default**.
+    ///
+    /// Values that don't fit in the bar are clipped or hidden.
+    #[default]
+    Inside,
+    /// The value is always printed above the bar.
+    ///
+    /// An extra row (or the width of the widest value for horizontal bars) is reserved so that
+    /// the value of the longest bar is visible.
+    Above,
+    /// The value is printed inside the bar if it fits, and above the bar otherwise.
+    Auto,
 }
 
 impl Default for BarChart<'_> {
@@ -123,6 +151,9 @@ impl Default for BarChart<'_> {
             bar_set: symbols::bar::NINE_LEVELS,
             style: Style::default(),
             direction: Direction::Vertical,
+            reference_line: None,
+            y_axis_labels: 0,
+            value_placement: ValuePlacement::Inside,
         }
     }
 }
@@ -379,15 +410,117 @@ impl<'a> BarChart<'a> {
     ///
     /// Horizontal bars
     /// ```plain
-    /// █foo██
+    /// foo 1█
     ///
-    /// █bar██
+    /// bar 2██████
     /// ```
+    ///
+    /// Horizontal bars are drawn after a gutter holding the bar labels. The gutter is as wide as
+    /// the widest label so that all the bars start at the same column, and each label is aligned
+    /// in it according to its [`Line::alignment`] (e.g. right aligned labels sit next to their
+    /// bar). Use [`ValuePlacement::Above`] to print the values at the end of the bars.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn direction(mut self, direction: Direction) -> Self {
         self.direction = direction;
         self
     }
+
+    /// Draws a reference line at the given value across all the bars.
+    ///
+    /// This is useful to show a threshold (e.g. a SLA target) that the bars can be compared to.
+    /// The line is drawn on the row (or column for [`Horizontal`](Direction::Horizontal) bars)
+    /// that a bar with the given value reaches, relative to the maximum of the chart (see
+    /// [`BarChart::max`]). Values above the maximum are drawn at the end of the bars. The line is
+    /// drawn behind the bars, so it is only visible in the cells that are not filled by a bar.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::BarChart,
+    /// };
+    ///
+    /// BarChart::default()
+    ///     .data(&[("foo", 1), ("bar", 4)])
+    ///     .reference_line(2, Style::new().dark_gray());
+    /// // Renders
+    /// //   █
+    /// //   █
+    /// // ──█
+    /// // 1 4
+    /// // f b
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn reference_line<S: Into<Style>>(mut self, value: u64, style: S) -> Self {
+        self.reference_line = Some((value, style.into()));
+        self
+    }
+
+    /// Sets the number of value labels shown on an axis to the left of the bars.
+    ///
+    /// The labels are evenly spaced between `0` and the maximum of the chart (see
+    /// [`BarChart::max`]) and are right aligned in a column that is as wide as the widest label.
+    /// A single label shows only the maximum. If set to `0` (the default), no axis is shown.
+    ///
+    /// The labels are styled with [`BarChart::label_style`]. They are only shown for
+    /// [`Vertical`](Direction::Vertical) bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::BarChart;
+    ///
+    /// BarChart::default()
+    ///     .data(&[("foo", 1), ("bar", 4)])
+    ///     .y_axis_labels(2);
+    /// // Renders
+    /// // 4   █
+    /// //     █
+    /// //     █
+    /// // 0 1 4
+    /// //   f b
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn y_axis_labels(mut self, count: u16) -> Self {
+        self.y_axis_labels = count;
+        self
+    }
+
+    /// Sets where the value of each bar is printed.
+    ///
+    /// By default ([`ValuePlacement::Inside`]) the value is printed inside the bar, which clips or
+    /// hides the value of bars that are too short to contain it. [`ValuePlacement::Above`] always
+    /// prints the value above the bar and reserves room for it, while [`ValuePlacement::Auto`]
+    /// only moves the values that don't fit inside their bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{BarChart, ValuePlacement};
+    ///
+    /// let chart = BarChart::default()
+    ///     .data(&[("a", 1), ("b", 8)])
+    ///     .value_placement(ValuePlacement::Auto);
+    /// ```
+    ///
+    /// ```plain
+    ///   █
+    ///   █
+    /// 1 █
+    /// ▄ 8
+    /// a b
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn value_placement(mut self, placement: ValuePlacement) -> Self {
+        self.value_placement = placement;
+        self
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -476,6 +609,17 @@ impl BarChart<'_> {
         }
     }
 
+    /// Renders the label of a horizontal bar, aligned in the gutter left of the bars
+    fn render_horizontal_label(buf: &mut Buffer, label: &Line, x: u16, y: u16, gutter_width: u16) {
+        let offset = gutter_width.saturating_sub(label.width() as u16);
+        let offset = match label.alignment {
+            Some(Alignment::Center) => offset / 2,
+            Some(Alignment::Right) => offset,
+            Some(Alignment::Left) | None => 0,
+        };
+        buf.set_line(x + offset, y, label, gutter_width - offset);
+    }
+
     fn render_horizontal(&self, buf: &mut Buffer, area: Rect) {
         // get the longest label
         let label_size = self
@@ -487,12 +631,23 @@ impl BarChart<'_> {
             .max()
             .unwrap_or(0) as u16;
 
+        // reserve room after the longest bar for the values printed after the bars
+        let value_size = if self.value_placement == ValuePlacement::Above {
+            self.data
+                .iter()
+                .flat_map(|group| group.bars.iter().map(Bar::value_width))
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+
         let label_x = area.x;
         let bars_area = {
             let margin = u16::from(label_size != 0);
             Rect {
                 x: area.x + label_size + margin,
-                width: area.width - label_size - margin,
+                width: (area.width - label_size - margin).saturating_sub(value_size),
                 ..area
             }
         };
@@ -504,19 +659,18 @@ impl BarChart<'_> {
         for (ticks_vec, group) in group_ticks.into_iter().zip(self.data.iter()) {
             for (ticks, bar) in ticks_vec.into_iter().zip(group.bars.iter()) {
                 let bar_length = (ticks / 8) as u16;
-                let bar_style = self.bar_style.patch(bar.style);
 
                 for y in 0..self.bar_width {
                     let bar_y = bar_y + y;
                     for x in 0..bars_area.width {
-                        let symbol = if x < bar_length {
-                            self.bar_set.full
+                        let (symbol, tick) = if x < bar_length {
+                            (self.bar_set.full, u64::from(x) * 8 + 4)
                         } else {
-                            self.bar_set.empty
+                            (self.bar_set.empty, ticks)
                         };
                         buf[(bars_area.left() + x, bar_y)]
                             .set_symbol(symbol)
-                            .set_style(bar_style);
+                            .set_style(self.bar_cell_style(bar, tick, ticks));
                     }
                 }
 
@@ -525,18 +679,37 @@ impl BarChart<'_> {
                     ..bars_area
                 };
 
-                // label
                 if let Some(label) = &bar.label {
-                    buf.set_line(label_x, bar_value_area.top(), label, label_size);
+                    Self::render_horizontal_label(
+                        buf,
+                        label,
+                        label_x,
+                        bar_value_area.y,
+                        label_size,
+                    );
                 }
 
-                bar.render_value_with_different_styles(
-                    buf,
-                    bar_value_area,
-                    bar_length as usize,
-                    self.value_style,
-                    self.bar_style,
-                );
+                let after_bar = match self.value_placement {
+                    ValuePlacement::Inside => false,
+                    ValuePlacement::Above => true,
+                    ValuePlacement::Auto => bar.value_width() > bar_length,
+                };
+                if after_bar {
+                    let value_area = Rect {
+                        x: bars_area.left() + bar_length,
+                        width: area.right().saturating_sub(bars_area.left() + bar_length),
+                        ..bar_value_area
+                    };
+                    bar.render_value_after(buf, value_area, self.value_style);
+                } else {
+                    bar.render_value_with_different_styles(
+                        buf,
+                        bar_value_area,
+                        bar_length as usize,
+                        self.value_style,
+                        self.bar_style,
+                    );
+                }
 
                 bar_y += self.bar_gap + self.bar_width;
             }
@@ -553,26 +726,130 @@ impl BarChart<'_> {
                 bar_y += self.group_gap;
             }
         }
+
+        if let Some((value, style)) = self.reference_line {
+            let x = bars_area.left() + self.value_length(value, bars_area.width).saturating_sub(1);
+            for y in bars_area.rows().map(|row| row.y) {
+                self.render_reference_cell(buf, (x, y), symbols::line::VERTICAL, style);
+            }
+        }
     }
 
     fn render_vertical(&self, buf: &mut Buffer, area: Rect) {
         let label_info = self.label_info(area.height - 1);
 
+        // the axis is only drawn when the bars leave room for its column of labels
+        let axis_width = self
+            .axis_values()
+            .map(|value| value.checked_ilog10().unwrap_or(0) as u16 + 2)
+            .max()
+            .unwrap_or_default();
+        let axis_reserved = axis_width > 0 && axis_width < area.width;
+        let axis_area = Rect {
+            width: if axis_reserved { axis_width } else { 0 },
+            ..area
+        };
+        let area = Rect {
+            x: area.x + axis_area.width,
+            width: area.width - axis_area.width,
+            ..area
+        };
+
         let bars_area = Rect {
             height: area.height - label_info.height,
             ..area
         };
+        // reserve a row above the highest bar for its value
+        let bars_area = if self.value_placement == ValuePlacement::Above && bars_area.height > 1 {
+            Rect {
+                y: bars_area.y + 1,
+                height: bars_area.height - 1,
+                ..bars_area
+            }
+        } else {
+            bars_area
+        };
 
         let group_ticks = self.group_ticks(bars_area.width, bars_area.height);
         self.render_vertical_bars(bars_area, buf, &group_ticks);
+        self.render_vertical_axis(buf, axis_area, bars_area);
         self.render_labels_and_values(area, buf, label_info, &group_ticks);
     }
 
+    /// Draws the reference line across `bars_area` and the axis labels in `axis_area`.
+    ///
+    /// Nothing is drawn when there is no room for the bars, as the rows of the values would fall
+    /// outside of the area.
+    fn render_vertical_axis(&self, buf: &mut Buffer, axis_area: Rect, bars_area: Rect) {
+        if bars_area.is_empty() {
+            return;
+        }
+        if let Some((value, style)) = self.reference_line {
+            let y = self.value_row(value, bars_area);
+            for x in bars_area.columns().map(|column| column.x) {
+                self.render_reference_cell(buf, (x, y), symbols::line::HORIZONTAL, style);
+            }
+        }
+        if axis_area.is_empty() {
+            return;
+        }
+        for value in self.axis_values() {
+            let y = self.value_row(value, bars_area);
+            // keep a blank column between the labels and the bars
+            let label_area = Rect::new(axis_area.x, y, axis_area.width - 1, 1);
+            Line::from(value.to_string())
+                .right_aligned()
+                .style(self.label_style)
+                .render(label_area, buf);
+        }
+    }
+
+    /// Draws a cell of the reference line, unless it is already filled by a bar.
+    fn render_reference_cell(
+        &self,
+        buf: &mut Buffer,
+        position: (u16, u16),
+        symbol: &str,
+        style: Style,
+    ) {
+        if let Some(cell) = buf.cell_mut(position) {
+            if cell.symbol() == self.bar_set.empty {
+                cell.set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+
+    /// Returns the values to show on the axis of vertical bars, from the lowest to the highest.
+    fn axis_values(&self) -> impl Iterator<Item = u64> {
+        let count = match self.direction {
+            Direction::Vertical => u64::from(self.y_axis_labels),
+            Direction::Horizontal => 0,
+        };
+        let max = self.maximum_data_value();
+        // a single label shows only the maximum
+        let first = u64::from(count == 1);
+        (first..count).map(move |i| max * i / count.saturating_sub(1).max(1))
+    }
+
+    /// Returns the number of cells that a bar with the given value fills, rounded up.
+    fn value_length(&self, value: u64, bar_max_length: u16) -> u16 {
+        let max = self.maximum_data_value();
+        let ticks = value.min(max) * u64::from(bar_max_length) * 8 / max;
+        ticks.div_ceil(8) as u16
+    }
+
+    /// Returns the row reached by a vertical bar with the given value.
+    fn value_row(&self, value: u64, bars_area: Rect) -> u16 {
+        let length = self.value_length(value, bars_area.height).max(1);
+        bars_area.bottom().saturating_sub(length)
+    }
+
     fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
         // print all visible bars (without labels and values)
         let mut bar_x = area.left();
         for (ticks_vec, group) in group_ticks.iter().zip(&self.data) {
             for (ticks, bar) in ticks_vec.iter().zip(&group.bars) {
+                let bar_ticks = *ticks;
                 let mut ticks = *ticks;
                 for j in (0..area.height).rev() {
                     let symbol = match ticks {
@@ -587,7 +864,9 @@ impl BarChart<'_> {
                         _ => self.bar_set.full,
                     };
 
-                    let bar_style = self.bar_style.patch(bar.style);
+                    // the cell takes the style of the segment at the middle of its filled part
+                    let tick = bar_ticks - ticks + ticks.min(8) / 2;
+                    let bar_style = self.bar_cell_style(bar, tick, bar_ticks);
 
                     for x in 0..self.bar_width {
                         buf[(bar_x + x, area.top() + j)]
@@ -603,6 +882,14 @@ impl BarChart<'_> {
         }
     }
 
+    /// Returns the style of the cell of `bar` at `tick`, when the bar is `ticks` long.
+    ///
+    /// The cell takes the style of the segment of the bar at that tick, if any.
+    fn bar_cell_style(&self, bar: &Bar, tick: u64, ticks: u64) -> Style {
+        self.bar_style
+            .patch(bar.segment_style(tick, ticks).unwrap_or(bar.style))
+    }
+
     /// get the maximum data value. the returned value is always greater equal 1
     fn maximum_data_value(&self) -> u64 {
         self.max
@@ -649,7 +936,19 @@ impl BarChart<'_> {
                     bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                let bar_area = Rect {
+                    x: bar_x,
+                    y: area.top(),
+                    width: self.bar_width,
+                    height: bar_y + 1 - area.top(),
+                };
+                bar.render_value(
+                    buf,
+                    bar_area,
+                    self.value_style,
+                    *ticks,
+                    self.value_placement,
+                );
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -701,6 +1000,7 @@ mod tests {
         style::{Color, Modifier, Stylize},
         text::Span,
     };
+    use rstest::rstest;
 
     use super::*;
     use crate::borders::BorderType;
@@ -772,6 +1072,103 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn reference_line() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 5));
+        let widget = BarChart::default()
+            .data(&[("foo", 1), ("bar", 4)])
+            .reference_line(2, Style::new().red());
+        widget.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "  █   ",
+            "  █   ",
+            "──█───",
+            "1 4   ",
+            "f b   ",
+        ]);
+        for x in [0, 1, 3, 4, 5] {
+            expected[(x, 2)].set_fg(Color::Red);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn reference_line_above_max() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let widget = BarChart::default()
+            .data(&[("foo", 1), ("bar", 2)])
+            .reference_line(10, Style::new());
+        widget.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "──█─",
+            "1 2 ",
+            "f b ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn reference_line_horizontal() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        let widget = BarChart::default()
+            .direction(Direction::Horizontal)
+            .data(&[("a", 1), ("b", 4)])
+            .reference_line(2, Style::new());
+        widget.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a 1│  ",
+            "   │  ",
+            "b 4███",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn y_axis_labels() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 6));
+        let widget = BarChart::default()
+            .data(&[("foo", 5), ("bar", 10)])
+            .y_axis_labels(3)
+            .label_style(Style::new().blue());
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "10   █  ",
+            "     █  ",
+            " 5 ▄ █  ",
+            "   █ █  ",
+            " 0 5 █  ",
+            "   f b  ",
+        ]);
+        for (x, y) in iproduct!(0..2, [0, 2, 4]) {
+            expected[(x, y)].set_fg(Color::Blue);
+        }
+        for x in [3, 5] {
+            expected[(x, 5)].set_fg(Color::Blue);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn y_axis_labels_wider_than_area() {
+        let mut buffer = Buffer::with_lines(["xxxxxx"; 3]);
+        let widget = BarChart::default()
+            .data(&[("a", 10), ("b", 5)])
+            .bar_gap(0)
+            .y_axis_labels(2);
+        widget.render(Rect::new(3, 0, 3, 3), &mut buffer);
+        // the labels need 3 columns, so the axis is not drawn rather than drawn left of the area
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "xxx█ x",
+            "xxx█5x",
+            "xxxabx",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn bar_style() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -791,6 +1188,38 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn bar_segments() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        let bar = Bar::default()
+            .segments(vec![(7, Style::new().red()), (9, Style::new().blue())])
+            .text_value("");
+        let widget = BarChart::default()
+            .data(BarGroup::default().bars(&[bar]))
+            .bar_width(3);
+        widget.render(buffer.area, &mut buffer);
+        // the boundary is at 7/16 of the bar, which is 1.75 rows, so the second row is split
+        // between the segments and takes the style of the segment at its middle
+        let mut expected = Buffer::with_lines(["███"; 4]);
+        expected.set_style(Rect::new(0, 0, 3, 2), Style::new().blue());
+        expected.set_style(Rect::new(0, 2, 3, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn bar_segments_horizontal() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let bar = Bar::default()
+            .segments(vec![(2, Style::new().red()), (2, Style::new().blue())])
+            .text_value("");
+        let widget = BarChart::horizontal(vec![bar]).max(8);
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["████    "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().blue());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn bar_width() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1096,6 +1525,33 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn horizontal_labels_are_aligned_in_a_gutter() {
+        let chart = BarChart::horizontal(vec![
+            Bar::default().label("a").value(2),
+            Bar::default()
+                .label(Line::from("bb").right_aligned())
+                .value(4),
+            Bar::default().label(Line::from("cccc").centered()).value(8),
+            Bar::default()
+                .label(Line::from("d").right_aligned())
+                .value(1),
+        ])
+        .bar_gap(0)
+        .value_placement(ValuePlacement::Above);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 4));
+        chart.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a    █2     ",
+            "  bb ███4   ",
+            "cccc ██████8",
+            "   d 1      ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_group_label_style() {
         let chart: BarChart<'_> = BarChart::default()
@@ -1187,6 +1643,83 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[rstest]
+    #[case::inside(ValuePlacement::Inside, [
+        "   ██",
+        "   ██",
+        "   ██",
+        "1▃ 10",
+        "a  b ",
+    ])]
+    #[case::above(ValuePlacement::Above, [
+        "   10",
+        "   ██",
+        "1  ██",
+        "▂▂ ██",
+        "a  b ",
+    ])]
+    #[case::auto(ValuePlacement::Auto, [
+        "   ██",
+        "   ██",
+        "1  ██",
+        "▃▃ 10",
+        "a  b ",
+    ])]
+    fn value_placement(#[case] placement: ValuePlacement, #[case] expected: [&str; 5]) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        let widget = BarChart::default()
+            .data(&[("a", 1), ("b", 10)])
+            .bar_width(2)
+            .value_placement(placement);
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn value_placement_auto_hides_values_wider_than_the_bar() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        let widget = BarChart::default()
+            .data(BarGroup::default().bars(&[
+                Bar::default().value(1).text_value("wide"),
+                Bar::default().value(3),
+            ]))
+            .value_placement(ValuePlacement::Auto);
+        widget.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "  █",
+            "  █",
+            "█ 3",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::inside(ValuePlacement::Inside, [
+        "a 1       ",
+        "          ",
+        "b 10██████",
+    ])]
+    #[case::above(ValuePlacement::Above, [
+        "a 1       ",
+        "          ",
+        "b ██████10",
+    ])]
+    #[case::auto(ValuePlacement::Auto, [
+        "a 1       ",
+        "          ",
+        "b 10██████",
+    ])]
+    fn value_placement_horizontal(#[case] placement: ValuePlacement, #[case] expected: [&str; 3]) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        let widget = BarChart::default()
+            .direction(Direction::Horizontal)
+            .data(&[("a", 1), ("b", 10)])
+            .value_placement(placement);
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
     #[test]
     fn handles_zero_width() {
         // this test is to ensure that a BarChart with zero bar / gap width does not panic
@@ -2,10 +2,11 @@ use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
     style::{Style, Styled},
-    text::Line,
+    text::{Line, WidthMode},
     widgets::Widget,
 };
-use unicode_width::UnicodeWidthStr;
+
+use super::ValuePlacement;
 
 /// A bar to be shown by the [`BarChart`](super::BarChart) widget.
 ///
@@ -44,6 +45,10 @@ pub struct Bar<'a> {
     pub(super) value_style: Style,
     /// optional `text_value` to be shown on the bar instead of the actual value
     pub(super) text_value: Option<String>,
+    /// optional breakdown of the value into stacked segments, from the base of the bar
+    pub(super) segments: Vec<(u64, Style)>,
+    /// sum of the segment values, computed when the segments are set
+    pub(super) segments_total: u64,
 }
 
 impl<'a> Bar<'a> {
@@ -63,6 +68,8 @@ impl<'a> Bar<'a> {
             style: Style::new(),
             value_style: Style::new(),
             text_value: None,
+            segments: Vec::new(),
+            segments_total: 0,
         }
     }
 
@@ -84,6 +91,8 @@ impl<'a> Bar<'a> {
             style: Style::new(),
             value_style: Style::new(),
             text_value: None,
+            segments: Vec::new(),
+            segments_total: 0,
         }
     }
 
@@ -95,6 +104,8 @@ impl<'a> Bar<'a> {
     ///
     /// - [`Bar::value_style`] to style the value.
     /// - [`Bar::text_value`] to set the displayed value.
+    /// - [`Bar::segments`], which sets the value to the sum of the segments. Setting the value
+    ///   afterwards keeps the segments and scales them to fill the new length of the bar.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn value(mut self, value: u64) -> Self {
         self.value = value;
@@ -127,7 +138,8 @@ impl<'a> Bar<'a> {
     /// For [`Vertical`](ratatui_core::layout::Direction::Vertical) bars,
     /// display the label **under** the bar.
     /// For [`Horizontal`](ratatui_core::layout::Direction::Horizontal) bars,
-    /// display the label **in** the bar.
+    /// display the label **left** of the bar, in a gutter as wide as the widest label. The label
+    /// is aligned in the gutter according to its [`Line::alignment`].
     /// See [`BarChart::direction`](crate::barchart::BarChart::direction) to set the direction.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
@@ -192,6 +204,53 @@ impl<'a> Bar<'a> {
         self
     }
 
+    /// Set the segments the bar is stacked from.
+    ///
+    /// Each segment is a `(value, style)` pair. The segments are stacked from the base of the bar
+    /// (the bottom of a vertical bar or the left of a horizontal bar) and each one takes a length
+    /// proportional to its value. Every cell is drawn with the style of the segment at the middle
+    /// of its filled part, which replaces the [style of the bar](Bar::style).
+    ///
+    /// This sets the value of the bar to the sum of the segment values, so the
+    /// [`BarChart`](super::BarChart) scales and prints the total as usual. Calling [`Bar::value`]
+    /// afterwards changes the length of the bar, and the segments keep their proportions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Bar,
+    /// };
+    ///
+    /// // a bar of value 10 that is red on the bottom third and green above
+    /// let bar = Bar::with_label("CPU", 0)
+    ///     .segments(vec![(3, Style::new().red()), (7, Style::new().green())]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn segments(mut self, segments: Vec<(u64, Style)>) -> Self {
+        self.segments_total = segments.iter().map(|(value, _)| value).sum();
+        self.value = self.segments_total;
+        self.segments = segments;
+        self
+    }
+
+    /// Returns the style of the segment drawn at `tick` of the bar when it is `ticks` long, or
+    /// `None` if the bar has no segments.
+    pub(super) fn segment_style(&self, tick: u64, ticks: u64) -> Option<Style> {
+        if self.segments_total == 0 {
+            return None;
+        }
+        let mut end = 0;
+        self.segments
+            .iter()
+            .find(|(value, _)| {
+                end += value;
+                end * ticks / self.segments_total > tick
+            })
+            .map(|(_, style)| *style)
+    }
+
     /// Render the value of the bar.
     ///
     /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
@@ -230,33 +289,75 @@ impl<'a> Bar<'a> {
         }
     }
 
+    /// Render the value of the bar after its end, for horizontal bars.
+    ///
+    /// The value is rendered using `value_style` and truncated to the width of `area`.
+    pub(super) fn render_value_after(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        default_value_style: Style,
+    ) {
+        let value = self.value.to_string();
+        let text = self.text_value.as_ref().unwrap_or(&value);
+        let style = default_value_style.patch(self.value_style);
+        buf.set_stringn(area.x, area.y, text, area.width as usize, style);
+    }
+
+    /// Render the value of a vertical bar.
+    ///
+    /// `area` is the column of the bar, from the top of the chart down to the base of the bar. The
+    /// value is printed in the base row of the bar or in the row above the bar depending on
+    /// `placement`. Values that are wider than the bar are not printed.
     pub(super) fn render_value(
         &self,
         buf: &mut Buffer,
-        max_width: u16,
-        x: u16,
-        y: u16,
+        area: Rect,
         default_value_style: Style,
         ticks: u64,
+        placement: ValuePlacement,
     ) {
-        if self.value != 0 {
-            const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
-            let value_label = self.text_value.as_ref().unwrap_or(&value);
-            let width = value_label.width() as u16;
+        const TICKS_PER_LINE: u64 = 8;
+        if self.value == 0 || area.is_empty() {
+            return;
+        }
+        let value = self.value.to_string();
+        let value_label = self.text_value.as_ref().unwrap_or(&value);
+        let width = WidthMode::current().width(value_label) as u16;
+        let max_width = area.width;
+        let base = area.bottom() - 1;
+        let full_cell = ticks >= TICKS_PER_LINE;
+        let y = match placement {
             // if we have enough space or the ticks are greater equal than 1 cell (8)
             // then print the value
-            if width < max_width || (width == max_width && ticks >= TICKS_PER_LINE) {
-                buf.set_string(
-                    x + (max_width.saturating_sub(value_label.len() as u16) >> 1),
-                    y,
-                    value_label,
-                    default_value_style.patch(self.value_style),
-                );
+            ValuePlacement::Inside if width < max_width || (width == max_width && full_cell) => {
+                Some(base)
             }
+            ValuePlacement::Auto if width <= max_width && full_cell => Some(base),
+            ValuePlacement::Above | ValuePlacement::Auto if width <= max_width => {
+                let bar_height = ticks.div_ceil(TICKS_PER_LINE) as u16;
+                base.checked_sub(bar_height).filter(|y| *y >= area.top())
+            }
+            _ => None,
+        };
+        if let Some(y) = y {
+            buf.set_string(
+                area.x + (max_width.saturating_sub(value_label.len() as u16) >> 1),
+                y,
+                value_label,
+                default_value_style.patch(self.value_style),
+            );
         }
     }
 
+    /// Returns the width of the value printed for this bar.
+    pub(super) fn value_width(&self) -> u16 {
+        self.text_value.as_ref().map_or_else(
+            || self.value.to_string().len() as u16,
+            |text| WidthMode::current().width(text) as u16,
+        )
+    }
+
     pub(super) fn render_label(
         &self,
         buf: &mut Buffer,
@@ -318,6 +419,27 @@ mod tests {
         assert_eq!(bar.value, 42);
     }
 
+    #[test]
+    fn segments() {
+        let bar = Bar::new(1).segments(vec![(2, Style::new().red()), (6, Style::new().blue())]);
+        assert_eq!(bar.value, 8);
+        assert_eq!(bar.segment_style(0, 16), Some(Style::new().red()));
+        assert_eq!(bar.segment_style(3, 16), Some(Style::new().red()));
+        assert_eq!(bar.segment_style(4, 16), Some(Style::new().blue()));
+        assert_eq!(bar.segment_style(15, 16), Some(Style::new().blue()));
+        assert_eq!(Bar::new(1).segment_style(0, 16), None);
+    }
+
+    #[test]
+    fn value_after_segments() {
+        let bar = Bar::new(1)
+            .segments(vec![(2, Style::new().red()), (6, Style::new().blue())])
+            .value(4);
+        assert_eq!(bar.value, 4);
+        assert_eq!(bar.segment_style(1, 8), Some(Style::new().red()));
+        assert_eq!(bar.segment_style(2, 8), Some(Style::new().blue()));
+    }
+
     #[test]
     fn test_bar_stylized() {
         let bar = Bar::default().red().bold();
@@ -0,0 +1,207 @@
+//! The [`Blink`] widget alternates the style of another widget between two styles.
+use ratatui_core::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::Style,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A widget that renders another widget and patches one of two styles onto its area.
+///
+/// The style that is applied depends on the [phase](Blink::phase): the first style when it is
+/// `false` and the second style when it is `true`. Toggling the phase on a timer (e.g. every few
+/// ticks of the event loop) makes the widget blink, which is useful for custom cursors or alerts
+/// that need to catch the eye without relying on [`Modifier::SLOW_BLINK`], which many terminals
+/// don't support. The styles are patched onto the cells, so unset fields keep the style set by the
+/// inner widget.
+///
+/// The style is only applied to the cells drawn by the inner widget. Cells that are left at their
+/// default (a blank [`Cell::EMPTY`]), such as the space after a short
+/// [`Line`](ratatui_core::text::Line), keep their style. This also skips unstyled spaces drawn by
+/// the inner widget, as they can't be told apart from blank cells.
+///
+/// [`Cell::EMPTY`]: ratatui_core::buffer::Cell::EMPTY
+///
+/// `Blink` implements [`Widget`] if the inner widget does (also for references), and
+/// [`StatefulWidget`] if the inner widget is a [`StatefulWidget`].
+///
+/// [`Modifier::SLOW_BLINK`]: ratatui_core::style::Modifier::SLOW_BLINK
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     text::Line,
+///     widgets::Blink,
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame, tick: u64) {
+/// let alert = Line::from("Connection lost");
+/// let blink =
+///     Blink::new(alert, Style::new().red(), Style::new().on_red()).phase(tick / 10 % 2 == 1);
+/// frame.render_widget(blink, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Blink<W> {
+    widget: W,
+    styles: [Style; 2],
+    phase: bool,
+}
+
+impl<W> Blink<W> {
+    /// Creates a new `Blink` widget rendering `widget` with `first` or `second` patched onto it.
+    ///
+    /// The phase is initially `false`, so `first` is applied.
+    pub const fn new(widget: W, first: Style, second: Style) -> Self {
+        Self {
+            widget,
+            styles: [first, second],
+            phase: false,
+        }
+    }
+
+    /// Sets the two styles that are alternately applied to the inner widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn styles(mut self, first: Style, second: Style) -> Self {
+        self.styles = [first, second];
+        self
+    }
+
+    /// Sets the phase, which applies the first style when `false` and the second one when `true`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn phase(mut self, phase: bool) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Returns the style applied in the current phase.
+    pub const fn current_style(&self) -> Style {
+        self.styles[self.phase as usize]
+    }
+
+    /// Returns a reference to the inner widget.
+    pub const fn inner_widget(&self) -> &W {
+        &self.widget
+    }
+
+    /// Consumes the `Blink` widget and returns the inner widget.
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+impl<W: Widget> Widget for Blink<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let style = self.current_style();
+        render_patched(area, buf, style, |buf| self.widget.render(area, buf));
+    }
+}
+
+impl<'a, W> Widget for &'a Blink<W>
+where
+    &'a W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let style = self.current_style();
+        render_patched(area, buf, style, |buf| self.widget.render(area, buf));
+    }
+}
+
+impl<W: StatefulWidget> StatefulWidget for Blink<W> {
+    type State = W::State;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let style = self.current_style();
+        render_patched(area, buf, style, |buf| {
+            self.widget.render(area, buf, state);
+        });
+    }
+}
+
+/// Renders a widget with `render` and patches `style` onto the cells of `area` it drew.
+fn render_patched(area: Rect, buf: &mut Buffer, style: Style, render: impl FnOnce(&mut Buffer)) {
+    render(buf);
+    for position in area.intersection(buf.area).positions() {
+        let cell = &mut buf[position];
+        if *cell != Cell::EMPTY {
+            cell.set_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{style::Stylize, text::Line};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::list::{List, ListState};
+
+    #[rstest]
+    #[case::first(false, Style::new().red())]
+    #[case::second(true, Style::new().on_blue())]
+    fn render(#[case] phase: bool, #[case] expected_style: Style) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Blink::new(
+            Line::from("abc"),
+            Style::new().red(),
+            Style::new().on_blue(),
+        )
+        .phase(phase)
+        .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["abc  "]);
+        expected.set_style(Rect::new(0, 0, 3, 1), expected_style);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_patches_inner_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let blink = Blink::new(Line::from("abc").italic(), Style::new().red(), Style::new());
+        (&blink).render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["abc"]);
+        expected.set_style(buffer.area, Style::new().red().italic());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_skips_untouched_cells() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        Blink::new(Line::from("ab cd"), Style::new().red(), Style::new().blue())
+            .render(Rect::new(1, 0, 5, 1), &mut buffer);
+        // an unstyled space is indistinguishable from a blank cell, so it is not styled either
+        let mut expected = Buffer::with_lines([" ab cd "]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 0, 2, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+        for x in [0, 3, 6] {
+            assert_eq!(buffer[(x, 0)], Cell::EMPTY);
+        }
+    }
+
+    #[test]
+    fn render_stateful() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        let mut state = ListState::default().with_offset(1);
+        let list = List::new(["a", "b", "c"]);
+        StatefulWidget::render(
+            Blink::new(list, Style::new(), Style::new().red()).phase(true),
+            buffer.area,
+            &mut buffer,
+            &mut state,
+        );
+        let mut expected = Buffer::with_lines(["b  ", "c  "]);
+        expected.set_style(Rect::new(0, 0, 1, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn current_style() {
+        let blink = Blink::new((), Style::new().red(), Style::new().blue());
+        assert_eq!(blink.current_style(), Style::new().red());
+        assert_eq!(blink.phase(true).current_style(), Style::new().blue());
+    }
+}
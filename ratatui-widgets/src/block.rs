@@ -8,10 +8,10 @@
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
+    layout::{self, Alignment, Rect},
     style::{Style, Styled},
     symbols::border,
-    text::Line,
+    text::{Line, Span, WidthMode},
     widgets::Widget,
 };
 
@@ -19,7 +19,7 @@ pub use self::{
     padding::Padding,
     title::{Position, Title},
 };
-use crate::borders::{BorderType, Borders};
+use crate::borders::{BorderType, BorderTypes, Borders};
 
 mod padding;
 pub mod title;
@@ -120,6 +120,14 @@ pub struct Block<'a> {
     titles_alignment: Alignment,
     /// The default position of the titles that don't have one
     titles_position: Position,
+    /// The symbol used to fill the rows that contain titles, on both sides of the titles
+    title_fill: Option<&'a str>,
+    /// The number of columns the titles that don't fit are scrolled by
+    title_scroll: u16,
+    /// The separator between the end and the start of a scrolled title
+    title_scroll_separator: Option<&'a str>,
+    /// Titles rendered over the block at an offset from its top left corner
+    overlay_titles: Vec<(layout::Position, Line<'a>)>,
     /// Visible borders
     borders: Borders,
     /// Border style
@@ -141,6 +149,10 @@ impl<'a> Block<'a> {
             titles_style: Style::new(),
             titles_alignment: Alignment::Left,
             titles_position: Position::Top,
+            title_fill: None,
+            title_scroll: 0,
+            title_scroll_separator: None,
+            overlay_titles: Vec::new(),
             borders: Borders::NONE,
             border_style: Style::new(),
             border_set: BorderType::Plain.to_border_set(),
@@ -302,6 +314,45 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Adds a title that is rendered over the block at the given offset from its top left corner.
+    ///
+    /// Unlike the titles added with [`Block::title`], an overlay title does not take up a row of
+    /// the block and is not laid out with the other titles: it is rendered last, over whatever is
+    /// at `offset`, replacing the border glyphs when it is on a border row. A row offset of `0`
+    /// puts the title on the top border, and a larger one on an inner row (which is also covered
+    /// by the content of the block if it is rendered afterwards).
+    ///
+    /// A column offset of `0` replaces the top left corner, so use `1` to keep the corners (e.g.
+    /// the [rounded](BorderType::Rounded) ones) visible. The title is truncated at the right edge
+    /// of the block, covering the right border if it is too long, and is not rendered when the
+    /// offset is outside of the block. The [`Block::title_style`] is applied to overlay titles as
+    /// well.
+    ///
+    /// `title` can be any type that can be converted into a [`Line`] (such as a string).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{layout::Position, widgets::Block};
+    ///
+    /// Block::bordered()
+    ///     .title_overlay(" Logs ", Position::new(2, 0))
+    ///     .title_overlay("12 new", Position::new(2, 2));
+    /// // Renders
+    /// // ┌─ Logs ──────┐
+    /// // │             │
+    /// // │ 12 new      │
+    /// // │             │
+    /// // └─────────────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_overlay<T: Into<Line<'a>>>(mut self, title: T, offset: layout::Position) -> Self {
+        self.overlay_titles.push((offset, title.into()));
+        self
+    }
+
     /// Applies the style to all titles.
     ///
     /// This style will be applied to all titles of the block. If a title has a style set, it will
@@ -368,6 +419,70 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Fills the rows that contain titles with the given symbol, on both sides of the titles.
+    ///
+    /// The symbol is repeated from one edge of the block to the other (stopping at the corners when
+    /// the block has side borders), and the titles are then rendered over it according to their
+    /// alignment. This is useful for section headers such as `── Logs ──────` where the block has
+    /// no border, or to use a different glyph than the border on the title rows. The fill is
+    /// rendered with the [`Block::border_style`].
+    ///
+    /// Rows without titles are not affected. Note that no space is added around the titles, so
+    /// include it in the title itself if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{text::Line, widgets::Block};
+    ///
+    /// Block::new()
+    ///     .title(Line::from(" Logs ").centered())
+    ///     .title_fill("─");
+    /// // Renders
+    /// // ───── Logs ─────
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn title_fill(mut self, symbol: &'a str) -> Self {
+        self.title_fill = Some(symbol);
+        self
+    }
+
+    /// Scrolls the titles that are too wide for the block by the given number of columns.
+    ///
+    /// A title that does not fit in the space left for it is shifted to the left by `offset`
+    /// columns and wraps around, with the [`Block::title_scroll_separator`] between its end and
+    /// its start. Incrementing the offset on each tick animates the title as a marquee. Titles
+    /// that fit are rendered as usual. Overlay titles are never scrolled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Block;
+    ///
+    /// # let tick = 3;
+    /// Block::bordered()
+    ///     .title("Never Gonna Give You Up - Rick Astley")
+    ///     .title_scroll(tick);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn title_scroll(mut self, offset: u16) -> Self {
+        self.title_scroll = offset;
+        self
+    }
+
+    /// Sets the separator rendered between the end and the start of a scrolled title.
+    ///
+    /// The default separator is a single space. See [`Block::title_scroll`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn title_scroll_separator(mut self, separator: &'a str) -> Self {
+        self.title_scroll_separator = Some(separator);
+        self
+    }
+
     /// Defines the style of the borders.
     ///
     /// This style is applied only to the areas covered by borders, and is applied to the block
@@ -484,6 +599,36 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets the border type of each side of the block separately.
+    ///
+    /// The corners use the junction glyphs matching the sides that meet there, e.g. a double top
+    /// side meets single line sides with `╒` and `╕`. See [`BorderTypes`] for how corners are
+    /// resolved when no matching glyph exists.
+    ///
+    /// Setting this overwrites any [`border_type`](Block::border_type) or custom
+    /// [`border_set`](Block::border_set) that was set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Block, BorderType, BorderTypes};
+    /// Block::bordered()
+    ///     .border_types(BorderTypes {
+    ///         top: BorderType::Double,
+    ///         ..BorderTypes::default()
+    ///     })
+    ///     .title("Block");
+    /// // Renders
+    /// // ╒Block╕
+    /// // │     │
+    /// // └─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_types(mut self, border_types: BorderTypes) -> Self {
+        self.border_set = border_types.to_border_set();
+        self
+    }
+
     /// Sets the symbols used to display the border as a [`ratatui_core::symbols::border::Set`].
     ///
     /// Setting this overwrites any [`border_type`](Block::border_type) that was set.
@@ -635,9 +780,28 @@ impl Block<'_> {
     fn render_titles(&self, area: Rect, buf: &mut Buffer) {
         self.render_title_position(Position::Top, area, buf);
         self.render_title_position(Position::Bottom, area, buf);
+        self.render_overlay_titles(area, buf);
+    }
+
+    /// Render the overlay titles at their offset, truncated at the right edge of the block
+    fn render_overlay_titles(&self, area: Rect, buf: &mut Buffer) {
+        for (offset, title) in &self.overlay_titles {
+            if offset.x >= area.width || offset.y >= area.height {
+                continue;
+            }
+            let title_area = Rect {
+                x: area.x + offset.x,
+                y: area.y + offset.y,
+                width: (title.width() as u16).min(area.width - offset.x),
+                height: 1,
+            };
+            buf.set_style(title_area, self.titles_style);
+            title.render(title_area, buf);
+        }
     }
 
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        self.render_title_fill(position, area, buf);
         // NOTE: the order in which these functions are called defines the overlapping behavior
         self.render_right_titles(position, area, buf);
         self.render_center_titles(position, area, buf);
@@ -718,6 +882,22 @@ impl Block<'_> {
         }
     }
 
+    /// Fill the row of the titles at the given position with the title fill symbol
+    fn render_title_fill(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        let Some(symbol) = self.title_fill else {
+            return;
+        };
+        if !self.has_title_at_position(position) {
+            return;
+        }
+        let titles_area = self.titles_area(area, position);
+        for x in titles_area.left()..titles_area.right() {
+            buf[(x, titles_area.top())]
+                .set_symbol(symbol)
+                .set_style(self.border_style);
+        }
+    }
+
     /// Render titles aligned to the right of the block
     ///
     /// Currently (due to the way lines are truncated), the right side of the leftmost title will
@@ -744,7 +924,7 @@ impl Block<'_> {
                 ..titles_area
             };
             buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+            self.render_title(title, title_area, buf);
 
             // bump the width of the titles area to the left
             titles_area.width = titles_area
@@ -785,7 +965,7 @@ impl Block<'_> {
                 ..titles_area
             };
             buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+            self.render_title(title, title_area, buf);
 
             // bump the titles area to the right and reduce its width
             titles_area.x = titles_area.x.saturating_add(title_width + 1);
@@ -808,7 +988,7 @@ impl Block<'_> {
                 ..titles_area
             };
             buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+            self.render_title(title, title_area, buf);
 
             // bump the titles area to the right and reduce its width
             titles_area.x = titles_area.x.saturating_add(title_width + 1);
@@ -816,6 +996,47 @@ impl Block<'_> {
         }
     }
 
+    /// Render a title in its area, scrolled by the title scroll offset when it doesn't fit
+    fn render_title(&self, title: &Line, area: Rect, buf: &mut Buffer) {
+        if title.width() <= area.width as usize {
+            title.render(area, buf);
+            return;
+        }
+        let width_mode = WidthMode::current();
+        let separator = Span::raw(self.title_scroll_separator.unwrap_or(" "));
+        let graphemes = title
+            .styled_graphemes(Style::new())
+            .chain(separator.styled_graphemes(title.style))
+            .collect_vec();
+        let cycle_width = graphemes
+            .iter()
+            .map(|grapheme| width_mode.width(grapheme.symbol))
+            .sum::<usize>();
+        let offset = usize::from(self.title_scroll) % cycle_width;
+
+        // clear the area so that columns left blank by wide graphemes don't show the border
+        for position in area.positions() {
+            buf[position].set_symbol(" ");
+        }
+
+        let mut column = 0;
+        let mut x = area.left();
+        for grapheme in graphemes.iter().cycle() {
+            let start = column;
+            column += width_mode.width(grapheme.symbol);
+            if start < offset {
+                // a wide grapheme cut by the offset leaves blank columns
+                x = area.left() + column.saturating_sub(offset) as u16;
+                continue;
+            }
+            let max_width = area.right().saturating_sub(x) as usize;
+            if max_width < width_mode.width(grapheme.symbol) {
+                break;
+            }
+            (x, _) = buf.set_stringn(x, area.top(), grapheme.symbol, max_width, grapheme.style);
+        }
+    }
+
     /// An iterator over the titles that match the position and alignment
     fn filtered_titles(
         &self,
@@ -1149,6 +1370,10 @@ mod tests {
                 titles_style: Style::new(),
                 titles_alignment: Alignment::Left,
                 titles_position: Position::Top,
+                title_fill: None,
+                title_scroll: 0,
+                title_scroll_separator: None,
+                overlay_titles: Vec::new(),
                 borders: Borders::NONE,
                 border_style: Style::new(),
                 border_set: BorderType::Plain.to_border_set(),
@@ -1365,6 +1590,155 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::left(Alignment::Left, "Logs────────")]
+    #[case::center(Alignment::Center, "────Logs────")]
+    #[case::right(Alignment::Right, "────────Logs")]
+    fn title_fill(#[case] alignment: Alignment, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        Block::new()
+            .title(Line::from("Logs").alignment(alignment))
+            .title_fill("─")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected, "            "]));
+    }
+
+    #[test]
+    fn title_fill_bordered() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        Block::bordered()
+            .title(Line::from(" Logs ").centered())
+            .title_fill("═")
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌══ Logs ══┐",
+            "│          │",
+            "└──────────┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_fill_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::new()
+            .title(Line::from("Logs").centered())
+            .title_fill("─")
+            .border_style(Style::new().yellow())
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["──Logs──"]);
+        expected.set_style(Rect::new(0, 0, 8, 1), Style::new().yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::no_offset(0, "┌Hello ┐")]
+    #[case::offset(3, "┌lo Wor┐")]
+    #[case::wrap_around(9, "┌ld Hel┐")]
+    #[case::full_cycle(15, "┌lo Wor┐")]
+    fn title_scroll(#[case] offset: u16, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::bordered()
+            .title("Hello World")
+            .title_scroll(offset)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn title_scroll_separator() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::bordered()
+            .title("Hello World")
+            .title_scroll(9)
+            .title_scroll_separator(" | ")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌ld | H┐"]));
+    }
+
+    #[test]
+    fn title_scroll_fitting_title() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::bordered()
+            .title(Line::from("Hello").right_aligned())
+            .title_scroll(3)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌─Hello┐"]));
+    }
+
+    #[test]
+    fn title_scroll_wide_graphemes() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::bordered()
+            .title("日本語テキスト")
+            .title_scroll(1)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌ 本語 ┐"]));
+    }
+
+    #[test]
+    fn title_scroll_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::bordered()
+            .title(Line::from("Hello World").red())
+            .title_scroll(9)
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌ld Hel┐"]);
+        expected.set_style(Rect::new(1, 0, 6, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_overlay() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title("Top")
+            .title_overlay("Over", layout::Position::new(6, 0))
+            .title_overlay("Inner", layout::Position::new(2, 2))
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "╭Top──Over─╮",
+            "│          │",
+            "│ Inner    │",
+            "╰──────────╯",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_overlay_does_not_take_up_a_row() {
+        let block = Block::new().title_overlay("title", layout::Position::new(0, 0));
+        assert_eq!(block.inner(Rect::new(0, 0, 10, 3)), Rect::new(0, 0, 10, 3));
+    }
+
+    #[rstest]
+    #[case::corner(layout::Position::new(0, 0), ["title──╮", "╰──────╯"])]
+    #[case::truncated(layout::Position::new(4, 1), ["╭──────╮", "╰───titl"])]
+    #[case::outside(layout::Position::new(8, 0), ["╭──────╮", "╰──────╯"])]
+    fn title_overlay_edges(#[case] offset: layout::Position, #[case] expected: [&str; 2]) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title_overlay("title", offset)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn title_overlay_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Block::new()
+            .title_style(Style::new().yellow())
+            .title_overlay("ab".red(), layout::Position::new(2, 0))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["  ab  "]);
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn title_border_style() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1493,6 +1867,25 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_border_types_double_top() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_types(BorderTypes {
+                top: BorderType::Double,
+                ..BorderTypes::default()
+            })
+            .title("Title")
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "╒Title═══╕",
+            "│        │",
+            "└────────┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn render_quadrant_inside() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1,4 +1,5 @@
-//! Border related types ([`Borders`], [`BorderType`]) and a macro to create borders ([`border`]).
+//! Border related types ([`Borders`], [`BorderType`], [`BorderTypes`]) and a macro to create
+//! borders ([`border`]).
 use std::fmt;
 
 use bitflags::bitflags;
@@ -168,6 +169,126 @@ impl BorderType {
     pub const fn to_border_set(self) -> border::Set {
         Self::border_symbols(self)
     }
+
+    /// Returns the weight of the lines drawn by this border type, used to find junction glyphs.
+    const fn weight(self) -> LineWeight {
+        match self {
+            Self::Plain
+            | Self::Rounded
+            | Self::LightDoubleDashed
+            | Self::LightTripleDashed
+            | Self::LightQuadrupleDashed => LineWeight::Light,
+            Self::Thick
+            | Self::HeavyDoubleDashed
+            | Self::HeavyTripleDashed
+            | Self::HeavyQuadrupleDashed => LineWeight::Heavy,
+            Self::Double => LineWeight::Double,
+            Self::QuadrantInside | Self::QuadrantOutside => LineWeight::Block,
+        }
+    }
+}
+
+/// The [`BorderType`] of each side of a [`Block`](crate::block::Block).
+///
+/// This makes it possible to mix border types, e.g. a double line at the top of a block with
+/// single lines on the other sides. See [`Block::border_types`](crate::block::Block::border_types).
+///
+/// The corners use the junction glyph matching the two sides that meet there (e.g. `╒` where a
+/// double top meets a single left side). When no such glyph exists (e.g. for a heavy side meeting
+/// a double side), the nearest available glyph is used, treating heavy lines as light ones. When
+/// the sides have the same line weight or one of them is a quadrant border, the corner of the
+/// horizontal side is used.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{Block, BorderType, BorderTypes};
+///
+/// let block = Block::bordered().border_types(BorderTypes {
+///     top: BorderType::Double,
+///     ..BorderTypes::default()
+/// });
+/// // Renders
+/// // ╒══════╕
+/// // │      │
+/// // └──────┘
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BorderTypes {
+    /// The border type of the top side
+    pub top: BorderType,
+    /// The border type of the right side
+    pub right: BorderType,
+    /// The border type of the bottom side
+    pub bottom: BorderType,
+    /// The border type of the left side
+    pub left: BorderType,
+}
+
+/// The weight of the lines of a [`BorderType`].
+#[derive(Clone, Copy)]
+enum LineWeight {
+    Light,
+    Heavy,
+    Double,
+    Block,
+}
+
+/// A corner of a border.
+#[derive(Clone, Copy)]
+enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl BorderTypes {
+    /// Creates a `BorderTypes` with the same border type on every side.
+    pub const fn uniform(border_type: BorderType) -> Self {
+        Self {
+            top: border_type,
+            right: border_type,
+            bottom: border_type,
+            left: border_type,
+        }
+    }
+
+    /// Convert these `BorderTypes` into the corresponding [`Set`](border::Set) of border symbols.
+    pub const fn to_border_set(self) -> border::Set {
+        border::Set {
+            top_left: Self::corner(self.top, self.left, Corner::TopLeft),
+            top_right: Self::corner(self.top, self.right, Corner::TopRight),
+            bottom_left: Self::corner(self.bottom, self.left, Corner::BottomLeft),
+            bottom_right: Self::corner(self.bottom, self.right, Corner::BottomRight),
+            vertical_left: self.left.to_border_set().vertical_left,
+            vertical_right: self.right.to_border_set().vertical_right,
+            horizontal_top: self.top.to_border_set().horizontal_top,
+            horizontal_bottom: self.bottom.to_border_set().horizontal_bottom,
+        }
+    }
+
+    /// Returns the glyph of the corner where the `horizontal` and `vertical` sides meet.
+    const fn corner(horizontal: BorderType, vertical: BorderType, corner: Corner) -> &'static str {
+        use LineWeight::{Block, Double, Heavy, Light};
+        // glyphs in the order top left, top right, bottom left, bottom right
+        let glyphs = match (horizontal.weight(), vertical.weight()) {
+            (Light, Heavy) => ["┎", "┒", "┖", "┚"],
+            (Heavy, Light) => ["┍", "┑", "┕", "┙"],
+            (Double, Light | Heavy) => ["╒", "╕", "╘", "╛"],
+            (Light | Heavy, Double) => ["╓", "╖", "╙", "╜"],
+            (Light, Light) | (Heavy, Heavy) | (Double, Double) | (Block, _) | (_, Block) => {
+                let set = horizontal.to_border_set();
+                [
+                    set.top_left,
+                    set.top_right,
+                    set.bottom_left,
+                    set.bottom_right,
+                ]
+            }
+        };
+        glyphs[corner as usize]
+    }
 }
 
 /// Implement the `Debug` trait for the `Borders` bitflags. This is a manual implementation to
@@ -254,8 +375,56 @@ macro_rules! border {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
+    #[test]
+    fn border_types_uniform() {
+        for border_type in [
+            BorderType::Plain,
+            BorderType::Rounded,
+            BorderType::Double,
+            BorderType::Thick,
+            BorderType::QuadrantOutside,
+        ] {
+            assert_eq!(
+                BorderTypes::uniform(border_type).to_border_set(),
+                border_type.to_border_set()
+            );
+        }
+        assert_eq!(
+            BorderTypes::default(),
+            BorderTypes::uniform(BorderType::Plain)
+        );
+    }
+
+    #[rstest]
+    #[case::double_top(BorderType::Double, BorderType::Plain, "╒", "╕")]
+    #[case::double_sides(BorderType::Plain, BorderType::Double, "╓", "╖")]
+    #[case::thick_top(BorderType::Thick, BorderType::Plain, "┍", "┑")]
+    #[case::thick_sides(BorderType::Rounded, BorderType::Thick, "┎", "┒")]
+    #[case::heavy_and_double(BorderType::Thick, BorderType::Double, "╓", "╖")]
+    #[case::same_weight(BorderType::Rounded, BorderType::LightDoubleDashed, "╭", "╮")]
+    #[case::quadrant(BorderType::QuadrantOutside, BorderType::Double, "▛", "▜")]
+    fn border_types_corners(
+        #[case] top: BorderType,
+        #[case] sides: BorderType,
+        #[case] top_left: &str,
+        #[case] top_right: &str,
+    ) {
+        let set = BorderTypes {
+            top,
+            right: sides,
+            left: sides,
+            bottom: sides,
+        }
+        .to_border_set();
+        assert_eq!((set.top_left, set.top_right), (top_left, top_right));
+        assert_eq!(set.horizontal_top, top.to_border_set().horizontal_top);
+        assert_eq!(set.vertical_left, sides.to_border_set().vertical_left);
+    }
+
     #[test]
     fn test_borders_debug() {
         assert_eq!(format!("{:?}", Borders::empty()), "NONE");
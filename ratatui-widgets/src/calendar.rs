@@ -8,7 +8,10 @@
 //! * a style is returned by the [`DateStyler`] for the day
 //!
 //! [`Monthly`] has several controls for what should be displayed
-use std::collections::HashMap;
+//!
+//! The [`Yearly`] widget lays out the months of a year (or any number of consecutive months) in a
+//! grid of [`Monthly`] calendars, with the same controls and the same [`DateStyler`].
+use std::{collections::HashMap, iter};
 
 use ratatui_core::{
     buffer::Buffer,
@@ -17,7 +20,7 @@ use ratatui_core::{
     text::{Line, Span},
     widgets::Widget,
 };
-use time::{Date, Duration, OffsetDateTime};
+use time::{Date, Duration, Month, OffsetDateTime};
 
 use crate::block::{Block, BlockExt};
 
@@ -175,13 +178,18 @@ impl<DS: DateStyler> Monthly<'_, DS> {
         // Set the start of the calendar to the Sunday before the 1st (or the sunday of the first)
         let first_of_month = self.display_date.replace_day(1).unwrap();
         let offset = Duration::days(first_of_month.weekday().number_days_from_sunday().into());
-        let mut curr_day = first_of_month - offset;
+        // the days stop at the last representable date
+        let mut days =
+            iter::successors(Some(first_of_month - offset), |day| day.next_day()).peekable();
 
         let mut y = days_area.y;
         // go through all the weeks containing a day in the target month.
-        while curr_day.month() != self.display_date.month().next() {
+        while days
+            .peek()
+            .is_some_and(|day| day.month() != self.display_date.month().next())
+        {
             let mut spans = Vec::with_capacity(14);
-            for i in 0..7 {
+            for (i, curr_day) in days.by_ref().take(7).enumerate() {
                 // Draw the gutter. Do it here so we can avoid worrying about
                 // styling the ' ' in the format_date method
                 if i == 0 {
@@ -190,9 +198,8 @@ impl<DS: DateStyler> Monthly<'_, DS> {
                     spans.push(Span::styled(" ", self.default_bg()));
                 }
                 spans.push(self.format_date(curr_day));
-                curr_day += Duration::DAY;
             }
-            if buf.area.height > y {
+            if y < days_area.bottom() {
                 buf.set_line(days_area.x, y, &spans.into(), area.width);
             }
             y += 1;
@@ -200,8 +207,205 @@ impl<DS: DateStyler> Monthly<'_, DS> {
     }
 }
 
-/// Provides a method for styling a given date. [Monthly] is generic on this trait, so any type
-/// that implements this trait can be used.
+/// The width of a [`Monthly`] calendar
+const MONTH_WIDTH: u16 = 21;
+
+/// The maximum number of weeks a [`Monthly`] calendar displays
+const MAX_WEEKS: u16 = 6;
+
+/// Display a grid of month calendars for the year containing `display_date`
+///
+/// Each month is rendered as a [`Monthly`] calendar, using the same [`DateStyler`] and the same
+/// styles, so events and the current date are highlighted across the whole year. The months are
+/// laid out in as many columns as fit in the area (e.g. 4×3 or 3×4 for a year), with the rows
+/// balanced. When the area is not tall enough for all the rows, the weekday headers are hidden.
+///
+/// [`Yearly::months`] displays any number of consecutive months instead of a whole year, e.g. to
+/// render a few months side by side.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::calendar::{CalendarEventStore, Yearly},
+/// };
+/// use time::{Date, Month};
+///
+/// let date = Date::from_calendar_date(2023, Month::January, 1).unwrap();
+/// let calendar = Yearly::new(date, CalendarEventStore::today(Style::new().red().bold()))
+///     .show_month_header(Style::new().bold())
+///     .show_weekdays_header(Style::new().italic());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Yearly<'a, DS: DateStyler> {
+    first_month: Date,
+    month_count: u8,
+    events: DS,
+    show_surrounding: Option<Style>,
+    show_weekday: Option<Style>,
+    show_month: Option<Style>,
+    default_style: Style,
+    block: Option<Block<'a>>,
+}
+
+impl<'a, DS: DateStyler> Yearly<'a, DS> {
+    /// Construct a calendar for the year containing `display_date` and highlight the `events`
+    pub fn new(display_date: Date, events: DS) -> Self {
+        Self {
+            first_month: Date::from_calendar_date(display_date.year(), Month::January, 1).unwrap(),
+            month_count: 12,
+            events,
+            show_surrounding: None,
+            show_weekday: None,
+            show_month: None,
+            default_style: Style::new(),
+            block: None,
+        }
+    }
+
+    /// Display `count` consecutive months, starting with the month containing `first_month`,
+    /// instead of the year
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn months(mut self, first_month: Date, count: u8) -> Self {
+        self.first_month = first_month.replace_day(1).unwrap();
+        self.month_count = count;
+        self
+    }
+
+    /// Fill the calendar slots for days not in the current month also, see
+    /// [`Monthly::show_surrounding`]
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn show_surrounding<S: Into<Style>>(mut self, style: S) -> Self {
+        self.show_surrounding = Some(style.into());
+        self
+    }
+
+    /// Display a header containing weekday abbreviations above each month, when there is enough
+    /// space
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn show_weekdays_header<S: Into<Style>>(mut self, style: S) -> Self {
+        self.show_weekday = Some(style.into());
+        self
+    }
+
+    /// Display a header containing the month and year above each month
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn show_month_header<S: Into<Style>>(mut self, style: S) -> Self {
+        self.show_month = Some(style.into());
+        self
+    }
+
+    /// How to render otherwise unstyled dates
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn default_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.default_style = style.into();
+        self
+    }
+
+    /// Render the calendar within a [Block]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl<DS: DateStyler> Widget for Yearly<'_, DS> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<DS: DateStyler> Widget for &Yearly<'_, DS> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.render_yearly(inner, buf);
+    }
+}
+
+impl<DS: DateStyler> Yearly<'_, DS> {
+    fn render_yearly(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() || self.month_count == 0 {
+            return;
+        }
+        let count = u16::from(self.month_count);
+        let max_columns = (area.width + 1) / (MONTH_WIDTH + 1);
+        let rows = count.div_ceil(max_columns.clamp(1, count));
+        // balance the rows, e.g. 4×3 rather than 5+5+2 months
+        let columns = count.div_ceil(rows);
+
+        // hide the weekday headers first when there is not enough space
+        let headers_height = u16::from(self.show_month.is_some());
+        let full_height = headers_height + u16::from(self.show_weekday.is_some()) + MAX_WEEKS;
+        let show_weekday = self
+            .show_weekday
+            .filter(|_| rows * (full_height + 1) - 1 <= area.height);
+        let month_height = headers_height + u16::from(show_weekday.is_some()) + MAX_WEEKS;
+
+        let row_areas = Layout::vertical(vec![Constraint::Length(month_height); rows.into()])
+            .spacing(1)
+            .split(area);
+        let month_areas = row_areas.iter().flat_map(|row_area| {
+            Layout::horizontal(vec![Constraint::Length(MONTH_WIDTH); columns.into()])
+                .spacing(1)
+                .split(*row_area)
+                .to_vec()
+        });
+        let mut date = self.first_month;
+        for month_area in month_areas.take(count.into()) {
+            let month = Monthly {
+                display_date: date,
+                events: EventsRef(&self.events),
+                show_surrounding: self.show_surrounding,
+                show_weekday,
+                show_month: self.show_month,
+                default_style: self.default_style,
+                block: None,
+            };
+            month.render(month_area, buf);
+            // the 28th of any month is at most 4 days before the next month
+            match date.replace_day(28).unwrap().checked_add(Duration::days(4)) {
+                Some(next_month) => date = next_month.replace_day(1).unwrap(),
+                // there is no month after the last representable date
+                None => break,
+            }
+        }
+    }
+}
+
+/// Borrows the [`DateStyler`] of a [`Yearly`] calendar for each of its months
+struct EventsRef<'a, DS>(&'a DS);
+
+impl<DS: DateStyler> DateStyler for EventsRef<'_, DS> {
+    fn get_style(&self, date: Date) -> Style {
+        self.0.get_style(date)
+    }
+}
+
+/// Provides a method for styling a given date. [Monthly] and [Yearly] are generic on this trait,
+/// so any type that implements this trait can be used.
 pub trait DateStyler {
     /// Given a date, return a style for that date
     fn get_style(&self, date: Date) -> Style;
@@ -267,7 +471,6 @@ impl Default for CalendarEventStore {
 #[cfg(test)]
 mod tests {
     use ratatui_core::style::Color;
-    use time::Month;
 
     use super::*;
 
@@ -6,9 +6,12 @@
 //! The available shapes are:
 //!
 //! - [`Circle`]: A basic circle
+//! - [`Gridlines`]: A grid of lines at regular intervals, with optional labels
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
 //! - [`Points`]: A scatter of points
+//! - [`IntensityPoints`]: A scatter of points lighting more dots per cell as their intensity rises
+//! - [`Polygon`]: A filled polygon
 //! - [`Rectangle`]: A basic rectangle
 //!
 //! You can also implement your own custom [`Shape`]s.
@@ -27,17 +30,21 @@ use ratatui_core::{
 
 pub use self::{
     circle::Circle,
+    gridlines::Gridlines,
     line::Line,
     map::{Map, MapResolution},
-    points::Points,
+    points::{IntensityPoints, Points},
+    polygon::Polygon,
     rectangle::Rectangle,
 };
 use crate::block::{Block, BlockExt};
 
 mod circle;
+mod gridlines;
 mod line;
 mod map;
 mod points;
+mod polygon;
 mod rectangle;
 mod world;
 
@@ -73,6 +80,18 @@ struct Layer {
     colors: Vec<(Color, Color)>,
 }
 
+/// Get the number of dots in a single terminal cell for the given marker, by column and row.
+///
+/// This is the only place that maps a [`Marker`] to its resolution, the grids, the [`Painter`] and
+/// the [`Canvas`] all derive their resolution from it.
+const fn cell_resolution(marker: Marker) -> (u16, u16) {
+    match marker {
+        Marker::Braille => (2, 4),
+        Marker::HalfBlock => (1, 2),
+        Marker::Dot | Marker::Block | Marker::Bar => (1, 1),
+    }
+}
+
 /// A grid of cells that can be painted on.
 ///
 /// The grid represents a particular screen region measured in rows and columns. The underlying
@@ -80,12 +99,11 @@ struct Layer {
 /// Braille patterns will have a resolution of 2x4 dots per cell. This means that a grid of 10x10
 /// cells will have a resolution of 20x40 dots.
 trait Grid: fmt::Debug {
-    /// Get the resolution of the grid in number of dots.
+    /// Get the size of the grid in number of terminal columns and rows.
     ///
-    /// This doesn't have to be the same as the number of rows and columns of the grid. For example,
-    /// a grid of Braille patterns will have a resolution of 2x4 dots per cell. This means that a
-    /// grid of 10x10 cells will have a resolution of 20x40 dots.
-    fn resolution(&self) -> (f64, f64);
+    /// The resolution of the grid in number of dots is this size multiplied by the
+    /// [`cell_resolution`] of the marker the grid was created for.
+    fn size(&self) -> (u16, u16);
     /// Paint a point of the grid.
     ///
     /// The point is expressed in number of dots starting at the origin of the grid in the top left
@@ -136,8 +154,8 @@ impl BrailleGrid {
 }
 
 impl Grid for BrailleGrid {
-    fn resolution(&self) -> (f64, f64) {
-        (f64::from(self.width) * 2.0, f64::from(self.height) * 4.0)
+    fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
     }
 
     fn save(&self) -> Layer {
@@ -199,8 +217,8 @@ impl CharGrid {
 }
 
 impl Grid for CharGrid {
-    fn resolution(&self) -> (f64, f64) {
-        (f64::from(self.width), f64::from(self.height))
+    fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
     }
 
     fn save(&self) -> Layer {
@@ -264,8 +282,8 @@ impl HalfBlockGrid {
 }
 
 impl Grid for HalfBlockGrid {
-    fn resolution(&self) -> (f64, f64) {
-        (f64::from(self.width), f64::from(self.height) * 2.0)
+    fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
     }
 
     fn save(&self) -> Layer {
@@ -444,11 +462,38 @@ impl Painter<'_, '_> {
     pub fn bounds(&self) -> (&[f64; 2], &[f64; 2]) {
         (&self.context.x_bounds, &self.context.y_bounds)
     }
+
+    /// Number of dots in a single terminal cell of the grid, by column and row.
+    ///
+    /// This is `(2, 4)` for [`Marker::Braille`], `(1, 2)` for [`Marker::HalfBlock`] and `(1, 1)`
+    /// for the other markers. The dot `(x, y)` returned by [`Painter::get_point`] is in the cell
+    /// `(x / columns, y / rows)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     symbols,
+    ///     widgets::canvas::{Context, Painter},
+    /// };
+    ///
+    /// let mut ctx = Context::new(1, 1, [0.0, 2.0], [0.0, 2.0], symbols::Marker::Braille);
+    /// let painter = Painter::from(&mut ctx);
+    /// assert_eq!(painter.cell_resolution(), (2, 4));
+    /// ```
+    pub const fn cell_resolution(&self) -> (u16, u16) {
+        cell_resolution(self.context.marker)
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
     fn from(context: &'a mut Context<'b>) -> Self {
-        let resolution = context.grid.resolution();
+        let (width, height) = context.grid.size();
+        let (columns, rows) = cell_resolution(context.marker);
+        let resolution = (
+            f64::from(width) * f64::from(columns),
+            f64::from(height) * f64::from(rows),
+        );
         Self {
             context,
             resolution,
@@ -465,6 +510,7 @@ pub struct Context<'a> {
     x_bounds: [f64; 2],
     y_bounds: [f64; 2],
     grid: Box<dyn Grid>,
+    marker: Marker,
     dirty: bool,
     layers: Vec<Layer>,
     labels: Vec<Label<'a>>,
@@ -514,6 +560,7 @@ impl<'a> Context<'a> {
             x_bounds,
             y_bounds,
             grid,
+            marker,
             dirty: false,
             layers: Vec::new(),
             labels: Vec::new(),
@@ -644,6 +691,7 @@ where
     paint_func: Option<F>,
     background_color: Color,
     marker: Marker,
+    grid: Option<Gridlines>,
 }
 
 impl<F> Default for Canvas<'_, F>
@@ -658,6 +706,7 @@ where
             paint_func: None,
             background_color: Color::Reset,
             marker: Marker::Braille,
+            grid: None,
         }
     }
 }
@@ -717,6 +766,35 @@ where
         self
     }
 
+    /// Draw [`Gridlines`] beneath the shapes of the canvas.
+    ///
+    /// The grid is drawn in its own layer before calling the [`paint`](Self::paint) closure, so
+    /// every shape is drawn on top of it. When [`Gridlines::labels`] is set, the values of the
+    /// labeled lines are printed along the left and bottom edges of the canvas, together with the
+    /// other labels.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     widgets::canvas::{Canvas, Gridlines},
+    /// };
+    ///
+    /// Canvas::default()
+    ///     .x_bounds([0.0, 100.0])
+    ///     .y_bounds([0.0, 100.0])
+    ///     .grid(Gridlines::new(10.0, 10.0, Color::DarkGray))
+    ///     .paint(|ctx| {});
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn grid(mut self, grid: Gridlines) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
     /// Change the type of points used to draw the shapes.
     ///
     /// By default the [`Braille`] patterns are used as they provide a more fine grained result,
@@ -760,6 +838,89 @@ where
         self.marker = marker;
         self
     }
+
+    /// Returns the number of dots drawn in each terminal cell, by column and row.
+    ///
+    /// This is the sub-cell grid of the current [`marker`]:
+    ///
+    /// - [`Braille`]: `(2, 4)`, each cell holds a pattern of 2 columns by 4 rows of dots
+    /// - [`HalfBlock`]: `(1, 2)`, each cell holds an upper and a lower half block
+    /// - [`Dot`], [`Block`] and [`Bar`]: `(1, 1)`, each cell is a single dot
+    ///
+    /// A canvas rendered in an area of `width` by `height` cells therefore has
+    /// `width * columns` by `height * rows` dots. The [`x_bounds`] are spread over the dot columns,
+    /// so that the left bound falls on the first column and the right bound on the last one, and
+    /// the [`y_bounds`] are spread over the dot rows in the same way (bottom bound on the last
+    /// row). Each dot thus covers `(right - left) / (width * columns - 1)` units horizontally.
+    ///
+    /// [`marker`]: Self::marker
+    /// [`x_bounds`]: Self::x_bounds
+    /// [`y_bounds`]: Self::y_bounds
+    /// [`Braille`]: ratatui_core::symbols::Marker::Braille
+    /// [`HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
+    /// [`Dot`]: ratatui_core::symbols::Marker::Dot
+    /// [`Block`]: ratatui_core::symbols::Marker::Block
+    /// [`Bar`]: ratatui_core::symbols::Marker::Bar
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     symbols,
+    ///     widgets::canvas::{Canvas, Context},
+    /// };
+    ///
+    /// let canvas = Canvas::default().paint(|_: &mut Context| {});
+    /// assert_eq!(canvas.resolution(), (2, 4));
+    ///
+    /// let canvas = canvas.marker(symbols::Marker::HalfBlock);
+    /// assert_eq!(canvas.resolution(), (1, 2));
+    /// ```
+    pub const fn resolution(&self) -> (u16, u16) {
+        cell_resolution(self.marker)
+    }
+
+    /// Returns the number of dots, by column and row, that the canvas has when rendered in `area`.
+    ///
+    /// This is the area left inside the [`block`] multiplied by the [`resolution`] of the current
+    /// [`marker`]. As the marker can be changed between frames (e.g. to fall back to
+    /// [`HalfBlock`] when the terminal font has no Braille patterns), this can be used to adapt
+    /// the density of the drawing to the dots that are actually available.
+    ///
+    /// [`block`]: Self::block
+    /// [`resolution`]: Self::resolution
+    /// [`marker`]: Self::marker
+    /// [`HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     layout::Rect,
+    ///     symbols,
+    ///     widgets::{
+    ///         canvas::{Canvas, Context},
+    ///         Block,
+    ///     },
+    /// };
+    ///
+    /// let area = Rect::new(0, 0, 12, 7);
+    /// let canvas = Canvas::default().paint(|_: &mut Context| {});
+    /// assert_eq!(canvas.grid_size(area), (24.0, 28.0));
+    ///
+    /// let canvas = canvas
+    ///     .marker(symbols::Marker::HalfBlock)
+    ///     .block(Block::bordered());
+    /// assert_eq!(canvas.grid_size(area), (10.0, 10.0));
+    /// ```
+    pub fn grid_size(&self, area: Rect) -> (f64, f64) {
+        let area = self.block.inner_if_some(area);
+        let (columns, rows) = self.resolution();
+        (
+            f64::from(area.width) * f64::from(columns),
+            f64::from(area.height) * f64::from(rows),
+        )
+    }
 }
 
 impl<F> Widget for Canvas<'_, F>
@@ -798,6 +959,11 @@ where
             self.y_bounds,
             self.marker,
         );
+        if let Some(grid) = &self.grid {
+            ctx.draw(grid);
+            ctx.layer();
+            grid.print_labels(&mut ctx, canvas_area.width, canvas_area.height);
+        }
         // Paint to this context
         painter(&mut ctx);
         ctx.finish();
@@ -848,10 +1014,50 @@ where
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
-    use ratatui_core::buffer::Cell;
+    use ratatui_core::{buffer::Cell, style::Stylize};
+    use rstest::rstest;
 
     use super::*;
 
+    #[rstest]
+    #[case::braille(Marker::Braille, (20.0, 20.0))]
+    #[case::half_block(Marker::HalfBlock, (10.0, 10.0))]
+    #[case::dot(Marker::Dot, (10.0, 5.0))]
+    #[case::block(Marker::Block, (10.0, 5.0))]
+    #[case::bar(Marker::Bar, (10.0, 5.0))]
+    fn grid_size(#[case] marker: Marker, #[case] expected: (f64, f64)) {
+        let canvas = Canvas::default().marker(marker).paint(|_: &mut Context| {});
+        assert_eq!(canvas.grid_size(Rect::new(3, 2, 10, 5)), expected);
+    }
+
+    #[test]
+    fn grid_size_with_block() {
+        let canvas = Canvas::default()
+            .block(Block::bordered())
+            .paint(|_: &mut Context| {});
+        assert_eq!(canvas.grid_size(Rect::new(0, 0, 10, 5)), (16.0, 12.0));
+        assert_eq!(canvas.grid_size(Rect::new(0, 0, 1, 1)), (0.0, 0.0));
+    }
+
+    #[test]
+    fn half_block_marker_pairs_colors() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Canvas::default()
+            .marker(Marker::HalfBlock)
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                ctx.draw(&Points::new(&[(0.0, 1.0)], Color::Red));
+                ctx.draw(&Points::new(&[(0.0, 0.0)], Color::Blue));
+                ctx.draw(&Points::new(&[(1.0, 0.0)], Color::Green));
+            })
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["▀▄"]);
+        expected[(0, 0)].set_style(Style::new().red().on_blue());
+        expected[(1, 0)].set_style(Style::new().green());
+        assert_eq!(buffer, expected);
+    }
+
     // helper to test the canvas checks that drawing a vertical and horizontal line
     // results in the expected output
     fn test_marker(marker: Marker, expected: &str) {
@@ -0,0 +1,315 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Context, Line, Painter, Shape};
+
+/// A grid of horizontal and vertical lines to draw on a [`Canvas`](crate::canvas::Canvas)
+///
+/// The lines are drawn at every multiple of [`x_interval`](Self::x_interval) and
+/// [`y_interval`](Self::y_interval) that falls within the bounds of the canvas, so the grid stays
+/// aligned with the world coordinates when the bounds change. An interval that is not positive
+/// disables the lines of that axis. When there would be more lines than dots on an axis, the lines
+/// of that axis are not drawn.
+///
+/// Every [`major_every`](Self::major_every)-th line, counted from the origin, is a major line
+/// drawn with [`major_color`](Self::major_color) on top of the other (minor) lines. When
+/// [`labels`](Self::labels) is set and the grid is added with [`Canvas::grid`], the value of each
+/// major line (or each line when there are no major lines) is printed along the left and bottom
+/// edges of the canvas, with as many decimals as the distance between two labeled lines needs.
+///
+/// [`Canvas::grid`]: crate::canvas::Canvas::grid
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     style::Color,
+///     widgets::canvas::{Canvas, Gridlines},
+/// };
+///
+/// // minor lines every 10 units, and labeled major lines every 50 units
+/// let grid = Gridlines {
+///     major_every: 5,
+///     major_color: Color::Gray,
+///     labels: true,
+///     ..Gridlines::new(10.0, 10.0, Color::DarkGray)
+/// };
+/// Canvas::default()
+///     .x_bounds([-100.0, 100.0])
+///     .y_bounds([-50.0, 50.0])
+///     .grid(grid)
+///     .paint(|ctx| {});
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Gridlines {
+    /// Distance between two vertical lines, in world coordinates
+    pub x_interval: f64,
+    /// Distance between two horizontal lines, in world coordinates
+    pub y_interval: f64,
+    /// Color of the minor lines
+    pub color: Color,
+    /// Number of intervals between two major lines, or `0` for no major lines
+    pub major_every: u32,
+    /// Color of the major lines
+    pub major_color: Color,
+    /// Whether to print the value of the labeled lines along the edges of the canvas
+    pub labels: bool,
+}
+
+impl Gridlines {
+    /// Create a new grid with lines every `x_interval` and `y_interval` in the given color
+    pub const fn new(x_interval: f64, y_interval: f64, color: Color) -> Self {
+        Self {
+            x_interval,
+            y_interval,
+            color,
+            major_every: 0,
+            major_color: color,
+            labels: false,
+        }
+    }
+
+    /// Returns whether the line at the given multiple of the interval is a major line
+    const fn is_major(&self, multiple: i64) -> bool {
+        self.major_every != 0 && multiple % self.major_every as i64 == 0
+    }
+
+    /// Prints the values of the labeled lines along the left and bottom edges of the context
+    pub(super) fn print_labels(&self, ctx: &mut Context, columns: u16, rows: u16) {
+        if !self.labels {
+            return;
+        }
+        let [left, right] = ctx.x_bounds;
+        let [bottom, top] = ctx.y_bounds;
+        let labeled_every = f64::from(self.major_every.max(1));
+        let precision = label_precision(self.x_interval * labeled_every);
+        for (multiple, x) in ticks([left, right], self.x_interval, f64::from(columns)) {
+            if self.major_every == 0 || self.is_major(multiple) {
+                ctx.print(x, bottom, format!("{x:.precision$}"));
+            }
+        }
+        let precision = label_precision(self.y_interval * labeled_every);
+        for (multiple, y) in ticks([bottom, top], self.y_interval, f64::from(rows)) {
+            if self.major_every == 0 || self.is_major(multiple) {
+                ctx.print(left, y, format!("{y:.precision$}"));
+            }
+        }
+    }
+}
+
+/// Returns the number of decimals needed to print the multiples of `interval`
+///
+/// This hides the rounding errors of the multiples (e.g. `3.0 * 0.1` is `0.30000000000000004`).
+fn label_precision(interval: f64) -> usize {
+    const MAX_PRECISION: usize = 6;
+    let mut scaled = interval.abs();
+    for precision in 0..MAX_PRECISION {
+        if (scaled - scaled.round()).abs() <= 1e-9 * scaled {
+            return precision;
+        }
+        scaled *= 10.0;
+    }
+    MAX_PRECISION
+}
+
+impl Shape for Gridlines {
+    fn draw(&self, painter: &mut Painter) {
+        let (&[left, right], &[bottom, top]) = painter.bounds();
+        let (columns, rows) = painter.resolution;
+        let vertical = ticks([left, right], self.x_interval, columns)
+            .map(|(multiple, x)| (multiple, Line::new(x, bottom, x, top, self.color)));
+        let horizontal = ticks([bottom, top], self.y_interval, rows)
+            .map(|(multiple, y)| (multiple, Line::new(left, y, right, y, self.color)));
+        let lines: Vec<_> = vertical.chain(horizontal).collect();
+        for (_, line) in lines
+            .iter()
+            .filter(|(multiple, _)| !self.is_major(*multiple))
+        {
+            line.draw(painter);
+        }
+        for (_, line) in lines
+            .iter()
+            .filter(|(multiple, _)| self.is_major(*multiple))
+        {
+            let line = Line {
+                color: self.major_color,
+                ..line.clone()
+            };
+            line.draw(painter);
+        }
+    }
+}
+
+/// Returns the multiples of `interval` within `bounds`, along with their value.
+///
+/// Returns nothing when the interval is not positive or when there would be more than `limit`
+/// multiples.
+fn ticks(bounds: [f64; 2], interval: f64, limit: f64) -> impl Iterator<Item = (i64, f64)> {
+    let [min, max] = bounds;
+    let first = (min / interval).ceil();
+    let count = (max / interval).floor() - first + 1.0;
+    let count = if interval > 0.0 && interval.is_finite() && count > 0.0 && count <= limit {
+        count as i64
+    } else {
+        0
+    };
+    (0..count).map(move |index| {
+        let multiple = first as i64 + index;
+        (multiple, multiple as f64 * interval)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        symbols::Marker,
+        widgets::Widget,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::canvas::{Canvas, Points};
+
+    #[test]
+    fn new() {
+        let grid = Gridlines::new(1.0, 2.0, Color::Red);
+        assert_eq!(
+            grid,
+            Gridlines {
+                x_interval: 1.0,
+                y_interval: 2.0,
+                color: Color::Red,
+                major_every: 0,
+                major_color: Color::Red,
+                labels: false,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::aligned([0.0, 10.0], 5.0, vec![(0, 0.0), (1, 5.0), (2, 10.0)])]
+    #[case::unaligned([-3.0, 7.0], 4.0, vec![(0, 0.0), (1, 4.0)])]
+    #[case::negative([-10.0, -1.0], 4.0, vec![(-2, -8.0), (-1, -4.0)])]
+    #[case::zero_interval([0.0, 10.0], 0.0, vec![])]
+    #[case::negative_interval([0.0, 10.0], -1.0, vec![])]
+    #[case::too_many([0.0, 10.0], 0.001, vec![])]
+    fn ticks(#[case] bounds: [f64; 2], #[case] interval: f64, #[case] expected: Vec<(i64, f64)>) {
+        assert_eq!(
+            super::ticks(bounds, interval, 100.0).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::integer(10.0, 0)]
+    #[case::tenth(0.1, 1)]
+    #[case::quarter(0.25, 2)]
+    #[case::rounding_error(0.1 + 0.2, 1)]
+    #[case::too_small(1e-9, 6)]
+    fn label_precision(#[case] interval: f64, #[case] expected: usize) {
+        assert_eq!(super::label_precision(interval), expected);
+    }
+
+    #[test]
+    fn draw_gridlines() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([-5.0, 5.0])
+            .y_bounds([0.0, 8.0])
+            .grid(Gridlines::new(2.5, 4.0, Color::Red))
+            .paint(|_| {})
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "•••••••••••",
+            "•  • •  • •",
+            "•••••••••••",
+            "•  • •  • •",
+            "•••••••••••",
+        ]);
+        for x in [0, 3, 5, 8, 10] {
+            expected.set_style(Rect::new(x, 0, 1, 5), Style::new().red());
+        }
+        for y in [0, 2, 4] {
+            expected.set_style(Rect::new(0, y, 11, 1), Style::new().red());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_major_gridlines_with_labels() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        let grid = Gridlines {
+            major_every: 2,
+            major_color: Color::Blue,
+            labels: true,
+            ..Gridlines::new(4.0, 4.0, Color::Red)
+        };
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 8.0])
+            .grid(grid)
+            .paint(|_| {})
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "8••••••••••",
+            "•   •   •  ",
+            "•••••••••••",
+            "•   •   •  ",
+            "0•••••••8••",
+        ]);
+        expected.set_style(Rect::new(0, 2, 11, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 1, 1, 3), Style::new().red());
+        let rows = [Rect::new(0, 0, 11, 1), Rect::new(0, 4, 11, 1)];
+        let columns = [Rect::new(0, 0, 1, 5), Rect::new(8, 0, 1, 5)];
+        for major in rows.into_iter().chain(columns) {
+            expected.set_style(major, Style::new().blue());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn print_labels_with_interval_precision() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 4));
+        let grid = Gridlines {
+            labels: true,
+            ..Gridlines::new(0.5, 0.1, Color::Red)
+        };
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.15, 0.35])
+            .grid(grid)
+            .paint(|_| {})
+            .render(buffer.area, &mut buffer);
+        // only the labels are checked here
+        buffer.set_style(buffer.area, Style::reset());
+        let expected =
+            Buffer::with_lines(["0.3  •    •", "•••••••••••", "0.2••••••••", "0.0  0.5  1"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draws_beneath_shapes() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 2.0])
+            .grid(Gridlines::new(1.0, 1.0, Color::Red))
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &[(1.0, 1.0)],
+                    color: Color::Green,
+                });
+            })
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["███"; 3]);
+        expected.set_style(buffer.area, Style::new().red());
+        expected[(1, 1)].set_style(Style::new().green());
+        assert_eq!(buffer, expected);
+    }
+}
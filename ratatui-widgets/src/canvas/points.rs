@@ -27,3 +27,164 @@ impl Shape for Points<'_> {
         }
     }
 }
+
+/// A group of points lighting a number of dots proportional to their intensity.
+///
+/// Each point lights some of the dots of the terminal cell it falls in: an intensity of `0.0`
+/// lights none, an intensity of `1.0` lights all of them, and values in between light a
+/// proportional number of dots (rounded to the nearest dot). Intensities outside of `0.0..=1.0`
+/// are clamped. With the [`Braille`] marker each cell holds a grid of 2x4 dots, which makes for
+/// 9 levels of density, while the [`HalfBlock`] marker only gives 3 levels and the other markers
+/// simply draw the cell when the intensity is at least `0.5`. See [`Canvas::resolution`].
+///
+/// The dots are lit in a fixed order that spreads them over the cell, so that cells of the same
+/// intensity look alike. Points in the same cell add up their dots rather than their intensity.
+///
+/// This is useful to draw density plots and heatmaps.
+///
+/// [`Braille`]: ratatui_core::symbols::Marker::Braille
+/// [`HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
+/// [`Canvas::resolution`]: crate::canvas::Canvas::resolution
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::Color,
+///     widgets::canvas::{Canvas, IntensityPoints},
+/// };
+///
+/// Canvas::default()
+///     .x_bounds([0.0, 10.0])
+///     .y_bounds([0.0, 10.0])
+///     .paint(|ctx| {
+///         ctx.draw(&IntensityPoints::new(
+///             &[(1.0, 1.0, 0.25), (5.0, 5.0, 0.5), (9.0, 9.0, 1.0)],
+///             Color::Yellow,
+///         ));
+///     });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IntensityPoints<'a> {
+    /// List of points to draw, as `(x, y, intensity)`
+    pub coords: &'a [(f64, f64, f64)],
+    /// Color of the points
+    pub color: Color,
+}
+
+/// The order in which the dots of a braille cell are lit, as `(column, row)`.
+const BRAILLE_FILL_ORDER: [(usize, usize); 8] = [
+    (0, 0),
+    (1, 2),
+    (1, 0),
+    (0, 2),
+    (0, 1),
+    (1, 3),
+    (1, 1),
+    (0, 3),
+];
+
+impl<'a> IntensityPoints<'a> {
+    /// Create a new `IntensityPoints` shape with the given `(x, y, intensity)` coordinates and
+    /// color
+    pub const fn new(coords: &'a [(f64, f64, f64)], color: Color) -> Self {
+        Self { coords, color }
+    }
+}
+
+impl Shape for IntensityPoints<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let (columns, rows) = painter.cell_resolution();
+        let (columns, rows) = (usize::from(columns), usize::from(rows));
+        let dots = columns * rows;
+        for (x, y, intensity) in self.coords {
+            let Some((x, y)) = painter.get_point(*x, *y) else {
+                continue;
+            };
+            let lit = (intensity.clamp(0.0, 1.0) * dots as f64).round() as usize;
+            let (left, top) = (x - x % columns, y - y % rows);
+            if (columns, rows) == (2, 4) {
+                for (dx, dy) in BRAILLE_FILL_ORDER.into_iter().take(lit) {
+                    painter.paint(left + dx, top + dy, self.color);
+                }
+            } else {
+                for index in 0..lit {
+                    painter.paint(left + index % columns, top + index / columns, self.color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{buffer::Buffer, layout::Rect, symbols::Marker, widgets::Widget};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    /// Renders a single point of the given intensity in a 1x1 canvas and returns the number of dots
+    /// lit in the braille cell.
+    fn braille_dots(intensity: f64) -> u32 {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Canvas::default()
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| ctx.draw(&IntensityPoints::new(&[(0.5, 0.5, intensity)], Color::Red)))
+            .render(buffer.area, &mut buffer);
+        let symbol = buffer[(0, 0)].symbol().chars().next().unwrap();
+        match symbol {
+            ' ' => 0,
+            _ => (u32::from(symbol) - 0x2800).count_ones(),
+        }
+    }
+
+    #[rstest]
+    #[case::none(0.0, 0)]
+    #[case::eighth(0.125, 1)]
+    #[case::quarter(0.25, 2)]
+    #[case::rounded_down(0.3, 2)]
+    #[case::rounded_up(0.45, 4)]
+    #[case::half(0.5, 4)]
+    #[case::three_quarters(0.75, 6)]
+    #[case::full(1.0, 8)]
+    #[case::clamped_low(-1.0, 0)]
+    #[case::clamped_high(2.0, 8)]
+    fn braille_dot_count(#[case] intensity: f64, #[case] expected: u32) {
+        assert_eq!(braille_dots(intensity), expected);
+    }
+
+    #[test]
+    fn braille_dots_spread_over_the_cell() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Canvas::default()
+            .x_bounds([0.0, 5.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                ctx.draw(&IntensityPoints::new(
+                    &[(0.0, 0.0, 0.25), (2.0, 0.0, 0.5), (4.0, 0.0, 1.0)],
+                    Color::Red,
+                ));
+            })
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["⠡⠭⣿"]);
+        expected.set_style(buffer.area, Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::none(0.0, " ")]
+    #[case::upper(0.5, "▀")]
+    #[case::full(1.0, "█")]
+    fn half_block_intensity(#[case] intensity: f64, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Canvas::default()
+            .marker(Marker::HalfBlock)
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| ctx.draw(&IntensityPoints::new(&[(0.5, 0.5, intensity)], Color::Red)))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].symbol(), expected);
+    }
+}
@@ -0,0 +1,207 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Line, Painter, Shape};
+
+/// A filled polygon to draw on a [`Canvas`](crate::canvas::Canvas)
+///
+/// The polygon is closed automatically: the last point is connected back to the first one. It is
+/// filled using the even-odd rule, so concave polygons are filled correctly and the points can be
+/// given in either winding order. Self-intersecting polygons leave the areas that are enclosed an
+/// even number of times empty.
+///
+/// The outline of the polygon is drawn in addition to its inside, so that thin parts of the shape
+/// remain visible. Coordinates are expressed in the coordinate system of the canvas, and the parts
+/// of the polygon outside of its bounds are not drawn.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     style::Color,
+///     widgets::canvas::{Canvas, Polygon},
+/// };
+///
+/// Canvas::default()
+///     .x_bounds([0.0, 10.0])
+///     .y_bounds([0.0, 10.0])
+///     .paint(|ctx| {
+///         ctx.draw(&Polygon::new(
+///             vec![(1.0, 1.0), (9.0, 1.0), (5.0, 9.0)],
+///             Color::Green,
+///         ));
+///     });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polygon {
+    /// The vertices of the polygon
+    pub points: Vec<(f64, f64)>,
+    /// The color of the polygon
+    pub color: Color,
+}
+
+impl Polygon {
+    /// Create a new polygon with the given vertices and color
+    pub const fn new(points: Vec<(f64, f64)>, color: Color) -> Self {
+        Self { points, color }
+    }
+
+    /// Returns the edges of the polygon, including the one closing it.
+    fn edges(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+        let next = self.points.iter().cycle().skip(1);
+        self.points.iter().copied().zip(next.copied())
+    }
+}
+
+impl Shape for Polygon {
+    fn draw(&self, painter: &mut Painter) {
+        let (&[left, right], &[bottom, top]) = painter.bounds();
+        let (columns, rows) = painter.resolution;
+        let (width, height) = (right - left, top - bottom);
+        if width <= 0.0 || height <= 0.0 || self.points.is_empty() {
+            return;
+        }
+        let mut crossings = Vec::new();
+        for row in 0..rows as usize {
+            // the world coordinate of the center of the dots of this row (see `get_point`)
+            let y = top - row as f64 * height / (rows - 1.0).max(1.0);
+            crossings.clear();
+            for ((x1, y1), (x2, y2)) in self.edges() {
+                // half-open rule: a vertex on the scanline counts for only one of its edges
+                if (y1 <= y) != (y2 <= y) {
+                    crossings.push(x1 + (y - y1) * (x2 - x1) / (y2 - y1));
+                }
+            }
+            crossings.sort_by(f64::total_cmp);
+            for span in crossings.chunks_exact(2) {
+                let start = ((span[0] - left) * (columns - 1.0) / width).ceil();
+                let end = ((span[1] - left) * (columns - 1.0) / width).floor();
+                let start = start.max(0.0) as usize;
+                let end = end.min(columns - 1.0);
+                if end < 0.0 {
+                    continue;
+                }
+                for column in start..=end as usize {
+                    painter.paint(column, row, self.color);
+                }
+            }
+        }
+        for ((x1, y1), (x2, y2)) in self.edges() {
+            Line::new(x1, y1, x2, y2, self.color).draw(painter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        symbols::Marker,
+        widgets::Widget,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    fn render(polygon: &Polygon, marker: Marker, area: Rect) -> Buffer {
+        let mut buffer = Buffer::empty(area);
+        Canvas::default()
+            .marker(marker)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 9.0])
+            .paint(|ctx| ctx.draw(polygon))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    const CONCAVE: [(f64, f64); 8] = [
+        (0.0, 0.0),
+        (9.0, 0.0),
+        (9.0, 9.0),
+        (6.0, 9.0),
+        (6.0, 3.0),
+        (3.0, 3.0),
+        (3.0, 9.0),
+        (0.0, 9.0),
+    ];
+
+    #[rstest]
+    #[case::clockwise(CONCAVE.to_vec())]
+    #[case::counter_clockwise(CONCAVE.iter().rev().copied().collect())]
+    fn draw_concave(#[case] points: Vec<(f64, f64)>) {
+        let buffer = render(
+            &Polygon::new(points, Color::Red),
+            Marker::Block,
+            Rect::new(0, 0, 10, 10),
+        );
+        let mut expected = Buffer::with_lines([
+            "████  ████",
+            "████  ████",
+            "████  ████",
+            "████  ████",
+            "████  ████",
+            "████  ████",
+            "██████████",
+            "██████████",
+            "██████████",
+            "██████████",
+        ]);
+        expected.set_style(Rect::new(0, 0, 4, 10), Style::new().red());
+        expected.set_style(Rect::new(4, 6, 2, 4), Style::new().red());
+        expected.set_style(Rect::new(6, 0, 4, 10), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_triangle() {
+        let triangle = Polygon::new(vec![(0.0, 0.0), (8.0, 0.0), (0.0, 8.0)], Color::Red);
+        let buffer = render(&triangle, Marker::Block, Rect::new(0, 0, 10, 10));
+        let mut expected = Buffer::with_lines([
+            "          ",
+            "█         ",
+            "██        ",
+            "███       ",
+            "████      ",
+            "█████     ",
+            "██████    ",
+            "███████   ",
+            "████████  ",
+            "█████████ ",
+        ]);
+        for y in 1..10 {
+            expected.set_style(Rect::new(0, y, y, 1), Style::new().red());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_outside_bounds() {
+        let square = Polygon::new(
+            vec![(-5.0, -5.0), (20.0, -5.0), (20.0, 20.0), (-5.0, 20.0)],
+            Color::Red,
+        );
+        let buffer = render(&square, Marker::Block, Rect::new(0, 0, 10, 10));
+        let mut expected = Buffer::with_lines(["██████████"; 10]);
+        expected.set_style(buffer.area, Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::empty(vec![], Rect::ZERO)]
+    #[case::point(vec![(4.0, 4.0)], Rect::new(4, 5, 1, 1))]
+    #[case::line(vec![(0.0, 4.0), (9.0, 4.0)], Rect::new(0, 5, 10, 1))]
+    fn draw_degenerate(#[case] points: Vec<(f64, f64)>, #[case] drawn: Rect) {
+        let buffer = render(
+            &Polygon::new(points, Color::Red),
+            Marker::Block,
+            Rect::new(0, 0, 10, 10),
+        );
+        let mut expected = Buffer::empty(buffer.area);
+        for position in drawn.positions() {
+            expected[position].set_symbol("█").set_fg(Color::Red);
+        }
+        assert_eq!(buffer, expected);
+    }
+}
@@ -1,12 +1,12 @@
 //! The [`Chart`] widget is used to plot one or more [`Dataset`] in a cartesian coordinate system.
-use std::{cmp::max, ops::Not};
+use std::{borrow::Cow, cmp::max, fmt, ops::Not, sync::Arc};
 
 use ratatui_core::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Flex, Layout, Position, Rect},
     style::{Color, Style, Styled},
     symbols::{self},
-    text::Line,
+    text::{Line, Span},
     widgets::Widget,
 };
 use strum::{Display, EnumString};
@@ -48,10 +48,42 @@ pub struct Axis<'a> {
     bounds: [f64; 2],
     /// A list of labels to put to the left or below the axis
     labels: Vec<Line<'a>>,
+    /// Formats the labels from the bounds of the axis when the chart is rendered
+    labels_fn: Option<LabelsFn<'a>>,
     /// The style used to draw the axis itself
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// The padding around the data when the bounds are computed from the datasets
+    auto_bounds: Option<f64>,
+}
+
+/// A function formatting the value of an axis tick, see [`Axis::labels_fn`]
+///
+/// The labels are owned, so that [`Axis`] stays covariant over `'a`.
+type FormatFn<'a> = dyn Fn(f64) -> Line<'static> + Send + Sync + 'a;
+
+/// The number of labels of an [`Axis`] and the function formatting them
+///
+/// Functions cannot be compared, so labels with the same count are considered equal.
+#[derive(Clone)]
+struct LabelsFn<'a> {
+    count: usize,
+    format: Arc<FormatFn<'a>>,
+}
+
+impl fmt::Debug for LabelsFn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LabelsFn")
+            .field("count", &self.count)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for LabelsFn<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count
+    }
 }
 
 impl<'a> Axis<'a> {
@@ -81,6 +113,63 @@ impl<'a> Axis<'a> {
         self
     }
 
+    /// Computes the bounds of this axis from the data of the chart's datasets
+    ///
+    /// When rendering, the bounds are set to the smallest and largest values of the data points
+    /// on this axis, across all the datasets of the [`Chart`], so that no point is clipped. Each
+    /// axis computes its bounds independently from the other axis. The bounds are then extended
+    /// on each side by `padding` times their range (e.g. `0.05` for 5%), so that the extreme
+    /// points are not drawn on the very edge of the graph.
+    ///
+    /// The bounds set with [`Axis::bounds`] are ignored, unless there is no data to compute the
+    /// bounds from. When all the values are equal (e.g. a single data point), the bounds are a
+    /// range of 1 centered on the value. The Y axis bounds of a chart with
+    /// [`GraphType::Bar`] datasets always include 0, where the bars start. Values that are not
+    /// finite are ignored.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart, Dataset};
+    ///
+    /// let data = [(0.0, 5.0), (1.0, 15.0)];
+    /// // the Y axis goes from 4.5 to 15.5
+    /// let chart = Chart::new(vec![Dataset::default().data(&data)])
+    ///     .x_axis(Axis::default().auto_bounds(0.0))
+    ///     .y_axis(Axis::default().auto_bounds(0.05));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_bounds(mut self, padding: f64) -> Self {
+        self.auto_bounds = Some(padding);
+        self
+    }
+
+    /// Returns the bounds of this axis when drawing the given values
+    fn resolve_bounds(&self, values: impl Iterator<Item = f64>) -> [f64; 2] {
+        let Some(padding) = self.auto_bounds else {
+            return self.bounds;
+        };
+        let Some((min, max)) =
+            values
+                .filter(|value| value.is_finite())
+                .fold(None, |bounds, value| match bounds {
+                    None => Some((value, value)),
+                    Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+                })
+        else {
+            return self.bounds;
+        };
+        let (min, max) = if min < max {
+            (min, max)
+        } else {
+            (min - 0.5, max + 0.5)
+        };
+        let padding = (max - min) * padding.max(0.0);
+        [min - padding, max + padding]
+    }
+
     /// Sets the axis labels
     ///
     /// - For the X axis, the labels are displayed left to right.
@@ -115,9 +204,72 @@ impl<'a> Axis<'a> {
         Labels::Item: Into<Line<'a>>,
     {
         self.labels = labels.into_iter().map(Into::into).collect();
+        self.labels_fn = None;
+        self
+    }
+
+    /// Sets the axis labels by formatting the values at evenly spaced ticks
+    ///
+    /// `count` labels are generated for values evenly spaced between the bounds of the axis
+    /// (inclusive), which is where the labels are displayed. Each value is formatted with
+    /// `format`, which returns any type that can be converted into an owned [`Line`] (e.g. a
+    /// `String` or a `&'static str`). This is useful for axes whose values need formatting, such
+    /// as Unix timestamps that should be shown as times.
+    ///
+    /// The labels are computed when the chart is rendered, from the bounds the axis is drawn with,
+    /// so they also follow the bounds computed with [`Axis::auto_bounds`]. The generated labels
+    /// behave exactly like labels set with [`Axis::labels`], so their width is taken into account
+    /// when laying out the chart. This replaces the labels set with [`Axis::labels`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Axis;
+    ///
+    /// // format Unix timestamps as HH:MM:SS
+    /// let axis = Axis::default()
+    ///     .bounds([1_700_000_000.0, 1_700_000_600.0])
+    ///     .labels_fn(3, |timestamp| {
+    ///         let seconds = timestamp as u64 % 86_400;
+    ///         format!(
+    ///             "{:02}:{:02}:{:02}",
+    ///             seconds / 3600,
+    ///             seconds % 3600 / 60,
+    ///             seconds % 60
+    ///         )
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn labels_fn<F, L>(mut self, count: usize, format: F) -> Self
+    where
+        F: Fn(f64) -> L + Send + Sync + 'a,
+        L: Into<Line<'static>>,
+    {
+        self.labels = Vec::new();
+        self.labels_fn = Some(LabelsFn {
+            count,
+            format: Arc::new(move |value| format(value).into()),
+        });
         self
     }
 
+    /// Returns the labels of this axis when it is drawn with the given bounds
+    fn resolve_labels(&self, [min, max]: [f64; 2]) -> Cow<'_, [Line<'a>]> {
+        let Some(LabelsFn { count, format }) = &self.labels_fn else {
+            return Cow::Borrowed(&self.labels);
+        };
+        let step = if *count > 1 {
+            (max - min) / (count - 1) as f64
+        } else {
+            0.0
+        };
+        (0..*count)
+            .map(|i| format(step.mul_add(i as f64, min)))
+            .collect()
+    }
+
     /// Sets the axis style
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -332,6 +484,35 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Whether a marker is drawn on each data point
+    show_markers: bool,
+    /// Symbol used for the markers, defaults to [`symbols::DOT`]
+    marker_symbol: Option<&'a str>,
+    /// Labels drawn next to the data points
+    point_labels: Option<PointLabels<'a>>,
+}
+
+/// A function returning the label of a data point, see [`Dataset::point_labels`]
+///
+/// The labels are owned, so that [`Dataset`] stays covariant over `'a`.
+type PointLabelFn<'a> = dyn Fn(usize, (f64, f64)) -> Option<Span<'static>> + Send + Sync + 'a;
+
+/// The labels of the data points of a [`Dataset`]
+///
+/// Functions cannot be compared, so all labels are considered equal.
+#[derive(Clone)]
+struct PointLabels<'a>(Arc<PointLabelFn<'a>>);
+
+impl fmt::Debug for PointLabels<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PointLabels")
+    }
+}
+
+impl PartialEq for PointLabels<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
 impl<'a> Dataset<'a> {
@@ -424,6 +605,81 @@ impl<'a> Dataset<'a> {
         self.style = style.into();
         self
     }
+
+    /// Sets whether a marker is drawn on each data point
+    ///
+    /// The [line](GraphType::Line) and [bar](GraphType::Bar) graph types draw their points with
+    /// the same [marker](Dataset::marker) as the lines between them, which makes it hard to tell
+    /// where the actual samples are. When enabled, a [`marker_symbol`](Dataset::marker_symbol) is
+    /// drawn in the cell of each data point, on top of the lines, using the foreground color of
+    /// the dataset's style.
+    ///
+    /// Points that fall in the same cell share a single marker. Points outside of the axis bounds
+    /// are not marked.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Dataset, GraphType};
+    ///
+    /// let dataset = Dataset::default()
+    ///     .data(&[(0.0, 0.0), (5.0, 10.0), (10.0, 5.0)])
+    ///     .graph_type(GraphType::Line)
+    ///     .show_markers(true)
+    ///     .marker_symbol("x");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_markers(mut self, show_markers: bool) -> Self {
+        self.show_markers = show_markers;
+        self
+    }
+
+    /// Sets the symbol drawn on each data point when [markers are
+    /// shown](Dataset::show_markers)
+    ///
+    /// Defaults to [`symbols::DOT`] (`•`). The symbol should be a single cell wide.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker_symbol(mut self, symbol: &'a str) -> Self {
+        self.marker_symbol = Some(symbol);
+        self
+    }
+
+    /// Sets a function returning the label drawn next to each data point
+    ///
+    /// The function is called with the index and the value of each data point within the axis
+    /// bounds, and returns the label to draw for that point, or `None` to leave the point
+    /// unlabeled. This allows labeling only some points, e.g. the maximum of a series. The labels
+    /// are owned spans, e.g. created from a `String` or a `&'static str`.
+    ///
+    /// The label starts in the cell to the right of the point and is clipped to the graph area.
+    /// Points are drawn in order, so when labels overlap, or when several points fall in the same
+    /// cell, the label of the later point is drawn over the earlier ones.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Stylize, widgets::Dataset};
+    ///
+    /// let data = [(0.0, 2.0), (1.0, 7.0), (2.0, 4.0)];
+    /// let dataset = Dataset::default()
+    ///     .data(&data)
+    ///     .show_markers(true)
+    ///     .point_labels(|_, (_, y)| (y > 5.0).then(|| format!("{y}").yellow()));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn point_labels<F>(mut self, labels: F) -> Self
+    where
+        F: Fn(usize, (f64, f64)) -> Option<Span<'static>> + Send + Sync + 'a,
+    {
+        self.point_labels = Some(PointLabels(Arc::new(labels)));
+        self
+    }
 }
 
 /// A container that holds all the infos about where to display each elements of the chart (axis,
@@ -447,6 +703,14 @@ struct ChartLayout {
     graph_area: Rect,
 }
 
+/// The labels of the axes of the chart, resolved against the bounds the chart is drawn with
+struct AxesLabels<'l, 'a> {
+    /// Labels of the x axis
+    x: Cow<'l, [Line<'a>]>,
+    /// Labels of the y axis
+    y: Cow<'l, [Line<'a>]>,
+}
+
 /// A widget to plot one or more [`Dataset`] in a cartesian coordinate system
 ///
 /// To use this widget, start by creating one or more [`Dataset`]. With it, you can set the
@@ -731,7 +995,7 @@ impl<'a> Chart<'a> {
 
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
-    fn layout(&self, area: Rect) -> Option<ChartLayout> {
+    fn layout(&self, area: Rect, labels: &AxesLabels) -> Option<ChartLayout> {
         if area.height == 0 || area.width == 0 {
             return None;
         }
@@ -739,22 +1003,22 @@ impl<'a> Chart<'a> {
         let mut y = area.bottom() - 1;
 
         let mut label_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
+        if !labels.x.is_empty() && y > area.top() {
             label_x = Some(y);
             y -= 1;
         }
 
-        let label_y = self.y_axis.labels.is_empty().not().then_some(x);
-        x += self.max_width_of_labels_left_of_y_axis(area, !self.y_axis.labels.is_empty());
+        let label_y = labels.y.is_empty().not().then_some(x);
+        x += self.max_width_of_labels_left_of_y_axis(area, labels, !labels.y.is_empty());
 
         let mut axis_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
+        if !labels.x.is_empty() && y > area.top() {
             axis_x = Some(y);
             y -= 1;
         }
 
         let mut axis_y = None;
-        if !self.y_axis.labels.is_empty() && x + 1 < area.right() {
+        if !labels.y.is_empty() && x + 1 < area.right() {
             axis_y = Some(x);
             x += 1;
         }
@@ -838,16 +1102,15 @@ impl<'a> Chart<'a> {
         })
     }
 
-    fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
-        let mut max_width = self
-            .y_axis
-            .labels
-            .iter()
-            .map(Line::width)
-            .max()
-            .unwrap_or_default() as u16;
+    fn max_width_of_labels_left_of_y_axis(
+        &self,
+        area: Rect,
+        labels: &AxesLabels,
+        has_y_axis: bool,
+    ) -> u16 {
+        let mut max_width = labels.y.iter().map(Line::width).max().unwrap_or_default() as u16;
 
-        if let Some(first_x_label) = self.x_axis.labels.first() {
+        if let Some(first_x_label) = labels.x.first() {
             let first_label_width = first_x_label.width() as u16;
             let width_left_of_y_axis = match self.x_axis.labels_alignment {
                 Alignment::Left => {
@@ -869,11 +1132,11 @@ impl<'a> Chart<'a> {
         &self,
         buf: &mut Buffer,
         layout: &ChartLayout,
+        labels: &[Line],
         chart_area: Rect,
         graph_area: Rect,
     ) {
         let Some(y) = layout.label_x else { return };
-        let labels = &self.x_axis.labels;
         let labels_len = labels.len() as u16;
         if labels_len < 2 {
             return;
@@ -948,11 +1211,11 @@ impl<'a> Chart<'a> {
         &self,
         buf: &mut Buffer,
         layout: &ChartLayout,
+        labels: &[Line],
         chart_area: Rect,
         graph_area: Rect,
     ) {
         let Some(x) = layout.label_y else { return };
-        let labels = &self.y_axis.labels;
         let labels_len = labels.len() as u16;
         for (i, label) in labels.iter().enumerate() {
             let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
@@ -967,6 +1230,95 @@ impl<'a> Chart<'a> {
             }
         }
     }
+
+    /// Draws the markers and labels of the data points of `dataset`
+    ///
+    /// `resolution` is the number of dots per cell of the canvas that drew the dataset.
+    fn render_point_markers(
+        dataset: &Dataset,
+        resolution: (u16, u16),
+        bounds: [[f64; 2]; 2],
+        graph_area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if !dataset.show_markers && dataset.point_labels.is_none() {
+            return;
+        }
+        let symbol = dataset.marker_symbol.unwrap_or(symbols::DOT);
+        let color = dataset.style.fg.unwrap_or(Color::Reset);
+        for (index, &point) in dataset.data.iter().enumerate() {
+            let Some(Position { x, y }) =
+                Self::point_position(resolution, bounds, graph_area, point)
+            else {
+                continue;
+            };
+            if dataset.show_markers {
+                buf[(x, y)].set_symbol(symbol).set_fg(color);
+            }
+            let label = dataset
+                .point_labels
+                .as_ref()
+                .and_then(|labels| (labels.0)(index, point));
+            if let Some(label) = label {
+                let x = x + 1;
+                if x < graph_area.right() {
+                    buf.set_span(x, y, &label, graph_area.right() - x);
+                }
+            }
+        }
+    }
+
+    /// Returns the bounds of the X and Y axes, computing them from the datasets if requested
+    fn bounds(&self) -> [[f64; 2]; 2] {
+        let points = || self.datasets.iter().flat_map(|dataset| dataset.data);
+        let has_bars = self
+            .datasets
+            .iter()
+            .any(|dataset| dataset.graph_type == GraphType::Bar);
+        let bar_base = has_bars.then_some(0.0);
+        [
+            self.x_axis.resolve_bounds(points().map(|(x, _)| *x)),
+            self.y_axis
+                .resolve_bounds(points().map(|(_, y)| *y).chain(bar_base)),
+        ]
+    }
+
+    /// Returns the labels of the X and Y axes when they are drawn with the given bounds
+    fn labels(&self, [x_bounds, y_bounds]: [[f64; 2]; 2]) -> AxesLabels<'_, 'a> {
+        AxesLabels {
+            x: self.x_axis.resolve_labels(x_bounds),
+            y: self.y_axis.resolve_labels(y_bounds),
+        }
+    }
+
+    /// Returns the cell of `graph_area` in which the canvas draws `point`
+    ///
+    /// The point is located on the dot grid of the canvas, with `resolution` dots per cell, so
+    /// that the cell matches the one of the point drawn by the dataset.
+    fn point_position(
+        (columns, rows): (u16, u16),
+        [[left, right], [bottom, top]]: [[f64; 2]; 2],
+        graph_area: Rect,
+        (x, y): (f64, f64),
+    ) -> Option<Position> {
+        if graph_area.is_empty() || x < left || x > right || y < bottom || y > top {
+            return None;
+        }
+        let width = right - left;
+        let height = top - bottom;
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+        // the dot grid can be wider than u16 when there are several dots per cell
+        let last_column = f64::from((u32::from(graph_area.width) * u32::from(columns)).max(1) - 1);
+        let last_row = f64::from((u32::from(graph_area.height) * u32::from(rows)).max(1) - 1);
+        let dot_x = ((x - left) * last_column / width).round() as u32;
+        let dot_y = ((top - y) * last_row / height).round() as u32;
+        Some(Position::new(
+            graph_area.x + (dot_x / u32::from(columns)) as u16,
+            graph_area.y + (dot_y / u32::from(rows)) as u16,
+        ))
+    }
 }
 
 impl Widget for Chart<'_> {
@@ -982,7 +1334,9 @@ impl Widget for &Chart<'_> {
 
         self.block.as_ref().render(area, buf);
         let chart_area = self.block.inner_if_some(area);
-        let Some(layout) = self.layout(chart_area) else {
+        let bounds = self.bounds();
+        let labels = self.labels(bounds);
+        let Some(layout) = self.layout(chart_area, &labels) else {
             return;
         };
         let graph_area = layout.graph_area;
@@ -992,8 +1346,8 @@ impl Widget for &Chart<'_> {
         // axis names).
         let original_style = buf[(area.left(), area.top())].style();
 
-        self.render_x_labels(buf, &layout, chart_area, graph_area);
-        self.render_y_labels(buf, &layout, chart_area, graph_area);
+        self.render_x_labels(buf, &layout, &labels.x, chart_area, graph_area);
+        self.render_y_labels(buf, &layout, &labels.y, chart_area, graph_area);
 
         if let Some(y) = layout.axis_x {
             for x in graph_area.left()..graph_area.right() {
@@ -1020,10 +1374,10 @@ impl Widget for &Chart<'_> {
         }
 
         for dataset in &self.datasets {
-            Canvas::default()
+            let canvas = Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
-                .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
+                .x_bounds(bounds[0])
+                .y_bounds(bounds[1])
                 .marker(dataset.marker)
                 .paint(|ctx| {
                     ctx.draw(&Points {
@@ -1055,8 +1409,10 @@ impl Widget for &Chart<'_> {
                         }
                         GraphType::Scatter => {}
                     }
-                })
-                .render(graph_area, buf);
+                });
+            let resolution = canvas.resolution();
+            canvas.render(graph_area, buf);
+            Chart::render_point_markers(dataset, resolution, bounds, graph_area, buf);
         }
 
         if let Some(Position { x, y }) = layout.title_x {
@@ -1196,11 +1552,146 @@ mod tests {
                 .x_axis(Axis::default().title("X axis"))
                 .y_axis(Axis::default().title("Y axis"))
                 .hidden_legend_constraints(case.hidden_legend_constraints);
-            let layout = chart.layout(case.chart_area).unwrap();
+            let labels = chart.labels(chart.bounds());
+            let layout = chart.layout(case.chart_area, &labels).unwrap();
             assert_eq!(layout.legend_area, case.legend_area);
         }
     }
 
+    #[rstest]
+    #[case::explicit(None, [[0.0, 1.0], [0.0, 1.0]])]
+    #[case::no_padding(Some(0.0), [[-2.0, 4.0], [10.0, 20.0]])]
+    #[case::padded(Some(0.5), [[-5.0, 7.0], [5.0, 25.0]])]
+    #[case::negative_padding(Some(-1.0), [[-2.0, 4.0], [10.0, 20.0]])]
+    fn auto_bounds(#[case] padding: Option<f64>, #[case] expected: [[f64; 2]; 2]) {
+        let first = [(-2.0, 10.0), (1.0, f64::NAN)];
+        let second = [(4.0, 20.0), (f64::INFINITY, 15.0)];
+        let mut x_axis = Axis::default().bounds([0.0, 1.0]);
+        let mut y_axis = Axis::default().bounds([0.0, 1.0]);
+        if let Some(padding) = padding {
+            x_axis = x_axis.auto_bounds(padding);
+            y_axis = y_axis.auto_bounds(padding);
+        }
+        let chart = Chart::new(vec![
+            Dataset::default().data(&first),
+            Dataset::default().data(&second),
+        ])
+        .x_axis(x_axis)
+        .y_axis(y_axis);
+        assert_eq!(chart.bounds(), expected);
+    }
+
+    #[rstest]
+    #[case::no_data(&[], GraphType::Scatter, [[0.0, 1.0], [0.0, 1.0]])]
+    #[case::single_point(&[(3.0, -2.0)], GraphType::Scatter, [[2.5, 3.5], [-2.5, -1.5]])]
+    #[case::bars_include_zero(&[(1.0, 5.0), (2.0, 10.0)], GraphType::Bar, [[1.0, 2.0], [0.0, 10.0]])]
+    fn auto_bounds_edge_cases(
+        #[case] data: &[(f64, f64)],
+        #[case] graph_type: GraphType,
+        #[case] expected: [[f64; 2]; 2],
+    ) {
+        let chart = Chart::new(vec![Dataset::default().data(data).graph_type(graph_type)])
+            .x_axis(Axis::default().bounds([0.0, 1.0]).auto_bounds(0.0))
+            .y_axis(Axis::default().bounds([0.0, 1.0]).auto_bounds(0.0));
+        assert_eq!(chart.bounds(), expected);
+    }
+
+    #[test]
+    fn render_with_auto_bounds() {
+        let data = [(10.0, 100.0), (12.0, 102.0), (14.0, 104.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)])
+        .x_axis(Axis::default().auto_bounds(0.0))
+        .y_axis(Axis::default().auto_bounds(0.0));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    •", "  •  ", "•    "]));
+    }
+
+    #[test]
+    fn axis_labels_fn() {
+        let axis = Axis::default().labels_fn(3, |value| format!("{value:.1}"));
+        assert_eq!(
+            axis.resolve_labels([10.0, 20.0]).as_ref(),
+            [Line::from("10.0"), Line::from("15.0"), Line::from("20.0")]
+        );
+        assert_eq!(
+            Axis::default()
+                .labels_fn(0, |_| "")
+                .resolve_labels([0.0, 1.0])
+                .as_ref(),
+            []
+        );
+        assert_eq!(
+            Axis::default()
+                .labels_fn(1, |v| v.to_string())
+                .resolve_labels([1.0, 2.0])
+                .as_ref(),
+            [Line::from("1")]
+        );
+    }
+
+    #[test]
+    fn axis_labels_replace_labels_fn() {
+        let axis = Axis::default()
+            .labels(["a", "b"])
+            .labels_fn(2, |v| v.to_string());
+        assert_eq!(
+            axis.resolve_labels([0.0, 1.0]).as_ref(),
+            [Line::from("0"), Line::from("1")]
+        );
+        let axis = axis.labels(["a", "b"]);
+        assert_eq!(
+            axis.resolve_labels([0.0, 1.0]).as_ref(),
+            [Line::from("a"), Line::from("b")]
+        );
+    }
+
+    #[test]
+    fn axis_labels_fn_follow_auto_bounds() {
+        let data = [(0.0, 10.0), (1.0, 30.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 1.0]))
+            .y_axis(
+                Axis::default()
+                    .auto_bounds(0.0)
+                    .labels_fn(2, |value| format!("{value}")),
+            );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["30│      •", "  │       ", "10│•      "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn axis_labels_fn_reserves_label_width() {
+        let chart = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 3600.0])
+                    .labels_fn(2, |seconds| {
+                        let seconds = seconds as u64;
+                        format!("{:02}:{:02}", seconds / 3600, seconds % 3600 / 60)
+                    }),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 100.0])
+                    .labels_fn(2, |value| format!("{value}%")),
+            );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 4));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "100%│               ",
+            "0%  │               ",
+            "    └───────────────",
+            "00:00          01:00",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn axis_can_be_stylized() {
         assert_eq!(
@@ -1269,7 +1760,8 @@ mod tests {
         let data_unnamed = Dataset::default(); // must not occupy a row in legend
         let widget = Chart::new(vec![data_named_1, data_unnamed, data_named_2]);
         let buffer = Buffer::empty(Rect::new(0, 0, 50, 25));
-        let layout = widget.layout(buffer.area).unwrap();
+        let labels = widget.labels(widget.bounds());
+        let layout = widget.layout(buffer.area, &labels).unwrap();
 
         assert!(layout.legend_area.is_some());
         assert_eq!(layout.legend_area.unwrap().height, 4); // 2 for borders, 2 for rows
@@ -1280,7 +1772,8 @@ mod tests {
         let dataset = Dataset::default();
         let widget = Chart::new(vec![dataset; 3]);
         let buffer = Buffer::empty(Rect::new(0, 0, 50, 25));
-        let layout = widget.layout(buffer.area).unwrap();
+        let labels = widget.labels(widget.bounds());
+        let layout = widget.layout(buffer.area, &labels).unwrap();
 
         assert!(layout.legend_area.is_none());
     }
@@ -1544,4 +2037,78 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[rstest]
+    #[case::scatter(GraphType::Scatter, symbols::Marker::Dot, ["  x  ", "     ", "x   x"])]
+    #[case::line(GraphType::Line, symbols::Marker::Dot, ["  x  ", " • • ", "x   x"])]
+    #[case::braille_line(GraphType::Line, symbols::Marker::Braille, ["  x⡄ ", " ⡜ ⠱⡀", "x   x"])]
+    fn markers_are_drawn_on_top<'line, Lines>(
+        #[case] graph_type: GraphType,
+        #[case] marker: symbols::Marker,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let data = [(0.0, 0.0), (2.0, 2.0), (4.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(marker)
+            .graph_type(graph_type)
+            .show_markers(true)
+            .marker_symbol("x")])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn dataset_with_point_labels_is_covariant() {
+        fn shorten<'short>(dataset: Dataset<'static>) -> Dataset<'short> {
+            dataset
+        }
+        let dataset = shorten(Dataset::default().point_labels(|_, _| Some(Span::raw("a"))));
+        assert!(dataset.point_labels.is_some());
+    }
+
+    #[test]
+    fn point_labels() {
+        let data = [(0.0, 0.0), (2.0, 2.0), (4.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .show_markers(true)
+            .marker_symbol("x")
+            .point_labels(|index, (_, y)| (index > 0).then(|| Span::from(format!("{y}"))))])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  x2 ", "     ", "x   x"]));
+    }
+
+    #[test]
+    fn points_in_the_same_cell_share_a_marker() {
+        let data = [(0.0, 0.0), (0.1, 0.0), (4.0, 0.0)];
+        let labels = ["aaa", "b", "c"];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .show_markers(true)
+            .point_labels(|index, _| Some(Span::from(labels[index])))])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["•baa•"]));
+    }
+
+    #[test]
+    fn point_position_in_a_wide_area() {
+        let area = Rect::new(0, 0, 40000, 20000);
+        let bounds = [[0.0, 10.0], [0.0, 10.0]];
+        let position = Chart::point_position((2, 4), bounds, area, (10.0, 0.0));
+        assert_eq!(position, Some(Position::new(39999, 19999)));
+    }
 }
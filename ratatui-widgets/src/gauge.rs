@@ -1,7 +1,7 @@
-//! The [`Gauge`] widget is used to display a horizontal progress bar.
+//! The [`Gauge`] widget is used to display a horizontal or vertical progress bar.
 use ratatui_core::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style, Styled},
     symbols::{self},
     text::{Line, Span},
@@ -19,7 +19,9 @@ use crate::block::{Block, BlockExt};
 /// The associated label is always centered horizontally and vertically. If not set with
 /// [`Gauge::label`], the label is the percentage of the bar filled.
 ///
-/// You might want to have a higher precision bar using [`Gauge::use_unicode`].
+/// You might want to have a higher precision bar using [`Gauge::use_unicode`], or split the bar
+/// into discrete blocks using [`Gauge::segments`]. The bar fills from left to right, or from bottom
+/// to top with [`Gauge::direction`].
 ///
 /// This can be useful to indicate the progression of a task, like a download.
 ///
@@ -41,7 +43,7 @@ use crate::block::{Block, BlockExt};
 ///
 /// - [`LineGauge`] for a thin progress bar
 #[allow(clippy::struct_field_names)] // gauge_style needs to be differentiated to style
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
@@ -49,6 +51,27 @@ pub struct Gauge<'a> {
     use_unicode: bool,
     style: Style,
     gauge_style: Style,
+    segments: u16,
+    unfilled_segment_style: Style,
+    segment_gap_style: Style,
+    direction: Direction,
+}
+
+impl Default for Gauge<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            ratio: 0.0,
+            label: None,
+            use_unicode: false,
+            style: Style::default(),
+            gauge_style: Style::default(),
+            segments: 0,
+            unfilled_segment_style: Style::default(),
+            segment_gap_style: Style::default(),
+            direction: Direction::Horizontal,
+        }
+    }
 }
 
 impl<'a> Gauge<'a> {
@@ -64,20 +87,23 @@ impl<'a> Gauge<'a> {
 
     /// Sets the bar progression from a percentage.
     ///
+    /// Values above 100 are clamped to 100.
+    ///
     /// # Panics
     ///
-    /// This method panics if `percent` is **not** between 0 and 100 inclusively.
+    /// In debug builds, this method panics if `percent` is **not** between 0 and 100 inclusively,
+    /// to help catching the mistake early.
     ///
     /// # See also
     ///
     /// See [`Gauge::ratio`] to set from a float.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn percent(mut self, percent: u16) -> Self {
-        assert!(
+        debug_assert!(
             percent <= 100,
             "Percentage should be between 0 and 100 inclusively."
         );
-        self.ratio = f64::from(percent) / 100.0;
+        self.ratio = f64::from(percent.min(100)) / 100.0;
         self
     }
 
@@ -86,20 +112,25 @@ impl<'a> Gauge<'a> {
     /// `ratio` is the ratio between filled bar over empty bar (i.e. `3/4` completion is `0.75`).
     /// This is more easily seen as a floating point percentage (e.g. 42% = `0.42`).
     ///
+    /// Values outside of `0.0..=1.0` (e.g. when computing `done / total` with a stale total) are
+    /// clamped, so that the gauge is rendered full above `1.0` and empty below `0.0` (or for
+    /// `NaN`).
+    ///
     /// # Panics
     ///
-    /// This method panics if `ratio` is **not** between 0 and 1 inclusively.
+    /// In debug builds, this method panics if `ratio` is **not** between 0 and 1 inclusively, to
+    /// help catching the mistake early.
     ///
     /// # See also
     ///
     /// See [`Gauge::percent`] to set from a percentage.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn ratio(mut self, ratio: f64) -> Self {
-        assert!(
+        debug_assert!(
             (0.0..=1.0).contains(&ratio),
             "Ratio should be between 0 and 1 inclusively."
         );
-        self.ratio = ratio;
+        self.ratio = clamp_ratio(ratio);
         self
     }
 
@@ -149,6 +180,89 @@ impl<'a> Gauge<'a> {
         self.use_unicode = unicode;
         self
     }
+
+    /// Splits the bar into a number of discrete segments.
+    ///
+    /// The gauge area is divided into `count` segments of (nearly) equal width separated by 1 cell
+    /// gaps. The number of filled segments is proportional to the ratio, rounded to the nearest
+    /// segment (e.g. 50% of 4 segments fills 2). This is useful for battery or signal strength
+    /// style indicators.
+    ///
+    /// Filled segments are drawn with the [`Gauge::gauge_style`], unfilled segments with the
+    /// [`Gauge::unfilled_segment_style`] and the gaps with the [`Gauge::segment_gap_style`]. The
+    /// label is drawn over the segments. [`Gauge::use_unicode`] has no effect on a segmented
+    /// gauge.
+    ///
+    /// A count of 0 (the default) renders a continuous bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Gauge,
+    /// };
+    ///
+    /// let battery = Gauge::default()
+    ///     .segments(10)
+    ///     .gauge_style(Style::new().green())
+    ///     .unfilled_segment_style(Style::new().on_dark_gray())
+    ///     .label("")
+    ///     .percent(70);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn segments(mut self, count: u16) -> Self {
+        self.segments = count;
+        self
+    }
+
+    /// Sets the style of the unfilled segments of a segmented gauge.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// Unfilled segments are drawn with spaces, so set a background color to make them visible.
+    /// This has no effect unless [`Gauge::segments`] is set.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unfilled_segment_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.unfilled_segment_style = style.into();
+        self
+    }
+
+    /// Sets the style of the gaps between the segments of a segmented gauge.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This has no effect unless [`Gauge::segments`] is set.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn segment_gap_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.segment_gap_style = style.into();
+        self
+    }
+
+    /// Sets the direction in which the bar is filled.
+    ///
+    /// A [`Horizontal`](Direction::Horizontal) gauge (the default) fills from left to right, while
+    /// a [`Vertical`](Direction::Vertical) gauge fills from bottom to top, which suits level
+    /// meters. [`Gauge::use_unicode`] uses the vertical eighth blocks for a vertical gauge, and
+    /// [`Gauge::segments`] are stacked from bottom to top. The label stays centered in the area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{layout::Direction, widgets::Gauge};
+    ///
+    /// let meter = Gauge::default()
+    ///     .direction(Direction::Vertical)
+    ///     .use_unicode(true)
+    ///     .ratio(0.3);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
 }
 
 impl Widget for Gauge<'_> {
@@ -172,64 +286,135 @@ impl Gauge<'_> {
             return;
         }
 
+        if self.segments > 0 {
+            self.render_segments(gauge_area, buf);
+            return;
+        }
+
         buf.set_style(gauge_area, self.gauge_style);
 
         // compute label value and its position
         // label is put at the center of the gauge_area
         let default_label = Span::raw(format!("{}%", f64::round(self.ratio * 100.0)));
         let label = self.label.as_ref().unwrap_or(&default_label);
-        let clamped_label_width = gauge_area.width.min(label.width() as u16);
-        let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
-        let label_row = gauge_area.top() + gauge_area.height / 2;
-
-        // the gauge will be filled proportionally to the ratio
-        let filled_width = f64::from(gauge_area.width) * self.ratio;
-        let end = if self.use_unicode {
-            gauge_area.left() + filled_width.floor() as u16
+        let (label_col, label_row, clamped_label_width) = label_position(label, gauge_area);
+
+        // the gauge will be filled proportionally to the ratio, from the left or from the bottom
+        let (length, ramp) = match self.direction {
+            Direction::Horizontal => (gauge_area.width, &symbols::block::ramp::HORIZONTAL),
+            Direction::Vertical => (gauge_area.height, &symbols::block::ramp::VERTICAL),
+        };
+        let filled_length = f64::from(length) * self.ratio;
+        let filled = if self.use_unicode {
+            filled_length.floor() as u16
         } else {
-            gauge_area.left() + filled_width.round() as u16
+            filled_length.round() as u16
         };
-        for y in gauge_area.top()..gauge_area.bottom() {
-            // render the filled area (left to end)
-            for x in gauge_area.left()..end {
-                // Use full block for the filled part of the gauge and spaces for the part that is
-                // covered by the label. Note that the background and foreground colors are swapped
-                // for the label part, otherwise the gauge will be inverted
-                if x < label_col || x > label_col + clamped_label_width || y != label_row {
-                    buf[(x, y)]
-                        .set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
-                } else {
-                    buf[(x, y)]
-                        .set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
-                }
+        // the partial cells are only rendered when the ratio is below 1, so they are in the area
+        let (filled_area, partial_area) = match self.direction {
+            Direction::Horizontal => (
+                Rect {
+                    width: filled,
+                    ..gauge_area
+                },
+                Rect {
+                    x: gauge_area.left() + filled,
+                    width: 1,
+                    ..gauge_area
+                },
+            ),
+            Direction::Vertical => (
+                Rect {
+                    y: gauge_area.bottom() - filled,
+                    height: filled,
+                    ..gauge_area
+                },
+                Rect {
+                    y: (gauge_area.bottom() - filled).saturating_sub(1),
+                    height: 1,
+                    ..gauge_area
+                },
+            ),
+        };
+        for position in filled_area.positions() {
+            let (x, y) = (position.x, position.y);
+            // Use full block for the filled part of the gauge and spaces for the part that is
+            // covered by the label. Note that the background and foreground colors are swapped
+            // for the label part, otherwise the gauge will be inverted
+            if x < label_col || x > label_col + clamped_label_width || y != label_row {
+                buf[position]
+                    .set_symbol(symbols::block::FULL)
+                    .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
+                    .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+            } else {
+                buf[position]
+                    .set_symbol(" ")
+                    .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
+                    .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
             }
-            if self.use_unicode && self.ratio < 1.0 {
-                buf[(end, y)].set_symbol(get_unicode_block(filled_width % 1.0));
+        }
+        if self.use_unicode && self.ratio < 1.0 {
+            let symbol = symbols::block::ramp::glyph(ramp, filled_length % 1.0);
+            for position in partial_area.positions() {
+                buf[position].set_symbol(symbol);
             }
         }
         // render the label
         buf.set_span(label_col, label_row, label, clamped_label_width);
     }
+
+    fn render_segments(&self, gauge_area: Rect, buf: &mut Buffer) {
+        buf.set_style(gauge_area, self.segment_gap_style);
+
+        let filled = (f64::from(self.segments) * self.ratio).round() as usize;
+        let mut segments = Layout::new(
+            self.direction,
+            vec![Constraint::Fill(1); self.segments as usize],
+        )
+        .spacing(1)
+        .split(gauge_area)
+        .to_vec();
+        if self.direction == Direction::Vertical {
+            // vertical gauges are filled from the bottom
+            segments.reverse();
+        }
+        for (i, segment) in segments.iter().enumerate() {
+            if i < filled {
+                for position in segment.positions() {
+                    buf[position]
+                        .set_symbol(symbols::block::FULL)
+                        .set_style(self.gauge_style);
+                }
+            } else {
+                buf.set_style(*segment, self.unfilled_segment_style);
+            }
+        }
+
+        // render the label at the center of the gauge area
+        let default_label = Span::raw(format!("{}%", f64::round(self.ratio * 100.0)));
+        let label = self.label.as_ref().unwrap_or(&default_label);
+        let (label_col, label_row, clamped_label_width) = label_position(label, gauge_area);
+        buf.set_span(label_col, label_row, label, clamped_label_width);
+    }
 }
 
-fn get_unicode_block<'a>(frac: f64) -> &'a str {
-    match (frac * 8.0).round() as u16 {
-        1 => symbols::block::ONE_EIGHTH,
-        2 => symbols::block::ONE_QUARTER,
-        3 => symbols::block::THREE_EIGHTHS,
-        4 => symbols::block::HALF,
-        5 => symbols::block::FIVE_EIGHTHS,
-        6 => symbols::block::THREE_QUARTERS,
-        7 => symbols::block::SEVEN_EIGHTHS,
-        8 => symbols::block::FULL,
-        _ => " ",
+/// Clamps `ratio` to `0.0..=1.0`, mapping `NaN` to `0.0`.
+fn clamp_ratio(ratio: f64) -> f64 {
+    if ratio.is_nan() {
+        0.0
+    } else {
+        ratio.clamp(0.0, 1.0)
     }
 }
 
+/// Returns the column, row and clamped width of a label centered in the gauge area.
+fn label_position(label: &Span, gauge_area: Rect) -> (u16, u16, u16) {
+    let clamped_label_width = gauge_area.width.min(label.width() as u16);
+    let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
+    let label_row = gauge_area.top() + gauge_area.height / 2;
+    (label_col, label_row, clamped_label_width)
+}
+
 /// A compact widget to display a progress bar over a single thin line.
 ///
 /// This can be useful to indicate the progression of a task, like a download.
@@ -307,16 +492,21 @@ impl<'a> LineGauge<'a> {
     /// `ratio` is the ratio between filled bar over empty bar (i.e. `3/4` completion is `0.75`).
     /// This is more easily seen as a floating point percentage (e.g. 42% = `0.42`).
     ///
+    /// Values outside of `0.0..=1.0` (e.g. when computing `done / total` with a stale total) are
+    /// clamped, so that the gauge is rendered full above `1.0` and empty below `0.0` (or for
+    /// `NaN`).
+    ///
     /// # Panics
     ///
-    /// This method panics if `ratio` is **not** between 0 and 1 inclusively.
+    /// In debug builds, this method panics if `ratio` is **not** between 0 and 1 inclusively, to
+    /// help catching the mistake early.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn ratio(mut self, ratio: f64) -> Self {
-        assert!(
+        debug_assert!(
             (0.0..=1.0).contains(&ratio),
             "Ratio should be between 0 and 1 inclusively."
         );
-        self.ratio = ratio;
+        self.ratio = clamp_ratio(ratio);
         self
     }
 
@@ -489,27 +679,69 @@ mod tests {
         style::{Color, Modifier, Style, Stylize},
         symbols,
     };
+    use rstest::rstest;
 
     use super::*;
 
+    #[cfg(debug_assertions)]
     #[test]
     #[should_panic = "Percentage should be between 0 and 100 inclusively"]
     fn gauge_invalid_percentage() {
         let _ = Gauge::default().percent(110);
     }
 
+    #[cfg(debug_assertions)]
     #[test]
     #[should_panic = "Ratio should be between 0 and 1 inclusively"]
     fn gauge_invalid_ratio_upper_bound() {
         let _ = Gauge::default().ratio(1.1);
     }
 
+    #[cfg(debug_assertions)]
     #[test]
     #[should_panic = "Ratio should be between 0 and 1 inclusively"]
     fn gauge_invalid_ratio_lower_bound() {
         let _ = Gauge::default().ratio(-0.5);
     }
 
+    // the setters only clamp out of range values in release builds, they panic in debug builds
+    #[cfg(not(debug_assertions))]
+    #[rstest]
+    #[case::above(1.5, ["██████", "█100% ", "██████"])]
+    #[case::below(-0.2, ["      ", "  0%  ", "      "])]
+    fn gauge_clamps_out_of_range_ratio(#[case] ratio: f64, #[case] expected: [&str; 3]) {
+        let gauge = Gauge::default().ratio(ratio);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[rstest]
+    #[case::above(1.5, "100% ━━━━━")]
+    #[case::below(-0.2, "0% ───────")]
+    fn line_gauge_clamps_out_of_range_ratio(#[case] ratio: f64, #[case] expected: &str) {
+        let gauge = LineGauge::default()
+            .filled_symbol(symbols::line::THICK_HORIZONTAL)
+            .ratio(ratio);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::inside(0.42, 0.42)]
+    #[case::lower_bound(0.0, 0.0)]
+    #[case::upper_bound(1.0, 1.0)]
+    #[case::below(-0.2, 0.0)]
+    #[case::above(1.5, 1.0)]
+    #[case::infinity(f64::INFINITY, 1.0)]
+    #[case::nan(f64::NAN, 0.0)]
+    fn clamp_ratio_to_unit_range(#[case] ratio: f64, #[case] expected: f64) {
+        // the ratio is either kept or replaced by an exact bound
+        assert_eq!(clamp_ratio(ratio).to_bits(), expected.to_bits());
+    }
+
     #[test]
     fn gauge_can_be_stylized() {
         assert_eq!(
@@ -522,6 +754,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn gauge_segments_half_filled() {
+        let gauge = Gauge::default()
+            .segments(4)
+            .gauge_style(Style::new().red())
+            .unfilled_segment_style(Style::new().on_blue())
+            .segment_gap_style(Style::new().on_black())
+            .label("")
+            .percent(50);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        gauge.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["██ ██      "]);
+        expected.set_style(Rect::new(0, 0, 11, 1), Style::new().on_black());
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(6, 0, 2, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(9, 0, 2, 1), Style::new().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn gauge_segments_rounding_and_label() {
+        let gauge = Gauge::default().segments(3).percent(90);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+        gauge.render(buf.area, &mut buf);
+        // 90% of 3 segments rounds to all 3 segments filled, with the label on the middle row
+        let expected = Buffer::with_lines(["███ ███ ███", "███ 90% ███"]);
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::half(false, 0.5, ["   ", "   ", "50%", "███"])]
+    #[case::unicode(true, 0.55, ["   ", "▂▂▂", "55%", "███"])]
+    #[case::full_unicode(true, 1.0, ["███", "███", "100", "███"])]
+    fn gauge_vertical(#[case] use_unicode: bool, #[case] ratio: f64, #[case] expected: [&str; 4]) {
+        let gauge = Gauge::default()
+            .direction(Direction::Vertical)
+            .use_unicode(use_unicode)
+            .ratio(ratio);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn gauge_vertical_one_column() {
+        let gauge = Gauge::default()
+            .direction(Direction::Vertical)
+            .use_unicode(true)
+            .ratio(0.6);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 5));
+        gauge.render(buf.area, &mut buf);
+        // the label is clamped to the width of the gauge
+        assert_eq!(buf, Buffer::with_lines([" ", " ", "6", "█", "█"]));
+    }
+
+    #[test]
+    fn gauge_vertical_segments() {
+        let gauge = Gauge::default()
+            .direction(Direction::Vertical)
+            .segments(2)
+            .label("")
+            .percent(50);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 5));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["  ", "  ", "  ", "██", "██"]));
+    }
+
     #[test]
     fn line_gauge_can_be_stylized() {
         assert_eq!(
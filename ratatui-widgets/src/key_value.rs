@@ -0,0 +1,527 @@
+//! The [`KeyValueList`] widget displays aligned `key: value` pairs.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Style, Styled},
+    text::{Line, Span, WidthMode},
+    widgets::Widget,
+};
+
+use crate::{
+    block::{Block, BlockExt},
+    reflow::{LineComposer, WordWrapper},
+};
+
+/// A single `key: value` pair of a [`KeyValueList`].
+///
+/// The style of a pair is applied to its whole row (or rows, when the value is wrapped), before
+/// the styles of the key and value lines.
+///
+/// A pair can be created from a tuple of anything that converts into a [`Line`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::KeyValue,
+/// };
+///
+/// let pair = KeyValue::new("Status", "failing".red()).style(Style::new().bold());
+/// let pair: KeyValue = ("Branch", "main").into();
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct KeyValue<'a> {
+    key: Line<'a>,
+    value: Line<'a>,
+    style: Style,
+}
+
+impl<'a> KeyValue<'a> {
+    /// Creates a new pair with the given key and value.
+    pub fn new<K, V>(key: K, value: V) -> Self
+    where
+        K: Into<Line<'a>>,
+        V: Into<Line<'a>>,
+    {
+        Self {
+            key: key.into(),
+            value: value.into(),
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the style of the pair.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, K, V> From<(K, V)> for KeyValue<'a>
+where
+    K: Into<Line<'a>>,
+    V: Into<Line<'a>>,
+{
+    fn from((key, value): (K, V)) -> Self {
+        Self::new(key, value)
+    }
+}
+
+impl Styled for KeyValue<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// A widget that displays a list of aligned `key: value` pairs.
+///
+/// The keys are drawn in a column that is as wide as the widest key, or as wide as the
+/// [`key_width`](Self::key_width) if one is set, in which case longer keys are truncated. Keys are
+/// right-aligned in their column by default, which can be changed with
+/// [`key_alignment`](Self::key_alignment). Each key is followed by the
+/// [`separator`](Self::separator) (`": "` by default) and its value, which takes the rest of the
+/// row. Values that don't fit are truncated, or wrapped over multiple rows with
+/// [`wrap`](Self::wrap).
+///
+/// Each pair is a [`KeyValue`], which can be styled individually.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::Alignment,
+///     style::{Style, Stylize},
+///     widgets::{Block, KeyValue, KeyValueList},
+/// };
+///
+/// // renders:
+/// //   Branch: main
+/// //   Status: failing
+/// // Upstream: origin/main
+/// let list = KeyValueList::new([
+///     KeyValue::new("Branch", "main"),
+///     KeyValue::new("Status", "failing").red(),
+///     KeyValue::new("Upstream", "origin/main"),
+/// ])
+/// .block(Block::bordered().title("Repository"))
+/// .key_style(Style::new().bold());
+///
+/// // keys left-aligned in a 10 columns wide column, separated by " = "
+/// let list = KeyValueList::new([("name", "ratatui"), ("version", "0.30")])
+///     .key_alignment(Alignment::Left)
+///     .key_width(10)
+///     .separator(" = ");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KeyValueList<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The pairs to display
+    items: Vec<KeyValue<'a>>,
+    /// Style of the whole widget
+    style: Style,
+    /// Style of the keys
+    key_style: Style,
+    /// Alignment of the keys in their column
+    key_alignment: Alignment,
+    /// Width of the key column, or `None` to fit the widest key
+    key_width: Option<u16>,
+    /// Drawn between each key and its value
+    separator: Span<'a>,
+    /// Whether values that don't fit are wrapped instead of truncated
+    wrap: bool,
+}
+
+impl Default for KeyValueList<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            items: Vec::new(),
+            style: Style::new(),
+            key_style: Style::new(),
+            key_alignment: Alignment::Right,
+            key_width: None,
+            separator: Span::raw(": "),
+            wrap: false,
+        }
+    }
+}
+
+impl<'a> KeyValueList<'a> {
+    /// Creates a new list with the given pairs.
+    ///
+    /// The pairs can be anything that converts into a [`KeyValue`], such as `(key, value)`
+    /// tuples.
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<KeyValue<'a>>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the pairs of the list.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<T>(mut self, items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<KeyValue<'a>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Wraps the list with a custom [`Block`] widget.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the keys.
+    ///
+    /// The key style is applied on top of the style of each pair.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    /// Sets the alignment of the keys in their column.
+    ///
+    /// Defaults to [`Alignment::Right`], which puts the separators of all pairs in the same column.
+    /// The alignment of a key [`Line`] takes precedence over this alignment.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn key_alignment(mut self, alignment: Alignment) -> Self {
+        self.key_alignment = alignment;
+        self
+    }
+
+    /// Sets a fixed width for the key column.
+    ///
+    /// By default the key column is as wide as the widest key. With a fixed width, keys that are
+    /// wider are truncated at their end, whatever their alignment.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn key_width(mut self, width: u16) -> Self {
+        self.key_width = Some(width);
+        self
+    }
+
+    /// Sets the separator drawn between each key and its value.
+    ///
+    /// Defaults to `": "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator<T: Into<Span<'a>>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets whether values that don't fit in their row are wrapped over multiple rows.
+    ///
+    /// Values are wrapped on word boundaries, and the following rows start in the value column.
+    /// By default, values are truncated instead.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Returns the width of the key column.
+    ///
+    /// This is the [`key_width`](Self::key_width) if one is set, or the width of the widest key.
+    pub fn key_column_width(&self) -> u16 {
+        self.key_width.unwrap_or_else(|| {
+            let width = self.items.iter().map(|item| item.key.width()).max();
+            u16::try_from(width.unwrap_or_default()).unwrap_or(u16::MAX)
+        })
+    }
+}
+
+impl Widget for KeyValueList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &KeyValueList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        let key_width = self.key_column_width().min(inner.width);
+        let separator_width = u16::try_from(self.separator.width()).unwrap_or(u16::MAX);
+        let separator_width = separator_width.min(inner.width - key_width);
+        let value_x = inner.x + key_width + separator_width;
+        let value_width = inner.right() - value_x;
+        let width_mode = WidthMode::current();
+
+        let mut y = inner.y;
+        for item in &self.items {
+            if y >= inner.bottom() {
+                break;
+            }
+            let max_rows = inner.bottom() - y;
+            let wrapped = self
+                .wrap
+                .then(|| wrap_value(&item.value, value_width, max_rows, width_mode));
+            let height = wrapped
+                .as_ref()
+                .map_or(1, |rows| (rows.len() as u16).max(1));
+            let row = Rect::new(inner.x, y, inner.width, height);
+            buf.set_style(row, item.style);
+
+            let key_area = Rect::new(inner.x, y, key_width, 1);
+            buf.set_style(key_area, self.key_style);
+            self.render_key(&item.key, key_area, buf);
+
+            let separator_area = Rect::new(inner.x + key_width, y, separator_width, 1);
+            (&self.separator).render(separator_area, buf);
+
+            let value_area = Rect::new(value_x, y, value_width, height);
+            match wrapped {
+                Some(rows) => {
+                    for (line, row) in rows.iter().zip(value_area.rows()) {
+                        line.render(row, buf);
+                    }
+                }
+                None => (&item.value).render(value_area, buf),
+            }
+            y += height;
+        }
+    }
+}
+
+impl KeyValueList<'_> {
+    /// Renders `key` aligned in `area`, truncating its end when it doesn't fit.
+    fn render_key(&self, key: &Line, area: Rect, buf: &mut Buffer) {
+        let width = u16::try_from(key.width())
+            .unwrap_or(u16::MAX)
+            .min(area.width);
+        let offset = match key.alignment.unwrap_or(self.key_alignment) {
+            Alignment::Left => 0,
+            Alignment::Center => (area.width - width) / 2,
+            Alignment::Right => area.width - width,
+        };
+        buf.set_line(area.x + offset, area.y, key, width);
+    }
+}
+
+/// Wraps `value` at word boundaries so that it fits in `width` columns, keeping at most
+/// `max_rows` rows.
+fn wrap_value<'a>(
+    value: &'a Line<'_>,
+    width: u16,
+    max_rows: u16,
+    width_mode: WidthMode,
+) -> Vec<Line<'a>> {
+    let alignment = value.alignment.unwrap_or_default();
+    let lines = [(value.styled_graphemes(Style::default()), alignment)].into_iter();
+    let mut line_composer = WordWrapper::new(lines, width, true);
+    line_composer.set_width_mode(width_mode);
+    let mut rows = Vec::new();
+    while rows.len() < usize::from(max_rows) {
+        let Some(wrapped) = line_composer.next_line() else {
+            break;
+        };
+        let mut spans: Vec<Span> = Vec::new();
+        for grapheme in wrapped.graphemes {
+            match spans.last_mut() {
+                Some(span) if span.style == grapheme.style => {
+                    span.content.to_mut().push_str(grapheme.symbol);
+                }
+                _ => spans.push(Span::styled(grapheme.symbol, grapheme.style)),
+            }
+        }
+        rows.push(Line::from(spans).alignment(wrapped.alignment));
+    }
+    rows
+}
+
+impl<'a, Item> FromIterator<Item> for KeyValueList<'a>
+where
+    Item: Into<KeyValue<'a>>,
+{
+    fn from_iter<Iter: IntoIterator<Item = Item>>(iter: Iter) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl Styled for KeyValueList<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let list = KeyValueList::new([("a", "1"), ("bc", "2")]);
+        assert_eq!(
+            list.items,
+            [KeyValue::new("a", "1"), KeyValue::new("bc", "2")]
+        );
+        assert_eq!(list.key_alignment, Alignment::Right);
+        assert_eq!(list.separator, Span::raw(": "));
+        assert_eq!(list.key_width, None);
+        assert!(!list.wrap);
+    }
+
+    #[rstest]
+    #[case::widest_key(None, 8)]
+    #[case::fixed(Some(4), 4)]
+    fn key_column_width(#[case] key_width: Option<u16>, #[case] expected: u16) {
+        let mut list = KeyValueList::new([("Branch", "main"), ("Upstream", "origin")]);
+        if let Some(width) = key_width {
+            list = list.key_width(width);
+        }
+        assert_eq!(list.key_column_width(), expected);
+    }
+
+    #[test]
+    fn render_sizes_keys_to_the_widest_key() {
+        let list = KeyValueList::new([("Branch", "main"), ("Upstream", "origin"), ("PR", "#1")]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 18, 3));
+        list.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "  Branch: main    ",
+            "Upstream: origin  ",
+            "      PR: #1      ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_fixed_key_width_left_aligned() {
+        let list = KeyValueList::new([("Branch", "main"), ("PR", "#1")])
+            .key_alignment(Alignment::Left)
+            .key_width(4)
+            .separator(" = ");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        list.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["Bran = main ", "PR   = #1   "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::truncated(false, ["a: one two ", "b: x       ", "           "])]
+    #[case::wrapped(true, ["a: one two ", "   three   ", "b: x       "])]
+    fn render_long_values(#[case] wrap: bool, #[case] expected: [&str; 3]) {
+        let list = KeyValueList::new([("a", "one two three"), ("b", "x")]).wrap(wrap);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 3));
+        list.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::right(Alignment::Right, ["Bran: main", "  PR: #1  "])]
+    #[case::center(Alignment::Center, ["Bran: main", " PR : #1  "])]
+    fn render_truncates_the_end_of_keys(#[case] alignment: Alignment, #[case] expected: [&str; 2]) {
+        let list = KeyValueList::new([("Branch", "main"), ("PR", "#1")])
+            .key_alignment(alignment)
+            .key_width(4);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        list.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_wrapped_value_keeps_styles_and_alignment() {
+        let value = Line::from(vec!["one ".green(), "two three".red()]).right_aligned();
+        let list = KeyValueList::new([("a", value)]).wrap(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 2));
+        list.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["a:  one two", "      three"]);
+        expected.set_style(Rect::new(4, 0, 4, 1), Style::new().green());
+        expected.set_style(Rect::new(8, 0, 3, 1), Style::new().red());
+        expected.set_style(Rect::new(6, 1, 5, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_styles() {
+        let list = KeyValueList::new([
+            KeyValue::new("a", "1"),
+            KeyValue::new("b", "2".green()).style(Color::Red),
+        ])
+        .key_style(Style::new().bold());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        list.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["a: 1", "b: 2"]);
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 0, 1, 2), Style::new().bold());
+        expected.set_style(Rect::new(3, 1, 1, 1), Style::new().green());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_with_block_in_small_area() {
+        let list =
+            KeyValueList::new([("key", "value"), ("other", "value")]).block(Block::bordered());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        list.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["┌────┐", "│ key│", "└────┘"]);
+        assert_eq!(buffer, expected);
+    }
+}
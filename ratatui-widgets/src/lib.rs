@@ -34,16 +34,20 @@
 //! # Available Widgets
 //!
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
+//! - [`Blink`]: alternates the style of another widget between two styles.
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles, and styles.
 //! - [`calendar::Monthly`]: displays a single month.
+//! - [`calendar::Yearly`]: displays the months of a year in a grid.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`KeyValueList`]: displays aligned `key: value` pairs.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
+//! - [`Padded`]: renders another widget with padding around it.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
@@ -51,16 +55,20 @@
 //! - [`Tabs`]: displays a tab bar and allows selection.
 //!
 //! [`BarChart`]: crate::barchart::BarChart
+//! [`Blink`]: crate::blink::Blink
 //! [`Block`]: crate::block::Block
 //! [`calendar::Monthly`]: crate::calendar::Monthly
+//! [`calendar::Yearly`]: crate::calendar::Yearly
 //! [`Canvas`]: crate::canvas::Canvas
 //! [`Chart`]: crate::chart::Chart
 //! [`Clear`]: crate::clear::Clear
 //! [`Gauge`]: crate::gauge::Gauge
+//! [`KeyValueList`]: crate::key_value::KeyValueList
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`RatatuiMascot`]: crate::mascot::RatatuiMascot
+//! [`Padded`]: crate::padded::Padded
 //! [`Paragraph`]: crate::paragraph::Paragraph
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
 //! [`Sparkline`]: crate::sparkline::Sparkline
@@ -80,15 +88,18 @@
 //!
 //! This project is licensed under the MIT License. See the [LICENSE](../LICENSE) file for details.
 pub mod barchart;
+pub mod blink;
 pub mod block;
 pub mod borders;
 pub mod canvas;
 pub mod chart;
 pub mod clear;
 pub mod gauge;
+pub mod key_value;
 pub mod list;
 pub mod logo;
 pub mod mascot;
+pub mod padded;
 pub mod paragraph;
 pub mod scrollbar;
 pub mod sparkline;
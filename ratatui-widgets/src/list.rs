@@ -1,6 +1,9 @@
 //! The [`List`] widget is used to display a list of items and allows selecting one or multiple
 //! items.
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::{
+    style::{Style, Styled},
+    text::Line,
+};
 use strum::{Display, EnumString};
 
 pub use self::{item::ListItem, state::ListState};
@@ -14,8 +17,8 @@ mod state;
 ///
 /// A list is a collection of [`ListItem`]s.
 ///
-/// This is different from a [`Table`] because it does not handle columns, headers or footers and
-/// the item's height is automatically determined. A `List` can also be put in reverse order (i.e.
+/// This is different from a [`Table`] because it does not handle columns and the item's height is
+/// automatically determined. A `List` can also be put in reverse order (i.e.
 /// *bottom to top*) whereas a [`Table`] cannot.
 ///
 /// [`Table`]: crate::table::Table
@@ -34,9 +37,14 @@ mod state;
 ///
 /// - [`List::highlight_style`] sets the style of the selected item.
 /// - [`List::highlight_symbol`] sets the symbol to be displayed in front of the selected item.
+/// - [`List::highlight_symbol_suffix`] sets the symbol to be displayed at the end of the selected
+///   item.
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 ///   multi-line items
 /// - [`List::direction`] sets the list direction
+/// - [`List::header`] and [`List::footer`] set rows that are pinned above and below the items
+/// - [`List::tree_lines`] draws tree connectors in the indentation set by [`ListItem::indent`]
+/// - [`List::wrap`] wraps the text of the items that are wider than the list
 ///
 /// # Examples
 ///
@@ -117,12 +125,36 @@ pub struct List<'a> {
     pub(crate) highlight_style: Style,
     /// Symbol in front of the selected item (Shift all items to the right)
     pub(crate) highlight_symbol: Option<&'a str>,
+    /// Symbol at the end of the selected item (Shrinks all items from the right)
+    pub(crate) highlight_symbol_suffix: Option<&'a str>,
     /// Whether to repeat the highlight symbol for each line of the selected item
     pub(crate) repeat_highlight_symbol: bool,
     /// Decides when to allocate spacing for the selection symbol
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// Optional row pinned above the items
+    pub(crate) header: Option<Line<'a>>,
+    /// Optional row pinned below the items
+    pub(crate) footer: Option<Line<'a>>,
+    /// Whether to draw tree connector lines in the indentation of the items
+    pub(crate) tree_lines: bool,
+    /// Whether to wrap the text of the items that don't fit the width of the list
+    pub(crate) wrap: bool,
+    /// Which items limit the horizontal offset
+    pub(crate) horizontal_limit: HorizontalLimit,
+}
+
+/// Defines which items limit how far the content of a [`List`] can be scrolled horizontally.
+///
+/// See [`List::follow_selection_horizontal`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) enum HorizontalLimit {
+    /// The end of the widest visible item can be scrolled to the right edge of the list
+    #[default]
+    VisibleItems,
+    /// The end of the selected item can be scrolled to the right edge of the list
+    SelectedItem,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -139,7 +171,24 @@ pub enum ListDirection {
     BottomToTop,
 }
 
+/// Defines what happens when navigating past the first or last item of a list.
+///
+/// See [`ListState::select_next_in`] and [`ListState::select_previous_in`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ListNavigation {
+    /// The selection stops at the first and last items
+    #[default]
+    Clamp,
+    /// The selection wraps around from the last item to the first one and vice versa
+    Wrap,
+}
+
 impl<'a> List<'a> {
+    /// The number of columns each indentation level of a [`ListItem`] takes up.
+    ///
+    /// See [`ListItem::indent`].
+    pub const INDENT_WIDTH: u16 = 4;
+
     /// Creates a new list from [`ListItem`]s
     ///
     /// The `items` parameter accepts any value that can be converted into an iterator of
@@ -303,6 +352,33 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the symbol to be displayed at the right edge of the selected item
+    ///
+    /// This can be used instead of or together with the [highlight symbol](List::highlight_symbol)
+    /// (e.g. for a cursor pointing inward from the right). The suffix follows the same rules as
+    /// the highlight symbol: the space for it is reserved according to the
+    /// [highlight spacing](List::highlight_spacing) on the right of all the items, so the content
+    /// of the items never overlaps it, and it is repeated over multi-line items if
+    /// [`List::repeat_highlight_symbol`] is set.
+    ///
+    /// By default there are no highlight symbol suffix.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).highlight_symbol_suffix(" <");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_symbol_suffix(mut self, highlight_symbol_suffix: &'a str) -> Self {
+        self.highlight_symbol_suffix = Some(highlight_symbol_suffix);
+        self
+    }
+
     /// Set the style of the selected item
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -310,7 +386,9 @@ impl<'a> List<'a> {
     ///
     /// This style will be applied to the entire item, including the
     /// [highlight symbol](List::highlight_symbol) if it is displayed, and will override any style
-    /// set on the item or on the individual cells.
+    /// set on the item or on the individual cells. It is also applied to the items of the
+    /// [multi-selection](ListState::selected_indices), which don't get the highlight
+    /// symbol.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -346,9 +424,9 @@ impl<'a> List<'a> {
 
     /// Set when to show the highlight spacing
     ///
-    /// The highlight spacing is the spacing that is allocated for the selection symbol (if enabled)
-    /// and is used to shift the list when an item is selected. This method allows you to configure
-    /// when this spacing is allocated.
+    /// The highlight spacing is the spacing that is allocated for the selection symbol and suffix
+    /// (if enabled) and is used to shift the list when an item is selected. This method allows you
+    /// to configure when this spacing is allocated.
     ///
     /// - [`HighlightSpacing::Always`] will always allocate the spacing, regardless of whether an
     ///   item is selected or not. This means that the table will never change size, regardless of
@@ -401,6 +479,16 @@ impl<'a> List<'a> {
 
     /// Sets the number of items around the currently selected item that should be kept visible
     ///
+    /// Like the `scrolloff` option of text editors, the list scrolls as soon as the selection gets
+    /// closer than `padding` items to the top or bottom of the list area, so that there is always
+    /// some context around the selected item. The padding does not apply past the first and last
+    /// items, which can still be selected at the edges of the list.
+    ///
+    /// When the list area is too small to fit the selected item and `padding` items on both sides,
+    /// the padding is reduced until they fit, which keeps the selected item in the middle of the
+    /// list area (or one row off when the number of visible items is even). With items of varying
+    /// heights, the padding is reduced until the heights of the items around the selected one fit.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Example
@@ -419,6 +507,148 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets whether to draw tree connector lines in the indentation of the items
+    ///
+    /// When enabled, each indented item (see [`ListItem::indent`]) is connected to its parent
+    /// with `├── ` when it has a following sibling or `└── ` when it is the last child, and the
+    /// lines of ancestors that have following siblings are continued with `│`. Items without
+    /// indentation have no connector.
+    ///
+    /// This is `false` by default, in which case the indentation is left blank.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let items = [
+    ///     ListItem::new("src"),
+    ///     ListItem::new("widgets").indent(1),
+    ///     ListItem::new("list.rs").indent(2),
+    ///     ListItem::new("lib.rs").indent(1),
+    /// ];
+    /// // src
+    /// // ├── widgets
+    /// // │   └── list.rs
+    /// // └── lib.rs
+    /// let list = List::new(items).tree_lines(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tree_lines(mut self, tree_lines: bool) -> Self {
+        self.tree_lines = tree_lines;
+        self
+    }
+
+    /// Sets whether to wrap the text of the items that are wider than the list
+    ///
+    /// By default, the lines of an item that don't fit in the width of the list are truncated.
+    /// When enabled, they are wrapped at word boundaries instead, so the item takes up as many rows
+    /// as it needs. The width left for the text excludes the highlight symbol, suffix, gutter and
+    /// indentation. The selection and scrolling take the wrapped height of the items into account,
+    /// and the highlight style covers every row of the selected item.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = [
+    ///     "A short item",
+    ///     "A much longer item that may not fit in the list",
+    /// ];
+    /// let list = List::new(items).wrap(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets whether the horizontal scrolling follows the selected item
+    ///
+    /// The content of the items is scrolled horizontally by [`ListState::horizontal_offset`]. By
+    /// default, the offset is limited so that the end of the widest visible item is at the right
+    /// edge of the list. When enabled, it is limited by the width of the selected item instead (and
+    /// reset when no item is selected), so moving the selection to a shorter item scrolls back
+    /// to keep its content visible.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let items = [
+    ///     "A short item",
+    ///     "A much longer item that may not fit in the list",
+    /// ];
+    /// let list = List::new(items).follow_selection_horizontal(true);
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.scroll_right(10);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn follow_selection_horizontal(mut self, follow: bool) -> Self {
+        self.horizontal_limit = if follow {
+            HorizontalLimit::SelectedItem
+        } else {
+            HorizontalLimit::VisibleItems
+        };
+        self
+    }
+
+    /// Sets a header row that is rendered above the items.
+    ///
+    /// The header takes up the first row of the list area and does not scroll with the items. The
+    /// remaining rows are used for the items, so the selected item is never rendered over the
+    /// header. The header is rendered at the top regardless of the [`ListDirection`].
+    ///
+    /// `header` accepts any type that can be converted into a [`Line`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["foo.txt    12K", "bar.rs      3K"];
+    /// let list = List::new(items).header("Name      Size");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header<T: Into<Line<'a>>>(mut self, header: T) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets a footer row that is rendered below the items.
+    ///
+    /// The footer takes up the last row of the list area and stays pinned there even when there
+    /// are fewer items than available rows. The footer is rendered at the bottom regardless of the
+    /// [`ListDirection`].
+    ///
+    /// `footer` accepts any type that can be converted into a [`Line`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).footer(format!("{} items", 2));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn footer<T: Into<Line<'a>>>(mut self, footer: T) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
         self.items.len()
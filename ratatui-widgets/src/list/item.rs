@@ -1,4 +1,7 @@
-use ratatui_core::{style::Style, text::Text};
+use ratatui_core::{
+    style::Style,
+    text::{Span, Text},
+};
 
 /// A single item in a [`List`]
 ///
@@ -71,6 +74,8 @@ use ratatui_core::{style::Style, text::Text};
 pub struct ListItem<'a> {
     pub(crate) content: Text<'a>,
     pub(crate) style: Style,
+    pub(crate) indent: u16,
+    pub(crate) gutter: Option<Span<'a>>,
 }
 
 impl<'a> ListItem<'a> {
@@ -116,6 +121,8 @@ impl<'a> ListItem<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            indent: 0,
+            gutter: None,
         }
     }
 
@@ -158,6 +165,79 @@ impl<'a> ListItem<'a> {
         self
     }
 
+    /// Sets the indentation level of the item
+    ///
+    /// Each level indents the item's content by [`List::INDENT_WIDTH`] columns. This can be used
+    /// to display a tree, optionally with connector lines drawn by [`List::tree_lines`]. Items
+    /// are treated as children of the closest preceding item with a lower indentation level.
+    ///
+    /// The indentation is always kept when the list is narrower than the item, and the content is
+    /// truncated instead.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let items = [
+    ///     ListItem::new("src"),
+    ///     ListItem::new("main.rs").indent(1),
+    ///     ListItem::new("lib.rs").indent(1),
+    /// ];
+    /// let list = List::new(items).tree_lines(true);
+    /// ```
+    ///
+    /// [`List::INDENT_WIDTH`]: crate::list::List::INDENT_WIDTH
+    /// [`List::tree_lines`]: crate::list::List::tree_lines
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indent(mut self, level: u16) -> Self {
+        self.indent = level;
+        self
+    }
+
+    /// Sets the gutter of the item
+    ///
+    /// The gutter is a [`Span`] (e.g. a checkbox or a status icon) drawn on the first line of the
+    /// item, in a column reserved before its content. The [`List`] sizes this column to the widest
+    /// gutter of the visible items, so the content of these items starts at the same column even
+    /// when the gutters have different widths. Items without a gutter leave the column blank. When
+    /// no visible item has a gutter, no column is reserved.
+    ///
+    /// The gutter is drawn after the [highlight symbol](crate::list::List::highlight_symbol) and
+    /// before the indentation of the item, so a row is laid out as follows:
+    ///
+    /// ```text
+    /// >> [x] └─ content
+    /// ^  ^   ^  ^
+    /// │  │   │  └ content
+    /// │  │   └─── indentation
+    /// │  └─────── gutter
+    /// └────────── highlight symbol
+    /// ```
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let items = [
+    ///     ListItem::new("Buy milk").gutter("[x] "),
+    ///     ListItem::new("Walk the dog").gutter("[ ] "),
+    /// ];
+    /// let list = List::new(items);
+    /// ```
+    ///
+    /// [`List`]: crate::list::List
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gutter<T: Into<Span<'a>>>(mut self, gutter: T) -> Self {
+        self.gutter = Some(gutter.into());
+        self
+    }
+
     /// Returns the item height
     ///
     /// # Examples
@@ -233,6 +313,12 @@ mod tests {
         assert_eq!(item.style, Style::default());
     }
 
+    #[test]
+    fn indent() {
+        assert_eq!(ListItem::new("Test item").indent, 0);
+        assert_eq!(ListItem::new("Test item").indent(2).indent, 2);
+    }
+
     #[test]
     fn new_from_string() {
         let item = ListItem::new("Test item".to_string());
@@ -1,13 +1,19 @@
+use std::ops::Range;
+
 use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
+    style::Style,
+    symbols::line,
+    text::{Line, Span, Text, WidthMode},
     widgets::{StatefulWidget, Widget},
 };
-use unicode_width::UnicodeWidthStr;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
     block::BlockExt,
-    list::{List, ListDirection, ListState},
+    list::{HorizontalLimit, List, ListDirection, ListItem, ListState},
+    reflow::{LineComposer, WordWrapper},
 };
 
 impl Widget for List<'_> {
@@ -34,10 +40,17 @@ impl StatefulWidget for List<'_> {
 impl StatefulWidget for &List<'_> {
     type State = ListState;
 
+    #[allow(clippy::too_many_lines)]
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let list_area = self.block.inner_if_some(area);
+        if self.wrap {
+            let list = self.with_wrapped_items(list_area, state);
+            StatefulWidget::render(&list, list_area, buf, state);
+            return;
+        }
+        let list_area = self.render_header_and_footer(list_area, buf);
 
         if list_area.is_empty() {
             return;
@@ -55,31 +68,78 @@ impl StatefulWidget for &List<'_> {
 
         let list_height = list_area.height as usize;
 
-        let (first_visible_index, last_visible_index) =
-            self.get_items_bounds(state.selected, state.offset, list_height);
+        let (first_visible_index, last_visible_index, line_offset) =
+            self.get_items_bounds_with_line_offset(state, list_height);
 
         // Important: this changes the state's offset to be the beginning of the now viewable items
         state.offset = first_visible_index;
+        state.line_offset = line_offset;
 
-        // Get our set highlighted symbol (if one was set)
-        let highlight_symbol = self.highlight_symbol.unwrap_or("");
-        let blank_symbol = " ".repeat(highlight_symbol.width());
+        // when scrolled by lines, the item following the visible ones is partially drawn in the
+        // remaining space so that scrolling doesn't leave a gap at the end of the list
+        let partial_items = usize::from(line_offset > 0);
+
+        let tree_lines = if self.tree_lines {
+            self.tree_lines_in(first_visible_index..last_visible_index + partial_items)
+        } else {
+            Vec::new()
+        };
+
+        let mut columns = ItemColumns {
+            selection_spacing: self.highlight_spacing.should_add(state.selected.is_some()),
+            gutter_width: self.gutter_width(
+                first_visible_index,
+                last_visible_index - first_visible_index + partial_items,
+            ),
+            horizontal_offset: 0,
+        };
+        if state.horizontal_offset > 0 {
+            let overflow = |item: &ListItem| {
+                let content_width = self.content_width(item, list_area.width, columns);
+                item.content.width().saturating_sub(content_width.into())
+            };
+            let max_horizontal_offset = match self.horizontal_limit {
+                HorizontalLimit::SelectedItem => {
+                    state.selected.map_or(0, |i| overflow(&self.items[i]))
+                }
+                HorizontalLimit::VisibleItems => self
+                    .items
+                    .iter()
+                    .skip(first_visible_index)
+                    .take(last_visible_index - first_visible_index + partial_items)
+                    .map(overflow)
+                    .max()
+                    .unwrap_or_default(),
+            };
+            state.horizontal_offset = state.horizontal_offset.min(max_horizontal_offset);
+            columns.horizontal_offset = state.horizontal_offset;
+        }
 
         let mut current_height = 0;
-        let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
         for (i, item) in self
             .items
             .iter()
             .enumerate()
             .skip(state.offset)
-            .take(last_visible_index - first_visible_index)
+            .take(last_visible_index - first_visible_index + partial_items)
         {
+            let item_height = item.height() as u16;
+            let clipped_lines = if i == first_visible_index {
+                line_offset as u16
+            } else {
+                0
+            };
+            let visible_height =
+                (item_height - clipped_lines).min(list_area.height - current_height);
+            if visible_height == 0 {
+                continue;
+            }
             let (x, y) = if self.direction == ListDirection::BottomToTop {
-                current_height += item.height() as u16;
+                current_height += visible_height;
                 (list_area.left(), list_area.bottom() - current_height)
             } else {
                 let pos = (list_area.left(), list_area.top() + current_height);
-                current_height += item.height() as u16;
+                current_height += visible_height;
                 pos
             };
 
@@ -87,54 +147,383 @@ impl StatefulWidget for &List<'_> {
                 x,
                 y,
                 width: list_area.width,
-                height: item.height() as u16,
+                height: visible_height,
+            };
+            // The lines scrolled out of view are at the start of the list, while the ones that
+            // don't fit are at its end.
+            let skipped_lines = if self.direction == ListDirection::BottomToTop {
+                item_height - visible_height - clipped_lines
+            } else {
+                clipped_lines
             };
+            let rows = ItemRows {
+                is_selected: state.selected == Some(i),
+                tree_lines: tree_lines.get(i - first_visible_index),
+                skipped_lines,
+            };
+            self.render_item(item, row_area, buf, columns, rows);
+            // the items of the multi-selection get the highlight style, but not the symbol
+            if state.selected_indices.contains(&i) {
+                buf.set_style(row_area, self.highlight_style);
+            }
+        }
+    }
+}
 
-            let item_style = self.style.patch(item.style);
-            buf.set_style(row_area, item_style);
+/// The columns reserved before the content of every item of a list.
+#[derive(Debug, Clone, Copy)]
+struct ItemColumns {
+    /// Whether the column of the highlight symbol is reserved
+    selection_spacing: bool,
+    /// The width of the gutter column
+    gutter_width: u16,
+    /// The number of columns the content of the items is scrolled by
+    horizontal_offset: usize,
+}
 
-            let is_selected = state.selected == Some(i);
+/// The rows of a single item that are rendered.
+#[derive(Debug, Clone, Copy)]
+struct ItemRows<'t> {
+    /// Whether the item is the selected one
+    is_selected: bool,
+    /// The tree connectors of the item, if they are drawn
+    tree_lines: Option<&'t Vec<bool>>,
+    /// The number of lines at the start of the item that are not rendered
+    skipped_lines: u16,
+}
 
-            let item_area = if selection_spacing {
-                let highlight_symbol_width = self.highlight_symbol.unwrap_or("").width() as u16;
-                Rect {
-                    x: row_area.x + highlight_symbol_width,
-                    width: row_area.width.saturating_sub(highlight_symbol_width),
-                    ..row_area
-                }
-            } else {
-                row_area
+impl List<'_> {
+    /// Renders the lines of a single item, including its highlight symbol, gutter and tree lines,
+    /// in `row_area`, starting from the line given by `rows.skipped_lines`.
+    fn render_item(
+        &self,
+        item: &ListItem,
+        row_area: Rect,
+        buf: &mut Buffer,
+        columns: ItemColumns,
+        rows: ItemRows,
+    ) {
+        let ItemColumns {
+            selection_spacing,
+            gutter_width,
+            horizontal_offset,
+        } = columns;
+        let ItemRows {
+            is_selected,
+            tree_lines,
+            skipped_lines,
+        } = rows;
+        let width_mode = WidthMode::current();
+        // Get our set highlighted symbol (if one was set)
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let highlight_symbol_width = width_mode.width(highlight_symbol);
+        let blank_symbol = " ".repeat(highlight_symbol_width);
+        let highlight_suffix = self.highlight_symbol_suffix.unwrap_or("");
+        let highlight_suffix_width = width_mode.width(highlight_suffix);
+        let blank_suffix = " ".repeat(highlight_suffix_width);
+
+        let item_style = self.style.patch(item.style);
+        buf.set_style(row_area, item_style);
+
+        let (item_area, suffix_area) = if selection_spacing {
+            let highlight_symbol_width = (highlight_symbol_width as u16).min(row_area.width);
+            let suffix_width =
+                (highlight_suffix_width as u16).min(row_area.width - highlight_symbol_width);
+            let item_area = Rect {
+                x: row_area.x + highlight_symbol_width,
+                width: row_area.width - highlight_symbol_width - suffix_width,
+                ..row_area
+            };
+            let suffix_area = Rect {
+                x: item_area.right(),
+                width: suffix_width,
+                ..row_area
+            };
+            (item_area, suffix_area)
+        } else {
+            (row_area, Rect::ZERO)
+        };
+        let gutter_width = gutter_width.min(item_area.width);
+        if let Some(gutter) = item.gutter.as_ref().filter(|_| skipped_lines == 0) {
+            let gutter_area = Rect {
+                width: gutter_width,
+                height: 1,
+                ..item_area
             };
-            Widget::render(&item.content, item_area, buf);
-
-            if selection_spacing {
-                for j in 0..item.content.height() {
-                    // if the item is selected, we need to display the highlight symbol:
-                    // - either for the first line of the item only,
-                    // - or for each line of the item if the appropriate option is set
-                    let symbol = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
-                        highlight_symbol
-                    } else {
-                        &blank_symbol
-                    };
-                    buf.set_stringn(
-                        x,
-                        y + j as u16,
-                        symbol,
-                        list_area.width as usize,
-                        item_style,
-                    );
+            gutter.render(gutter_area, buf);
+        }
+        let item_area = Rect {
+            x: item_area.x + gutter_width,
+            width: item_area.width - gutter_width,
+            ..item_area
+        };
+        let indent_width = item
+            .indent
+            .saturating_mul(List::INDENT_WIDTH)
+            .min(item_area.width);
+        if let Some(continues) = tree_lines {
+            let indent_area = Rect {
+                width: indent_width,
+                ..item_area
+            };
+            List::render_tree_lines(continues, indent_area, skipped_lines, buf, item_style);
+        }
+        let content_area = Rect {
+            x: item_area.x + indent_width,
+            width: item_area.width - indent_width,
+            ..item_area
+        };
+        render_content(
+            &item.content,
+            content_area,
+            buf,
+            skipped_lines.into(),
+            horizontal_offset,
+        );
+
+        if selection_spacing {
+            let lines = item.content.height() as u16;
+            for (j, y) in (skipped_lines..lines).zip(row_area.rows().map(|row| row.y)) {
+                // if the item is selected, we need to display the highlight symbol:
+                // - either for the first line of the item only,
+                // - or for each line of the item if the appropriate option is set
+                let highlighted = is_selected && (j == 0 || self.repeat_highlight_symbol);
+                let (symbol, suffix) = if highlighted {
+                    (highlight_symbol, highlight_suffix)
+                } else {
+                    (blank_symbol.as_str(), blank_suffix.as_str())
+                };
+                buf.set_stringn(row_area.x, y, symbol, row_area.width as usize, item_style);
+                buf.set_stringn(
+                    suffix_area.x,
+                    y,
+                    suffix,
+                    suffix_area.width as usize,
+                    item_style,
+                );
+            }
+        }
+
+        if is_selected {
+            buf.set_style(row_area, self.highlight_style);
+        }
+    }
+
+    /// Returns a copy of the list, without block or wrapping, where the content of the items is
+    /// wrapped to the width left for it in a list of the given area.
+    ///
+    /// Only the items that can be visible given the offset, selection and scroll padding of the
+    /// state are wrapped. The items before them are left empty, as they are never rendered.
+    fn with_wrapped_items(&self, area: Rect, state: &ListState) -> List<'_> {
+        let Rect { width, height, .. } = area;
+        let width_mode = WidthMode::current();
+        let first = state
+            .selected
+            .map_or(state.offset, |selected| selected.min(state.offset))
+            .saturating_sub(self.scroll_padding);
+        // every visible item takes up at least a row, so they are within the height of the list
+        // after the offset or the selected item
+        let last = state
+            .selected
+            .map_or(state.offset, |selected| selected.max(state.offset))
+            .saturating_add(self.scroll_padding)
+            .saturating_add(height.into());
+        let mut width = width.saturating_sub(self.gutter_width(first, last - first));
+        if self.highlight_spacing.should_add(state.selected.is_some()) {
+            let symbol_width = width_mode.width(self.highlight_symbol.unwrap_or(""))
+                + width_mode.width(self.highlight_symbol_suffix.unwrap_or(""));
+            width = width.saturating_sub(u16::try_from(symbol_width).unwrap_or(u16::MAX));
+        }
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let width = width.saturating_sub(item.indent.saturating_mul(Self::INDENT_WIDTH));
+                let content = if i < first {
+                    Text::default()
+                } else if width == 0 {
+                    skip_lines(&item.content, 0)
+                } else {
+                    wrap_text(&item.content, width, width_mode)
+                };
+                ListItem {
+                    content,
+                    style: item.style,
+                    indent: item.indent,
+                    gutter: item.gutter.as_ref().map(borrow_span),
                 }
+            })
+            .collect();
+        List {
+            block: None,
+            items,
+            style: self.style,
+            direction: self.direction,
+            highlight_style: self.highlight_style,
+            highlight_symbol: self.highlight_symbol,
+            highlight_symbol_suffix: self.highlight_symbol_suffix,
+            repeat_highlight_symbol: self.repeat_highlight_symbol,
+            highlight_spacing: self.highlight_spacing.clone(),
+            scroll_padding: self.scroll_padding,
+            header: self.header.as_ref().map(borrow_line),
+            footer: self.footer.as_ref().map(borrow_line),
+            tree_lines: self.tree_lines,
+            wrap: false,
+            horizontal_limit: self.horizontal_limit,
+        }
+    }
+
+    /// Returns the width left for the content of an item in a list of the given width.
+    fn content_width(&self, item: &ListItem, width: u16, columns: ItemColumns) -> u16 {
+        let width_mode = WidthMode::current();
+        let mut width = width.saturating_sub(columns.gutter_width);
+        if columns.selection_spacing {
+            let symbol_width = width_mode.width(self.highlight_symbol.unwrap_or(""))
+                + width_mode.width(self.highlight_symbol_suffix.unwrap_or(""));
+            width = width.saturating_sub(u16::try_from(symbol_width).unwrap_or(u16::MAX));
+        }
+        width.saturating_sub(item.indent.saturating_mul(Self::INDENT_WIDTH))
+    }
+
+    /// Returns the width of the gutter column, which is the width of the widest gutter of the
+    /// `count` items from `first` onwards.
+    fn gutter_width(&self, first: usize, count: usize) -> u16 {
+        self.items
+            .iter()
+            .skip(first)
+            .take(count)
+            .filter_map(|item| item.gutter.as_ref())
+            .map(|gutter| u16::try_from(gutter.width()).unwrap_or(u16::MAX))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Computes the tree connectors of the items in `range`.
+    ///
+    /// For each item, returns whether there is a following sibling at each indentation level from
+    /// 1 up to the item's own level. An item is a sibling at level `k` when it comes after the item
+    /// at level `k` before any item with a level lower than `k`. The items after the range are
+    /// only looked at up to the next item without indentation, which ends every level.
+    fn tree_lines_in(&self, range: Range<usize>) -> Vec<Vec<bool>> {
+        let end = self
+            .items
+            .iter()
+            .skip(range.end)
+            .position(|item| item.indent == 0)
+            .map_or(self.items.len(), |position| range.end + position);
+        let mut has_sibling: Vec<bool> = Vec::new();
+        let mut lines = Vec::with_capacity(range.len());
+        for (i, item) in self
+            .items
+            .iter()
+            .enumerate()
+            .take(end)
+            .skip(range.start)
+            .rev()
+        {
+            let level = item.indent as usize;
+            has_sibling.resize(level + 1, false);
+            if i < range.end {
+                lines.push(has_sibling[1..].to_vec());
             }
+            has_sibling[level] = true;
+        }
+        lines.reverse();
+        lines
+    }
 
-            if is_selected {
-                buf.set_style(row_area, self.highlight_style);
+    /// Draws the tree connectors of an item in its indentation area.
+    ///
+    /// `continues` holds whether there is a following sibling at each level of the item (see
+    /// [`Self::tree_lines_in`]). The first row connects the item to its parent, the other rows
+    /// of a multi-line item only continue the lines. `skipped_lines` is the number of lines of
+    /// the item above `area` that are not rendered.
+    fn render_tree_lines(
+        continues: &[bool],
+        area: Rect,
+        skipped_lines: u16,
+        buf: &mut Buffer,
+        style: Style,
+    ) {
+        let width = Self::INDENT_WIDTH;
+        let horizontal = line::HORIZONTAL.repeat(width.saturating_sub(2) as usize);
+        let blank = " ".repeat(width as usize);
+        let vertical = format!("{}{}", line::VERTICAL, " ".repeat(width as usize - 1));
+        let branch = format!("{}{horizontal} ", line::VERTICAL_RIGHT);
+        let last_branch = format!("{}{horizontal} ", line::BOTTOM_LEFT);
+        for row in 0..area.height {
+            for (level, &has_sibling) in continues.iter().enumerate() {
+                let is_own_level = level + 1 == continues.len();
+                let first_row = skipped_lines == 0 && row == 0;
+                let segment = match (has_sibling, is_own_level && first_row) {
+                    (true, true) => &branch,
+                    (false, true) => &last_branch,
+                    (true, false) => &vertical,
+                    (false, false) => &blank,
+                };
+                let x = area.left() + level as u16 * width;
+                if x >= area.right() {
+                    break;
+                }
+                let max_width = (area.right() - x) as usize;
+                buf.set_stringn(x, area.top() + row, segment, max_width, style);
             }
         }
     }
-}
 
-impl List<'_> {
+    /// Renders the header and footer rows and returns the area that is left for the items.
+    fn render_header_and_footer(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        let mut area = area;
+        if let Some(header) = &self.header {
+            if area.height > 0 {
+                header.render(Rect { height: 1, ..area }, buf);
+                area.y += 1;
+                area.height -= 1;
+            }
+        }
+        if let Some(footer) = &self.footer {
+            if area.height > 0 {
+                area.height -= 1;
+                footer.render(
+                    Rect {
+                        y: area.bottom(),
+                        height: 1,
+                        ..area
+                    },
+                    buf,
+                );
+            }
+        }
+        area
+    }
+
+    /// Like [`Self::get_items_bounds`], but also returns the number of lines of the first item
+    /// scrolled out of view.
+    ///
+    /// The line offset of the state is only honored if the first item doesn't change to make the
+    /// selected item visible.
+    fn get_items_bounds_with_line_offset(
+        &self,
+        state: &ListState,
+        max_height: usize,
+    ) -> (usize, usize, usize) {
+        let offset = state.offset.min(self.items.len().saturating_sub(1));
+        let line_offset = self.items.get(offset).map_or(0, |item| {
+            state.line_offset.min(item.height().saturating_sub(1))
+        });
+        let (first, last) = self.get_items_bounds(
+            state.selected,
+            offset,
+            max_height.saturating_add(line_offset),
+        );
+        if line_offset > 0 && first != offset {
+            let (first, last) = self.get_items_bounds(state.selected, state.offset, max_height);
+            return (first, last, 0);
+        }
+        (first, last, line_offset)
+    }
+
     /// Given an offset, calculate which items can fit in a given area
     fn get_items_bounds(
         &self,
@@ -263,6 +652,118 @@ impl List<'_> {
     }
 }
 
+/// Wraps the lines of `text` at word boundaries so that they fit in `width` columns.
+fn wrap_text<'a>(text: &'a Text<'_>, width: u16, width_mode: WidthMode) -> Text<'a> {
+    let lines = text.iter().map(|line| {
+        let alignment = line.alignment.or(text.alignment).unwrap_or_default();
+        (line.styled_graphemes(Style::default()), alignment)
+    });
+    let mut line_composer = WordWrapper::new(lines, width, true);
+    line_composer.set_width_mode(width_mode);
+    let mut wrapped_lines = Vec::new();
+    while let Some(wrapped) = line_composer.next_line() {
+        let mut spans: Vec<Span> = Vec::new();
+        for grapheme in wrapped.graphemes {
+            match spans.last_mut() {
+                Some(span) if span.style == grapheme.style => {
+                    span.content.to_mut().push_str(grapheme.symbol);
+                }
+                _ => spans.push(Span::styled(grapheme.symbol, grapheme.style)),
+            }
+        }
+        wrapped_lines.push(Line::from(spans).alignment(wrapped.alignment));
+    }
+    Text {
+        alignment: text.alignment,
+        style: text.style,
+        lines: wrapped_lines,
+    }
+}
+
+/// Renders `text` scrolled by `lines` lines and at most by `columns` columns, so that the end of
+/// the lines that don't fit in `area` is never scrolled past.
+fn render_content(text: &Text, area: Rect, buf: &mut Buffer, lines: usize, columns: usize) {
+    let overflow = text.width().saturating_sub(area.width.into());
+    let visible_text;
+    let text = if lines == 0 {
+        text
+    } else {
+        visible_text = skip_lines(text, lines);
+        &visible_text
+    };
+    match columns.min(overflow) {
+        0 => Widget::render(text, area, buf),
+        columns => Widget::render(skip_columns(text, columns), area, buf),
+    }
+}
+
+/// Returns a copy of `span` that borrows its content.
+fn borrow_span<'a>(span: &'a Span<'_>) -> Span<'a> {
+    Span::styled(span.content.as_ref(), span.style)
+}
+
+/// Returns a copy of `line` that borrows the content of its spans.
+fn borrow_line<'a>(line: &'a Line<'_>) -> Line<'a> {
+    Line {
+        style: line.style,
+        alignment: line.alignment,
+        direction: line.direction,
+        spans: line.iter().map(borrow_span).collect(),
+    }
+}
+
+/// Removes the first `lines` lines of `text`, borrowing the content of the others.
+fn skip_lines<'a>(text: &'a Text<'_>, lines: usize) -> Text<'a> {
+    let lines = text.iter().skip(lines).map(borrow_line);
+    Text {
+        alignment: text.alignment,
+        style: text.style,
+        lines: lines.collect(),
+    }
+}
+
+/// Removes the first `columns` columns of each line of `text`.
+///
+/// A wide grapheme cut by the left edge is replaced by spaces, so that it is never half rendered.
+fn skip_columns<'a>(text: &'a Text<'_>, columns: usize) -> Text<'a> {
+    let width_mode = WidthMode::current();
+    let lines = text.iter().map(|line| {
+        let mut remaining = columns;
+        let mut spans = Vec::with_capacity(line.spans.len());
+        for span in line {
+            let mut start = 0;
+            for (index, grapheme) in span.content.grapheme_indices(true) {
+                if remaining == 0 {
+                    break;
+                }
+                let width = width_mode.width(grapheme);
+                start = index + grapheme.len();
+                if width > remaining {
+                    spans.push(Span::styled(" ".repeat(width - remaining), span.style));
+                    remaining = 0;
+                } else {
+                    remaining -= width;
+                }
+            }
+            if start < span.content.len() {
+                #[allow(clippy::string_slice)] // Is safe as it comes from UnicodeSegmentation
+                spans.push(Span::styled(&span.content[start..], span.style));
+            }
+        }
+        Line {
+            style: line.style,
+            alignment: line.alignment,
+            direction: line.direction,
+            spans,
+        }
+    });
+    Text {
+        alignment: text.alignment,
+        style: text.style,
+        lines: lines.collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -804,6 +1305,102 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn multi_selection() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"])
+            .highlight_symbol(">>")
+            .highlight_style(Style::default().fg(Color::Yellow));
+        let mut state = ListState::default().with_selected(Some(1));
+        state.toggle(0);
+        state.toggle(2);
+        let buffer = stateful_widget(list, &mut state, 10, 4);
+        let expected = Buffer::with_lines([
+            "  Item 0  ".yellow(),
+            ">>Item 1  ".yellow(),
+            "  Item 2  ".yellow(),
+            "  Item 3  ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn multi_selection_partially_visible_item() {
+        let list = List::new(["Item 0\nLine 2", "Item 1"]).highlight_style(Style::new().yellow());
+        let mut state = ListState::default().with_line_offset(1);
+        state.select_all(2);
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        let expected = Buffer::with_lines([
+            "Line 2    ".yellow(),
+            "Item 1    ".yellow(),
+            "          ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::suffix(None, [
+        "Item 0    ",
+        "Item 1   <",
+        "Item 2    ",
+    ])]
+    #[case::prefix_and_suffix(Some(">>"), [
+        "  Item 0  ",
+        ">>Item 1 <",
+        "  Item 2  ",
+    ])]
+    fn highlight_symbol_suffix(#[case] prefix: Option<&str>, #[case] expected: [&str; 3]) {
+        let mut list = List::new(["Item 0", "Item 1", "Item 2"]).highlight_symbol_suffix(" <");
+        if let Some(prefix) = prefix {
+            list = list.highlight_symbol(prefix);
+        }
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn gutter_aligns_content_with_mixed_width_icons() {
+        let items = [
+            ListItem::new("Done").gutter("✔ "),
+            ListItem::new("Todo\nMore"),
+            ListItem::new("Wide").gutter("🚧 "),
+            ListItem::new("Child").gutter("• ").indent(1),
+        ];
+        let list = List::new(items).highlight_symbol(">");
+        let mut state = ListState::default().with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 12, 5);
+        let expected = Buffer::with_lines([
+            " ✔  Done    ",
+            "    Todo    ",
+            "    More    ",
+            ">🚧 Wide    ",
+            " •      Chil",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn gutter_style() {
+        let list = List::new([ListItem::new("a").gutter("x".red()).blue()]);
+        let buffer = widget(list, 3, 1);
+        let mut expected = Buffer::with_lines(["xa ".blue()]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_symbol_suffix_clips_content() {
+        let list = List::new(["Item 0", "Item 1 is long", "Item 2"])
+            .highlight_symbol(">")
+            .highlight_symbol_suffix("<")
+            .highlight_spacing(HighlightSpacing::Always)
+            .repeat_highlight_symbol(true);
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 8, 3);
+        let expected = Buffer::with_lines([" Item 0 ", ">Item 1<", " Item 2 "]);
+        assert_eq!(buffer, expected);
+    }
+
     #[rstest]
     #[case::top_to_bottom(ListDirection::TopToBottom, [
         "Item 0    ",
@@ -852,6 +1449,71 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[rstest]
+    #[case::top_to_bottom(ListDirection::TopToBottom, [
+        "  b2  ",
+        ">>c1  ",
+        "  c2  ",
+        "  d1  ",
+    ])]
+    #[case::bottom_to_top(ListDirection::BottomToTop, [
+        "  d2  ",
+        ">>c1  ",
+        "  c2  ",
+        "  b1  ",
+    ])]
+    fn line_offset_clips_first_item<'line, Lines>(
+        #[case] direction: ListDirection,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let list = List::new(["a1\na2", "b1\nb2", "c1\nc2", "d1\nd2"])
+            .highlight_symbol(">>")
+            .direction(direction);
+        let mut state = ListState::default()
+            .with_offset(1)
+            .with_line_offset(1)
+            .with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 6, 4);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+        assert_eq!(state.offset, 1);
+        assert_eq!(state.line_offset, 1);
+    }
+
+    #[test]
+    fn line_offset_is_clamped_to_the_first_item() {
+        let list = List::new(["a1\na2", "b1\nb2", "c1\nc2"]);
+        let mut state = ListState::default().with_line_offset(5);
+        let buffer = stateful_widget(list, &mut state, 4, 3);
+        assert_eq!(buffer, Buffer::with_lines(["a2  ", "b1  ", "b2  "]));
+        assert_eq!(state.line_offset, 1);
+    }
+
+    #[test]
+    fn empty_item_does_not_stop_rendering() {
+        let items = [
+            ListItem::new("a"),
+            ListItem::new(Text::default()),
+            ListItem::new("b"),
+        ];
+        let buffer = widget(List::new(items), 4, 3);
+        assert_eq!(buffer, Buffer::with_lines(["a   ", "b   ", "    "]));
+    }
+
+    #[test]
+    fn line_offset_is_reset_when_scrolling_to_the_selected_item() {
+        let list = List::new(["a1\na2", "b1\nb2", "c1\nc2"]);
+        let mut state = ListState::default()
+            .with_line_offset(1)
+            .with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 4, 3);
+        assert_eq!(buffer, Buffer::with_lines(["c1  ", "c2  ", "    "]));
+        assert_eq!(state.offset, 2);
+        assert_eq!(state.line_offset, 0);
+    }
+
     #[rstest]
     #[case(None, [
         "Item 0 with a v",
@@ -904,6 +1566,144 @@ mod tests {
         );
     }
 
+    #[test]
+    fn indent() {
+        let items = [
+            ListItem::new("root"),
+            ListItem::new("child").indent(1),
+            ListItem::new("grandchild").indent(2),
+        ];
+        let buffer = widget(List::new(items), 12, 3);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "root        ",
+            "    child   ",
+            "        gran",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn tree_lines() {
+        let items = [
+            ListItem::new("src"),
+            ListItem::new("widgets").indent(1),
+            ListItem::new("list.rs").indent(2),
+            ListItem::new("table.rs").indent(2),
+            ListItem::new("lib.rs").indent(1),
+            ListItem::new("README"),
+        ];
+        let buffer = widget(List::new(items).tree_lines(true), 16, 6);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "src             ",
+            "├── widgets     ",
+            "│   ├── list.rs ",
+            "│   └── table.rs",
+            "└── lib.rs      ",
+            "README          ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn tree_lines_with_siblings_below_the_list() {
+        let items = [
+            ListItem::new("a"),
+            ListItem::new("b").indent(1),
+            ListItem::new("c").indent(2),
+            ListItem::new("d").indent(2),
+            ListItem::new("e").indent(1),
+        ];
+        let buffer = widget(List::new(items).tree_lines(true), 10, 3);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a         ",
+            "├── b     ",
+            "│   ├── c ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn tree_lines_with_selection_and_offset() {
+        let items = [
+            ListItem::new("a"),
+            ListItem::new("b").indent(1),
+            ListItem::new("c").indent(2),
+            ListItem::new("d").indent(1),
+        ];
+        let list = List::new(items)
+            .tree_lines(true)
+            .highlight_symbol(">")
+            .highlight_style(Style::new().red());
+        let mut state = ListState::default().with_selected(Some(2)).with_offset(2);
+        let buffer = stateful_widget(list, &mut state, 11, 2);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            ">│   └── c ",
+            " └── d     ",
+        ]);
+        // the highlight covers the whole row, including the indentation
+        expected.set_style(Rect::new(0, 0, 11, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn header_and_footer() {
+        let list = List::new(["Item 0", "Item 1"])
+            .header("Header")
+            .footer("Footer");
+        let buffer = widget(list, 10, 5);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "Header    ",
+            "Item 0    ",
+            "Item 1    ",
+            "          ",
+            "Footer    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn header_and_footer_with_selection() {
+        let items = ["Item 0", "Item 1", "Item 2", "Item 3", "Item 4"];
+        let list = List::new(items)
+            .header("Header")
+            .footer("Footer")
+            .highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(4));
+        let buffer = stateful_widget(list, &mut state, 10, 4);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "Header    ",
+            "  Item 3  ",
+            ">>Item 4  ",
+            "Footer    ",
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset, 3);
+    }
+
+    #[test]
+    fn header_and_footer_bottom_to_top() {
+        let list = List::new(["Item 0", "Item 1"])
+            .header("Header")
+            .footer("Footer")
+            .direction(ListDirection::BottomToTop);
+        let buffer = widget(list, 10, 5);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "Header    ",
+            "          ",
+            "Item 1    ",
+            "Item 0    ",
+            "Footer    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn selected_item_ensures_selected_item_is_visible_when_offset_is_after_visible_range() {
         let items = [
@@ -1153,6 +1953,42 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 
+    #[test]
+    fn padding_centers_selection_in_small_area() {
+        let items = (0..8).map(|i| format!("Item {i}")).collect::<Vec<_>>();
+        let list = List::new(items).scroll_padding(5);
+        let mut state = ListState::default();
+        let mut visible = Vec::new();
+        for selected in (0..8).chain((0..8).rev()) {
+            state.select(Some(selected));
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+            StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+            visible.push((selected, state.offset()));
+        }
+        // the selected item stays in the middle row, except next to the first and last items
+        assert_eq!(
+            visible,
+            [
+                (0, 0),
+                (1, 0),
+                (2, 1),
+                (3, 2),
+                (4, 3),
+                (5, 4),
+                (6, 5),
+                (7, 5),
+                (7, 5),
+                (6, 5),
+                (5, 4),
+                (4, 3),
+                (3, 2),
+                (2, 1),
+                (1, 0),
+                (0, 0),
+            ]
+        );
+    }
+
     /// If there isn't enough room for the selected item and the requested padding the list can jump
     /// up and down every frame if something isn't done about it. This code tests to make sure that
     /// isn't currently happening
@@ -1235,6 +2071,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrap() {
+        let items = ["one", "two three four", "five"];
+        let buffer = widget(List::new(items).wrap(true), 10, 5);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "one       ",
+            "two three ",
+            "four      ",
+            "five      ",
+            "          ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn wrap_highlights_every_row_of_selected_item() {
+        let items = ["Item 0", "Item 1", "long item"];
+        let list = List::new(items)
+            .wrap(true)
+            .highlight_symbol(">> ")
+            .highlight_style(Style::new().yellow());
+        let mut state = ListState::default().with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            ">> long   ",
+            "   item   ",
+        ]);
+        expected.set_style(expected.area, Style::new().yellow());
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset, 2);
+    }
+
+    #[test]
+    fn wrap_scrolls_by_wrapped_height() {
+        let items = ["long item", "Item 1", "Item 2"];
+        let list = List::new(items).wrap(true).highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "   Item 1 ",
+            ">> Item 2 ",
+            "          ",
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset, 1);
+    }
+
+    #[test]
+    fn wrap_scrolls_back_to_selected_item() {
+        let items = ["a long item", "Item 1", "Item 2", "Item 3"];
+        let list = List::new(items).wrap(true).scroll_padding(1);
+        let mut state = ListState::default().with_offset(3).with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 7, 4);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a long ",
+            "item   ",
+            "Item 1 ",
+            "Item 2 ",
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset, 0);
+    }
+
+    #[test]
+    fn wrap_excludes_indent_and_gutter() {
+        let items = [
+            ListItem::new("a b c").gutter("1"),
+            ListItem::new("d e f").indent(1).gutter("2"),
+        ];
+        let buffer = widget(List::new(items).wrap(true), 7, 5);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "1a b c ",
+            "2    d ",
+            "     e ",
+            "     f ",
+            "       ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     /// Regression test for a bug where highlight symbol being greater than width caused a panic due
     /// to subtraction with underflow.
     ///
@@ -1255,4 +2176,61 @@ mod tests {
         StatefulWidget::render(list, single_line_buf.area, &mut single_line_buf, &mut state);
         assert_eq!(single_line_buf, Buffer::with_lines([expected]));
     }
+
+    #[rstest]
+    #[case::none(0, 0, ["Short     ", "A long ite"])]
+    #[case::some(4, 4, ["Short     ", "ng item te"])]
+    #[case::clamped(100, 6, ["Short     ", " item text"])]
+    fn horizontal_offset(
+        #[case] offset: usize,
+        #[case] expected_offset: usize,
+        #[case] expected: [&str; 2],
+    ) {
+        let list = List::new(["Short", "A long item text"]);
+        let mut state = ListState::default().with_horizontal_offset(offset);
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+        assert_eq!(state.horizontal_offset(), expected_offset);
+    }
+
+    #[test]
+    fn horizontal_offset_clamped_to_visible_items() {
+        let list = List::new(["A long item text", "Short", "Medium item"]);
+        let mut state = ListState::default()
+            .with_offset(1)
+            .with_horizontal_offset(100);
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Short     ", "edium item"]));
+        assert_eq!(state.horizontal_offset(), 1);
+    }
+
+    #[rstest]
+    #[case::short_item_selected(0, 0, [">>Short   ", "  A long i"])]
+    #[case::long_item_selected(1, 4, ["  Short   ", ">>ng item "])]
+    #[case::no_selection(None, 0, ["Short     ", "A long ite"])]
+    fn follow_selection_horizontal<T: Into<Option<usize>>>(
+        #[case] selected: T,
+        #[case] expected_offset: usize,
+        #[case] expected: [&str; 2],
+    ) {
+        let list = List::new(["Short", "A long item text"])
+            .highlight_symbol(">>")
+            .follow_selection_horizontal(true);
+        let mut state = ListState::default()
+            .with_selected(selected.into())
+            .with_horizontal_offset(4);
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+        assert_eq!(state.horizontal_offset(), expected_offset);
+    }
+
+    #[rstest]
+    #[case::cut_wide_grapheme(1, " 本語 ")]
+    #[case::whole_wide_grapheme(2, "本語テ")]
+    fn horizontal_offset_wide_graphemes(#[case] offset: usize, #[case] expected: &str) {
+        let list = List::new(["日本語テキスト"]);
+        let mut state = ListState::default().with_horizontal_offset(offset);
+        let buffer = stateful_widget(list, &mut state, 6, 1);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
 }
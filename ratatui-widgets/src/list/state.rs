@@ -1,3 +1,7 @@
+use std::collections::BTreeSet;
+
+use crate::list::ListNavigation;
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -5,12 +9,29 @@
 /// that the selected item is visible. This will modify the [`ListState`] object passed to the
 /// `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of five fields:
 /// - [`offset`]: the index of the first item to be displayed
+/// - [`line_offset`]: the number of lines of the first item scrolled out of view, which allows
+///   scrolling smoothly through multi-line items
+/// - [`horizontal_offset`]: the number of columns the content of the items is scrolled by, which
+///   reveals the end of items wider than the list
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
+/// - [`selected_indices`]: the indices of the items that are part of a multi-selection
+///
+/// The selected item acts as a cursor which is moved by the navigation methods, while the
+/// multi-selection is only changed by [`toggle`], [`select_all`] and [`clear_selection`]. Both are
+/// highlighted with the [`List::highlight_style`], but only the selected item gets the highlight
+/// symbol.
 ///
 /// [`offset`]: ListState::offset()
+/// [`line_offset`]: ListState::line_offset()
+/// [`horizontal_offset`]: ListState::horizontal_offset()
 /// [`selected`]: ListState::selected()
+/// [`selected_indices`]: ListState::selected_indices()
+/// [`toggle`]: ListState::toggle()
+/// [`select_all`]: ListState::select_all()
+/// [`clear_selection`]: ListState::clear_selection()
+/// [`List::highlight_style`]: super::List::highlight_style
 ///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
@@ -46,7 +67,13 @@
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListState {
     pub(crate) offset: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) line_offset: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) horizontal_offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) selected_indices: BTreeSet<usize>,
 }
 
 impl ListState {
@@ -67,6 +94,42 @@ impl ListState {
         self
     }
 
+    /// Sets the number of lines of the first displayed item that are scrolled out of view
+    ///
+    /// See [`ListState::line_offset`] for more information.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_offset(1).with_line_offset(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_line_offset(mut self, line_offset: usize) -> Self {
+        self.line_offset = line_offset;
+        self
+    }
+
+    /// Sets the number of columns the content of the items is scrolled by
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_horizontal_offset(4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_horizontal_offset(mut self, horizontal_offset: usize) -> Self {
+        self.horizontal_offset = horizontal_offset;
+        self
+    }
+
     /// Sets the index of the selected item
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -112,6 +175,85 @@ impl ListState {
         &mut self.offset
     }
 
+    /// Number of lines of the first displayed item that are scrolled out of view
+    ///
+    /// The first displayed item (see [`ListState::offset`]) is clipped by this many lines, so only
+    /// its lower lines are drawn (its upper lines when the list goes from bottom to top). Changing
+    /// this value by one line at a time scrolls smoothly through items taller than one line. The
+    /// space freed at the other end of the list is filled with the beginning of the next item.
+    ///
+    /// The line offset is clamped to leave at least one line of the item visible when the list is
+    /// rendered, and is reset to `0` if the list scrolls to a different item to show the selected
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default();
+    /// assert_eq!(state.line_offset(), 0);
+    /// ```
+    pub const fn line_offset(&self) -> usize {
+        self.line_offset
+    }
+
+    /// Mutable reference to the number of lines of the first displayed item that are scrolled out
+    /// of view
+    ///
+    /// See [`ListState::line_offset`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// *state.line_offset_mut() = 1;
+    /// ```
+    pub fn line_offset_mut(&mut self) -> &mut usize {
+        &mut self.line_offset
+    }
+
+    /// Number of columns the content of the items is scrolled by
+    ///
+    /// The content of each item is shifted left by this many columns, so the end of the items
+    /// that are wider than the list can be revealed. The highlight symbol, gutter and indentation
+    /// are not scrolled. Each item is scrolled at most until its end is visible, so the items that
+    /// fit in the list are not affected.
+    ///
+    /// The horizontal offset is clamped when the list is rendered so that the end of the widest
+    /// visible item is at the right edge of the list, or of the selected item when
+    /// [`List::follow_selection_horizontal`] is enabled.
+    ///
+    /// [`List::follow_selection_horizontal`]: super::List::follow_selection_horizontal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default();
+    /// assert_eq!(state.horizontal_offset(), 0);
+    /// ```
+    pub const fn horizontal_offset(&self) -> usize {
+        self.horizontal_offset
+    }
+
+    /// Mutable reference to the number of columns the content of the items is scrolled by
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// *state.horizontal_offset_mut() = 4;
+    /// ```
+    pub fn horizontal_offset_mut(&mut self) -> &mut usize {
+        &mut self.horizontal_offset
+    }
+
     /// Index of the selected item
     ///
     /// Returns `None` if no item is selected
@@ -146,7 +288,8 @@ impl ListState {
 
     /// Sets the index of the selected item
     ///
-    /// Set to `None` if no item is selected. This will also reset the offset to `0`.
+    /// Set to `None` if no item is selected. This will also reset the offset and the line offset to
+    /// `0`.
     ///
     /// # Examples
     ///
@@ -160,6 +303,7 @@ impl ListState {
         self.selected = index;
         if index.is_none() {
             self.offset = 0;
+            self.line_offset = 0;
         }
     }
 
@@ -233,6 +377,218 @@ impl ListState {
         self.select(Some(usize::MAX));
     }
 
+    /// Selects the next item of a list of `item_count` items
+    ///
+    /// Unlike [`select_next`](Self::select_next), the number of items is known, so the selection
+    /// is always valid afterwards. When the last item is selected, the `navigation` decides whether
+    /// the selection stays there ([`ListNavigation::Clamp`]) or moves to the first item
+    /// ([`ListNavigation::Wrap`]). The first item is selected if no item is selected, and nothing
+    /// is selected if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListNavigation, ListState};
+    ///
+    /// let mut state = ListState::default().with_selected(Some(2));
+    /// state.select_next_in(3, ListNavigation::Clamp);
+    /// assert_eq!(state.selected(), Some(2));
+    /// state.select_next_in(3, ListNavigation::Wrap);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn select_next_in(&mut self, item_count: usize, navigation: ListNavigation) {
+        let Some(last) = item_count.checked_sub(1) else {
+            self.select(None);
+            return;
+        };
+        let next = match self.selected.map(|i| i.min(last)) {
+            None => 0,
+            Some(i) if i < last => i + 1,
+            Some(_) => match navigation {
+                ListNavigation::Clamp => last,
+                ListNavigation::Wrap => 0,
+            },
+        };
+        self.select(Some(next));
+    }
+
+    /// Selects the previous item of a list of `item_count` items
+    ///
+    /// Unlike [`select_previous`](Self::select_previous), the number of items is known, so the
+    /// selection is always valid afterwards. When the first item is selected, the `navigation`
+    /// decides whether the selection stays there ([`ListNavigation::Clamp`]) or moves to the last
+    /// item ([`ListNavigation::Wrap`]). The last item is selected if no item is selected, and
+    /// nothing is selected if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ListNavigation, ListState};
+    ///
+    /// let mut state = ListState::default().with_selected(Some(0));
+    /// state.select_previous_in(3, ListNavigation::Clamp);
+    /// assert_eq!(state.selected(), Some(0));
+    /// state.select_previous_in(3, ListNavigation::Wrap);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub fn select_previous_in(&mut self, item_count: usize, navigation: ListNavigation) {
+        let Some(last) = item_count.checked_sub(1) else {
+            self.select(None);
+            return;
+        };
+        let previous = match self.selected.map(|i| i.min(last)) {
+            None => last,
+            Some(i) if i > 0 => i - 1,
+            Some(_) => match navigation {
+                ListNavigation::Clamp => 0,
+                ListNavigation::Wrap => last,
+            },
+        };
+        self.select(Some(previous));
+    }
+
+    /// Selects the item whose key matches `key`, keeping the selection on the same item when the
+    /// items of the list change
+    ///
+    /// When the items are refreshed, rows can be inserted or removed before the selected item so
+    /// that its index points at a different item. Store the key of the selected item before
+    /// updating the items, then call this method with the new items and a function returning the
+    /// key of an item to select the item with that key again.
+    ///
+    /// If no item has the key (e.g. it was removed), the selected index is kept and clamped to the
+    /// new number of items, so the item that took its place (or the new last item) is selected.
+    /// Nothing is selected if there are no items, and the selection is left unchanged if nothing
+    /// was selected and the key is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let items = ["apple", "banana", "cherry"];
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// let key = items[1];
+    ///
+    /// // an item is inserted before the selected one
+    /// let items = ["apple", "apricot", "banana", "cherry"];
+    /// state.select_by_key(&key, &items, |item| *item);
+    /// assert_eq!(state.selected(), Some(2));
+    ///
+    /// // the selected item is removed
+    /// let items = ["apple", "apricot", "cherry"];
+    /// state.select_by_key(&key, &items, |item| *item);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub fn select_by_key<I, K, F>(&mut self, key: &K, items: I, mut key_fn: F)
+    where
+        I: IntoIterator,
+        K: PartialEq,
+        F: FnMut(I::Item) -> K,
+    {
+        let mut item_count = 0;
+        for (index, item) in items.into_iter().enumerate() {
+            if key_fn(item) == *key {
+                self.select(Some(index));
+                return;
+            }
+            item_count = index + 1;
+        }
+        match (self.selected, item_count.checked_sub(1)) {
+            (Some(_), None) => self.select(None),
+            (Some(selected), Some(last)) => self.select(Some(selected.min(last))),
+            (None, _) => {}
+        }
+    }
+
+    /// Indices of the items that are part of the multi-selection, in ascending order
+    ///
+    /// The multi-selection is independent of the [selected item](ListState::selected), which acts
+    /// as the cursor of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle(2);
+    /// state.toggle(0);
+    /// assert_eq!(
+    ///     state.selected_indices().iter().collect::<Vec<_>>(),
+    ///     [&0, &2]
+    /// );
+    /// ```
+    pub const fn selected_indices(&self) -> &BTreeSet<usize> {
+        &self.selected_indices
+    }
+
+    /// Mutable reference to the indices of the items that are part of the multi-selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.selected_indices_mut().extend([1, 3]);
+    /// assert!(state.selected_indices().contains(&3));
+    /// ```
+    pub fn selected_indices_mut(&mut self) -> &mut BTreeSet<usize> {
+        &mut self.selected_indices
+    }
+
+    /// Adds the item at `index` to the multi-selection, or removes it if it is already part of it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle(1);
+    /// assert!(state.selected_indices().contains(&1));
+    /// state.toggle(1);
+    /// assert!(state.selected_indices().is_empty());
+    /// ```
+    pub fn toggle(&mut self, index: usize) {
+        if !self.selected_indices.remove(&index) {
+            self.selected_indices.insert(index);
+        }
+    }
+
+    /// Adds all the items of a list of `item_count` items to the multi-selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.select_all(3);
+    /// assert_eq!(state.selected_indices().len(), 3);
+    /// ```
+    pub fn select_all(&mut self, item_count: usize) {
+        self.selected_indices.extend(0..item_count);
+    }
+
+    /// Removes all the items from the multi-selection
+    ///
+    /// The [selected item](ListState::selected) is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.select_all(3);
+    /// state.clear_selection();
+    /// assert!(state.selected_indices().is_empty());
+    /// ```
+    pub fn clear_selection(&mut self) {
+        self.selected_indices.clear();
+    }
+
     /// Scrolls down by a specified `amount` in the list.
     ///
     /// This method updates the selected index by moving it down by the given `amount`.
@@ -270,13 +626,101 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Scrolls the content of the items left by a specified `amount` of columns.
+    ///
+    /// This reveals the beginning of the items again. The horizontal offset does not go below
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_horizontal_offset(4);
+    /// state.scroll_left(3);
+    /// assert_eq!(state.horizontal_offset(), 1);
+    /// ```
+    pub fn scroll_left(&mut self, amount: u16) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(amount.into());
+    }
+
+    /// Scrolls the content of the items right by a specified `amount` of columns.
+    ///
+    /// This reveals the end of the items that are wider than the list. The horizontal offset is
+    /// clamped to the width of the items when the list is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.scroll_right(3);
+    /// assert_eq!(state.horizontal_offset(), 3);
+    /// ```
+    pub fn scroll_right(&mut self, amount: u16) {
+        self.horizontal_offset = self.horizontal_offset.saturating_add(amount.into());
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::list::{ListNavigation, ListState};
+
+    #[test]
+    fn scroll_left_right() {
+        let mut state = ListState::default();
+        state.scroll_right(3);
+        state.scroll_right(2);
+        assert_eq!(state.horizontal_offset(), 5);
+        state.scroll_left(4);
+        assert_eq!(state.horizontal_offset(), 1);
+        state.scroll_left(4);
+        assert_eq!(state.horizontal_offset(), 0);
+    }
+
+    #[test]
+    fn toggle() {
+        let mut state = ListState::default().with_selected(Some(1));
+        state.toggle(3);
+        state.toggle(0);
+        assert_eq!(
+            state.selected_indices().iter().copied().collect::<Vec<_>>(),
+            [0, 3]
+        );
+
+        state.toggle(3);
+        assert_eq!(
+            state.selected_indices().iter().copied().collect::<Vec<_>>(),
+            [0]
+        );
+        assert_eq!(state.selected(), Some(1));
+    }
+
+    #[rstest]
+    #[case::empty(0, vec![])]
+    #[case::some(3, vec![0, 1, 2])]
+    fn select_all(#[case] item_count: usize, #[case] expected: Vec<usize>) {
+        let mut state = ListState::default();
+        state.select_all(item_count);
+        assert_eq!(
+            state.selected_indices().iter().copied().collect::<Vec<_>>(),
+            expected
+        );
+    }
 
-    use crate::list::ListState;
+    #[test]
+    fn clear_selection() {
+        let mut state = ListState::default().with_selected(Some(2));
+        state.select_all(4);
+        state.clear_selection();
+        assert!(state.selected_indices().is_empty());
+        assert_eq!(state.selected(), Some(2));
+    }
 
     #[test]
     fn selected() {
@@ -356,4 +800,75 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[rstest]
+    #[case::none(None, 3, ListNavigation::Clamp, Some(0))]
+    #[case::middle(Some(1), 3, ListNavigation::Clamp, Some(2))]
+    #[case::last_clamped(Some(2), 3, ListNavigation::Clamp, Some(2))]
+    #[case::last_wrapped(Some(2), 3, ListNavigation::Wrap, Some(0))]
+    #[case::out_of_bounds_clamped(Some(5), 3, ListNavigation::Clamp, Some(2))]
+    #[case::out_of_bounds_wrapped(Some(5), 3, ListNavigation::Wrap, Some(0))]
+    #[case::single_clamped(Some(0), 1, ListNavigation::Clamp, Some(0))]
+    #[case::single_wrapped(Some(0), 1, ListNavigation::Wrap, Some(0))]
+    #[case::empty(Some(0), 0, ListNavigation::Wrap, None)]
+    #[case::empty_none(None, 0, ListNavigation::Clamp, None)]
+    fn select_next_in(
+        #[case] selected: Option<usize>,
+        #[case] item_count: usize,
+        #[case] navigation: ListNavigation,
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = ListState::default().with_selected(selected);
+        state.select_next_in(item_count, navigation);
+        assert_eq!(state.selected, expected);
+    }
+
+    #[rstest]
+    #[case::none(None, 3, ListNavigation::Clamp, Some(2))]
+    #[case::middle(Some(1), 3, ListNavigation::Clamp, Some(0))]
+    #[case::first_clamped(Some(0), 3, ListNavigation::Clamp, Some(0))]
+    #[case::first_wrapped(Some(0), 3, ListNavigation::Wrap, Some(2))]
+    #[case::out_of_bounds(Some(5), 3, ListNavigation::Clamp, Some(1))]
+    #[case::single_clamped(Some(0), 1, ListNavigation::Clamp, Some(0))]
+    #[case::single_wrapped(Some(0), 1, ListNavigation::Wrap, Some(0))]
+    #[case::empty(Some(0), 0, ListNavigation::Wrap, None)]
+    #[case::empty_none(None, 0, ListNavigation::Clamp, None)]
+    fn select_previous_in(
+        #[case] selected: Option<usize>,
+        #[case] item_count: usize,
+        #[case] navigation: ListNavigation,
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = ListState::default().with_selected(selected);
+        state.select_previous_in(item_count, navigation);
+        assert_eq!(state.selected, expected);
+    }
+
+    #[rstest]
+    #[case::moved_down(Some(1), 2, &[1, 9, 2, 3], Some(2))]
+    #[case::moved_up(Some(2), 3, &[3, 4], Some(0))]
+    #[case::unchanged(Some(1), 2, &[1, 2, 3], Some(1))]
+    #[case::removed(Some(1), 2, &[1, 3], Some(1))]
+    #[case::removed_last(Some(2), 3, &[1, 2], Some(1))]
+    #[case::removed_all(Some(0), 1, &[], None)]
+    #[case::not_selected(None, 2, &[1, 2, 3], Some(1))]
+    #[case::not_selected_removed(None, 2, &[1, 3], None)]
+    fn select_by_key(
+        #[case] selected: Option<usize>,
+        #[case] key: u32,
+        #[case] items: &[u32],
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = ListState::default().with_selected(selected);
+        state.select_by_key(&key, items, |item| *item);
+        assert_eq!(state.selected, expected);
+    }
+
+    #[test]
+    fn select_by_key_resets_offset_when_nothing_is_selected() {
+        let mut state = ListState::default().with_selected(Some(0)).with_offset(2);
+        state.select_by_key(&"a", Vec::<&str>::new(), |item| item);
+        assert_eq!(state.selected, None);
+        assert_eq!(state.offset, 0);
+    }
 }
@@ -0,0 +1,180 @@
+//! The [`Padded`] widget adds padding around another widget.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::block::Padding;
+
+/// A widget that renders another widget with some [`Padding`] around it.
+///
+/// The area given to `Padded` is shrunk by the padding and the inner widget is rendered in the
+/// remaining space. Nothing is drawn in the padding itself. This is the non-bordered complement
+/// to [`Block::padding`](crate::block::Block::padding), and avoids having to wrap a widget in an
+/// invisible [`Block`](crate::block::Block) just to get some space around it.
+///
+/// If the padding leaves no room for the inner widget, the inner widget is not rendered.
+///
+/// `Padded` implements [`Widget`] if the inner widget does (also for references), and
+/// [`StatefulWidget`] if the inner widget is a [`StatefulWidget`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Padded, Padding, Paragraph},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let paragraph = Paragraph::new("Hello, world!");
+/// frame.render_widget(Padded::new(paragraph, Padding::uniform(1)), frame.area());
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Padded<W> {
+    widget: W,
+    padding: Padding,
+}
+
+impl<W> Padded<W> {
+    /// Creates a new `Padded` widget rendering `widget` with the given `padding` around it.
+    pub const fn new(widget: W, padding: Padding) -> Self {
+        Self { widget, padding }
+    }
+
+    /// Sets the padding around the inner widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns a reference to the inner widget.
+    pub const fn inner_widget(&self) -> &W {
+        &self.widget
+    }
+
+    /// Consumes the `Padded` widget and returns the inner widget.
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+
+    /// Returns the area in which the inner widget is rendered when `Padded` is rendered in `area`.
+    ///
+    /// The returned area is empty if the padding leaves no room for the inner widget.
+    pub const fn inner(&self, area: Rect) -> Rect {
+        let horizontal = self.padding.left.saturating_add(self.padding.right);
+        let vertical = self.padding.top.saturating_add(self.padding.bottom);
+        if area.width <= horizontal || area.height <= vertical {
+            return Rect {
+                x: area.x,
+                y: area.y,
+                width: 0,
+                height: 0,
+            };
+        }
+        Rect {
+            x: area.x + self.padding.left,
+            y: area.y + self.padding.top,
+            width: area.width - horizontal,
+            height: area.height - vertical,
+        }
+    }
+}
+
+impl<W: Widget> Widget for Padded<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.inner(area);
+        if !inner.is_empty() {
+            self.widget.render(inner, buf);
+        }
+    }
+}
+
+impl<'a, W> Widget for &'a Padded<W>
+where
+    &'a W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.inner(area);
+        if !inner.is_empty() {
+            self.widget.render(inner, buf);
+        }
+    }
+}
+
+impl<W: StatefulWidget> StatefulWidget for Padded<W> {
+    type State = W::State;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let inner = self.inner(area);
+        if !inner.is_empty() {
+            self.widget.render(inner, buf, state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::Line;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::list::{List, ListState};
+
+    #[test]
+    fn render() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Padded::new(Line::from("abcdefg"), Padding::new(2, 1, 1, 0))
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "       ",
+            "  abcd ",
+            "       ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_ref() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let padded = Padded::new(Line::from("abc"), Padding::left(1));
+        (&padded).render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" abc "]));
+    }
+
+    #[test]
+    fn render_stateful() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let mut state = ListState::default().with_offset(1);
+        let list = List::new(["a", "b", "c", "d"]);
+        StatefulWidget::render(
+            Padded::new(list, Padding::uniform(1)),
+            buffer.area,
+            &mut buffer,
+            &mut state,
+        );
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "     ",
+            " b   ",
+            "     ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::too_wide(Padding::horizontal(2), Rect::new(0, 0, 4, 2))]
+    #[case::too_tall(Padding::vertical(1), Rect::new(0, 0, 4, 2))]
+    #[case::empty_area(Padding::ZERO, Rect::new(0, 0, 0, 0))]
+    fn render_nothing_without_room(#[case] padding: Padding, #[case] area: Rect) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Padded::new(Line::from("abcd"), padding).render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 4, 2)));
+        assert!(Padded::new((), padding).inner(area).is_empty());
+    }
+}
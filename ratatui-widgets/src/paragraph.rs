@@ -1,17 +1,20 @@
 //! The [`Paragraph`] widget and related types allows displaying a block of text with optional
 //! wrapping, alignment, and block styling.
+use std::cell::Cell;
+
 use ratatui_core::{
     buffer::Buffer,
     layout::{Alignment, Position, Rect},
     style::{Style, Styled},
-    text::{Line, StyledGrapheme, Text},
-    widgets::Widget,
+    text::{Line, StyledGrapheme, Text, TextDirection, WidthMode},
+    widgets::{StatefulWidget, Widget},
 };
-use unicode_width::UnicodeWidthStr;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
     block::{Block, BlockExt},
-    reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine},
+    reflow::{Grapheme, LineComposer, LineTruncator, WordWrapper, WrappedLine},
+    scrollbar::ScrollbarState,
 };
 
 /// A widget to display some text.
@@ -79,7 +82,7 @@ use crate::{
 /// ```
 ///
 /// [`Span`]: ratatui_core::text::Span
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Paragraph<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -93,6 +96,40 @@ pub struct Paragraph<'a> {
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// Base direction of the text
+    direction: TextDirection,
+    /// Number of columns between tab stops
+    tab_width: u16,
+    /// Whether whitespace is kept at the end of wrapped lines
+    preserve_whitespace: bool,
+    /// The symbol and style used to draw the trailing whitespace of each line
+    trailing_whitespace: Option<(&'a str, Style)>,
+    /// Number of columns the first row of each wrapped line is indented by
+    first_line_indent: u16,
+    /// Number of columns the following rows of each wrapped line are indented by
+    hanging_indent: u16,
+}
+
+/// The default number of columns between tab stops.
+const DEFAULT_TAB_WIDTH: u16 = 8;
+
+impl Default for Paragraph<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            wrap: None,
+            text: Text::default(),
+            scroll: Position::ORIGIN,
+            alignment: Alignment::Left,
+            direction: TextDirection::Ltr,
+            tab_width: DEFAULT_TAB_WIDTH,
+            preserve_whitespace: false,
+            trailing_whitespace: None,
+            first_line_indent: 0,
+            hanging_indent: 0,
+        }
+    }
 }
 
 /// Describes how to wrap text across lines.
@@ -136,6 +173,330 @@ pub struct Wrap {
 type Horizontal = u16;
 type Vertical = u16;
 
+/// The screen regions occupied by each span of a rendered [`Paragraph`].
+///
+/// This is filled in by [`Paragraph::render_with_regions`], which records where each visible
+/// [`Span`] ended up after wrapping, alignment and scrolling were applied. This makes it possible
+/// to map a mouse click back to the span that was clicked, e.g. to implement clickable usernames in
+/// a chat application.
+///
+/// Spans are identified by the index of their line in the paragraph's [`Text`] and the index of
+/// the span within that line. A span that is split across multiple wrapped lines is reported as
+/// multiple regions, one per screen row, in rendering order.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::{Position, Rect},
+///     text::Line,
+///     widgets::{Paragraph, SpanRegions, Wrap},
+/// };
+///
+/// let paragraph = Paragraph::new(Line::from(vec!["<alice> ".into(), "hello world".into()]))
+///     .wrap(Wrap { trim: true });
+/// let mut regions = SpanRegions::new();
+/// let area = Rect::new(0, 0, 10, 3);
+/// let mut buf = Buffer::empty(area);
+/// paragraph.render_with_regions(area, &mut buf, &mut regions);
+///
+/// // the first span is the username
+/// assert_eq!(regions.span_at(Position::new(2, 0)), Some((0, 0)));
+/// ```
+///
+/// [`Span`]: ratatui_core::text::Span
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct SpanRegions {
+    regions: Vec<SpanRegion>,
+}
+
+/// A single row region of the screen occupied by (part of) a span.
+///
+/// See [`SpanRegions`] for more information.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SpanRegion {
+    /// The index of the line containing the span in the paragraph's [`Text`]
+    pub line: usize,
+    /// The index of the span within its line
+    pub span: usize,
+    /// The area occupied by the span on the screen
+    pub area: Rect,
+}
+
+impl SpanRegions {
+    /// Creates a new empty [`SpanRegions`].
+    pub const fn new() -> Self {
+        Self {
+            regions: Vec::new(),
+        }
+    }
+
+    /// Returns an iterator over all the recorded regions in rendering order.
+    pub fn iter(&self) -> std::slice::Iter<'_, SpanRegion> {
+        self.regions.iter()
+    }
+
+    /// Returns the number of recorded regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Returns `true` if no regions were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Returns the `(line, span)` index of the span rendered at the given position, if any.
+    pub fn span_at<P: Into<Position>>(&self, position: P) -> Option<(usize, usize)> {
+        let position = position.into();
+        self.regions
+            .iter()
+            .find(|region| region.area.contains(position))
+            .map(|region| (region.line, region.span))
+    }
+
+    /// Returns the areas occupied by the span at the given `(line, span)` index.
+    ///
+    /// A span that was wrapped over several rows yields one area per row.
+    pub fn areas_of(&self, line: usize, span: usize) -> impl Iterator<Item = Rect> + '_ {
+        self.regions
+            .iter()
+            .filter(move |region| region.line == line && region.span == span)
+            .map(|region| region.area)
+    }
+
+    /// Removes all recorded regions.
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    /// Records a grapheme of the given span at the given area, merging it with the previous
+    /// region if they are adjacent on the same row.
+    fn push(&mut self, line: usize, span: usize, area: Rect) {
+        if let Some(last) = self.regions.last_mut() {
+            if last.line == line
+                && last.span == span
+                && last.area.y == area.y
+                && last.area.right() == area.x
+            {
+                last.area.width += area.width;
+                return;
+            }
+        }
+        self.regions.push(SpanRegion { line, span, area });
+    }
+}
+
+impl<'a> IntoIterator for &'a SpanRegions {
+    type Item = &'a SpanRegion;
+    type IntoIter = std::slice::Iter<'a, SpanRegion>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// State of a [`Paragraph`] rendered with [`Paragraph::with_cursor`], used to place a caret in
+/// the text and to scroll it.
+///
+/// The caret is given as a char offset in the paragraph's text, where each line break counts as
+/// one char (i.e. an offset in the string obtained by joining the lines with `\n`). Offsets past
+/// the end of the text are clamped to the end of the last line.
+///
+/// After rendering, [`ParagraphState::caret_position`] returns the screen position of the caret,
+/// taking wrapping, alignment, scrolling and the [`Block`] into account. It can be passed to
+/// `Frame::set_cursor_position` to show the terminal cursor at the caret. The caret is placed on
+/// the grapheme containing the caret char, or right after the last grapheme of its line when the
+/// caret is at the end of the line. A caret on whitespace that was trimmed when wrapping is
+/// placed at the start of the next row.
+///
+/// The position is `None` when the caret is scrolled out of view (vertically, or horizontally
+/// when the text is not wrapped). When the text is wrapped, a caret past the last column of a
+/// full row is clamped to that column.
+///
+/// # Scrolling
+///
+/// The state can also hold the vertical scroll offset of the paragraph, which then replaces the
+/// vertical offset set with [`Paragraph::scroll`]. The offset is set with
+/// [`ParagraphState::with_scroll_offset`], [`ParagraphState::set_scroll_offset`],
+/// [`ParagraphState::scroll_down`] or [`ParagraphState::scroll_up`].
+///
+/// Each render records the height of the content (after wrapping) and of the viewport in the
+/// state, and clamps the scroll offset so that the last row of the content never scrolls above
+/// the bottom of the viewport. Between renders, [`ParagraphState::scroll_down`] clamps to the
+/// height recorded by the last render. [`ParagraphState::scrollbar_state`] returns a
+/// [`ScrollbarState`] matching the offset, so a [`Scrollbar`] can be rendered next to the
+/// paragraph without keeping the two in sync manually.
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Paragraph, ParagraphState, Scrollbar, ScrollbarOrientation, Wrap},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame, area: Rect) {
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ParagraphState::new();
+/// state.scroll_down(3);
+/// let paragraph = Paragraph::new("a long text...").wrap(Wrap { trim: true });
+/// frame.render_stateful_widget(paragraph.with_cursor(), area, &mut state);
+/// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+/// frame.render_stateful_widget(scrollbar, area, &mut state.scrollbar_state());
+/// # }
+/// ```
+///
+/// [`Scrollbar`]: crate::scrollbar::Scrollbar
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::{Position, Rect},
+///     widgets::{Paragraph, ParagraphState, Wrap},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ParagraphState::new().with_caret(8);
+/// let paragraph = Paragraph::new("Hello, world!").wrap(Wrap { trim: true });
+/// frame.render_stateful_widget(paragraph.with_cursor(), Rect::new(0, 0, 7, 2), &mut state);
+/// if let Some(position) = state.caret_position() {
+///     // "world!" is wrapped to the second row and the caret is on the "o"
+///     assert_eq!(position, Position::new(1, 1));
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphState {
+    caret: usize,
+    caret_position: Option<Position>,
+    scroll_offset: Option<u16>,
+    content_height: usize,
+    viewport_height: u16,
+}
+
+impl ParagraphState {
+    /// Creates a new [`ParagraphState`] with the caret at the start of the text.
+    pub const fn new() -> Self {
+        Self {
+            caret: 0,
+            caret_position: None,
+            scroll_offset: None,
+            content_height: 0,
+            viewport_height: 0,
+        }
+    }
+
+    /// Sets the caret char offset.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_caret(mut self, caret: usize) -> Self {
+        self.caret = caret;
+        self
+    }
+
+    /// Returns the caret char offset.
+    pub const fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Sets the caret char offset.
+    pub fn set_caret(&mut self, caret: usize) {
+        self.caret = caret;
+    }
+
+    /// Returns the screen position of the caret computed by the last render, or `None` if the
+    /// caret was not visible.
+    pub const fn caret_position(&self) -> Option<Position> {
+        self.caret_position
+    }
+
+    /// Sets the vertical scroll offset, replacing the one set with [`Paragraph::scroll`].
+    ///
+    /// The offset is clamped to [`ParagraphState::max_scroll_offset`] when the paragraph is
+    /// rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_scroll_offset(mut self, offset: u16) -> Self {
+        self.scroll_offset = Some(offset);
+        self
+    }
+
+    /// Returns the vertical scroll offset, or 0 if no offset was set on the state.
+    pub const fn scroll_offset(&self) -> u16 {
+        match self.scroll_offset {
+            Some(offset) => offset,
+            None => 0,
+        }
+    }
+
+    /// Sets the vertical scroll offset, replacing the one set with [`Paragraph::scroll`].
+    ///
+    /// The offset is clamped to [`ParagraphState::max_scroll_offset`] when the paragraph is
+    /// rendered.
+    pub fn set_scroll_offset(&mut self, offset: u16) {
+        self.scroll_offset = Some(offset);
+    }
+
+    /// Scrolls down by the given number of rows, up to [`ParagraphState::max_scroll_offset`].
+    pub fn scroll_down(&mut self, amount: u16) {
+        let offset = self
+            .scroll_offset()
+            .saturating_add(amount)
+            .min(self.max_scroll_offset());
+        self.scroll_offset = Some(offset);
+    }
+
+    /// Scrolls up by the given number of rows, stopping at the top of the content.
+    pub fn scroll_up(&mut self, amount: u16) {
+        self.scroll_offset = Some(self.scroll_offset().saturating_sub(amount));
+    }
+
+    /// Returns the number of rows of the content after wrapping, as of the last render.
+    ///
+    /// This does not include the rows of the [`Block`].
+    pub const fn content_height(&self) -> usize {
+        self.content_height
+    }
+
+    /// Returns the number of rows the content was rendered in by the last render.
+    pub const fn viewport_height(&self) -> u16 {
+        self.viewport_height
+    }
+
+    /// Returns the largest scroll offset that keeps the viewport filled with content, as of the
+    /// last render.
+    pub fn max_scroll_offset(&self) -> u16 {
+        let max = self
+            .content_height
+            .saturating_sub(usize::from(self.viewport_height));
+        u16::try_from(max).unwrap_or(u16::MAX)
+    }
+
+    /// Returns a [`ScrollbarState`] matching the scroll offset and the heights recorded by the last
+    /// render.
+    ///
+    /// The thumb of a [`Scrollbar`](crate::scrollbar::Scrollbar) rendered with this state is
+    /// proportional to the part of the content that is visible, and reaches the end of the track
+    /// when the paragraph is scrolled to the bottom. The state is empty (and the scrollbar not
+    /// rendered) before the paragraph is first rendered or when it has no content.
+    pub fn scrollbar_state(&self) -> ScrollbarState {
+        if self.content_height == 0 {
+            return ScrollbarState::default();
+        }
+        ScrollbarState::new(usize::from(self.max_scroll_offset()) + 1)
+            .position(usize::from(self.scroll_offset()))
+            .viewport_content_length(usize::from(self.viewport_height))
+    }
+}
+
 impl<'a> Paragraph<'a> {
     /// Creates a new [`Paragraph`] widget with the given text.
     ///
@@ -168,6 +529,12 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             scroll: Position::ORIGIN,
             alignment: Alignment::Left,
+            direction: TextDirection::Ltr,
+            tab_width: DEFAULT_TAB_WIDTH,
+            preserve_whitespace: false,
+            trailing_whitespace: None,
+            first_line_indent: 0,
+            hanging_indent: 0,
         }
     }
 
@@ -267,6 +634,156 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets the base direction of the text.
+    ///
+    /// Defaults to [`TextDirection::Ltr`]. With [`TextDirection::Rtl`], each row is reordered for
+    /// display using the Unicode Bidirectional Algorithm with a right-to-left base direction, so
+    /// right-to-left text such as Arabic or Hebrew, stored in logical order, reads correctly:
+    /// the first character of a row is drawn rightmost, and runs of left-to-right text (e.g. Latin
+    /// words or numbers) keep their order within the row. Wrapping and alignment are not affected,
+    /// so right-to-left text is usually combined with [`Paragraph::right_aligned`].
+    ///
+    /// Lines with their own [`Line::direction`] are rendered in that direction instead. The caret
+    /// position of [`ParagraphState`] does not take the reordering into account. See
+    /// [`TextDirection`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{text::TextDirection, widgets::Paragraph};
+    ///
+    /// // renders "םולש"
+    /// let paragraph = Paragraph::new("שלום")
+    ///     .direction(TextDirection::Rtl)
+    ///     .right_aligned();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the number of columns between tab stops.
+    ///
+    /// Tab characters (`\t`) are expanded to spaces up to the next tab stop. Tab stops are counted
+    /// in display columns from the start of each line of the text, so wrapping a line does not
+    /// change where its tabs end. Defaults to 8. A width of 0 removes tab characters instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("name\tsize\nfoo.rs\t12K").tab_width(4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tab_width(mut self, width: u16) -> Self {
+        self.tab_width = width;
+        self
+    }
+
+    /// Keeps the whitespace at the points where the text is wrapped.
+    ///
+    /// By default, the whitespace between the last word of a wrapped row and the first word of
+    /// the next row is removed as far as it would fit at the end of the row. When preserved, this
+    /// whitespace is kept at the end of the row instead, so that every row shows the whitespace
+    /// following its last word. The whitespace that doesn't fit in the row is handled the same way
+    /// in both cases: it starts the next row, unless leading whitespace is trimmed (see
+    /// [`Wrap::trim`]).
+    ///
+    /// The whitespace at the end of a line of the text is always kept, and is wrapped the same way
+    /// when it doesn't fit. This has no effect when the text is not wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// // renders "ab  " and "cd" in a width of 4 instead of "ab" and "cd"
+    /// let paragraph = Paragraph::new("ab  cd")
+    ///     .wrap(Wrap { trim: true })
+    ///     .preserve_whitespace(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn preserve_whitespace(mut self, preserve: bool) -> Self {
+        self.preserve_whitespace = preserve;
+        self
+    }
+
+    /// Draws the trailing whitespace of each line with the given symbol and style.
+    ///
+    /// The trailing whitespace of a line is the whitespace following its last non-whitespace
+    /// character (or the whole line if it only contains whitespace), including the spaces tabs
+    /// are expanded to. Each whitespace cell is drawn with `symbol` (e.g. `"·"`) and its style is
+    /// patched with `style`. Whitespace characters that are not as wide as `symbol` are only
+    /// styled. This makes trailing whitespace visible, e.g. to highlight whitespace errors.
+    ///
+    /// Only the trailing whitespace of the lines of the text is drawn this way, not the whitespace
+    /// at the end of wrapped rows (see [`Paragraph::preserve_whitespace`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Color, widgets::Paragraph};
+    ///
+    /// // renders "let x = 1;··" with red dots
+    /// let paragraph = Paragraph::new("let x = 1;  ").trailing_whitespace("·", Color::Red);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn trailing_whitespace<S: Into<Style>>(mut self, symbol: &'a str, style: S) -> Self {
+        self.trailing_whitespace = Some((symbol, style.into()));
+        self
+    }
+
+    /// Indents the rows following the first row of each wrapped line by the given number of
+    /// columns.
+    ///
+    /// The indented rows have `indent` fewer columns for the text, so the line is wrapped to a
+    /// narrower width after its first row. Combined with [`Paragraph::first_line_indent`], this
+    /// lays out bullet lists and quotes where the wrapped text lines up after the bullet. At least
+    /// one column is always left for the text.
+    ///
+    /// This has no effect when the text is not wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// // renders "- item text" and "  that wraps" in a width of 12
+    /// let paragraph = Paragraph::new("- item text that wraps")
+    ///     .wrap(Wrap { trim: true })
+    ///     .hanging_indent(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hanging_indent(mut self, indent: u16) -> Self {
+        self.hanging_indent = indent;
+        self
+    }
+
+    /// Indents the first row of each wrapped line by the given number of columns.
+    ///
+    /// The first row has `indent` fewer columns for the text. Use [`Paragraph::hanging_indent`] to
+    /// indent the following rows instead. At least one column is always left for the text.
+    ///
+    /// This has no effect when the text is not wrapped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// // renders "    Lorem ipsum" and "dolor sit amet" in a width of 15
+    /// let paragraph = Paragraph::new("Lorem ipsum dolor sit amet")
+    ///     .wrap(Wrap { trim: true })
+    ///     .first_line_indent(4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn first_line_indent(mut self, indent: u16) -> Self {
+        self.first_line_indent = indent;
+        self
+    }
+
     /// Left-aligns the text in the given paragraph.
     ///
     /// Convenience shortcut for `Paragraph::alignment(Alignment::Left)`.
@@ -323,6 +840,11 @@ impl<'a> Paragraph<'a> {
     ///
     /// This method will also account for the [`Block`] if one is set through [`Self::block`].
     ///
+    /// The lines are wrapped exactly like when rendering, so the count can be used to clamp the
+    /// offset passed to [`Self::scroll`] or as the content length of a [`ScrollbarState`].
+    ///
+    /// [`ScrollbarState`]: crate::scrollbar::ScrollbarState
+    ///
     /// Note: The design for text wrapping is not stable and might affect this API.
     ///
     /// # Example
@@ -350,26 +872,7 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
-            }
-            count
-        } else {
-            self.text.height()
-        };
-
-        count
+        self.text_height(width, WidthMode::current())
             .saturating_add(top as usize)
             .saturating_add(bottom as usize)
     }
@@ -396,7 +899,18 @@ impl<'a> Paragraph<'a> {
         issue = "https://github.com/ratatui/ratatui/issues/293"
     )]
     pub fn line_width(&self) -> usize {
-        let width = self.text.iter().map(Line::width).max().unwrap_or_default();
+        let width_mode = WidthMode::current();
+        let width = self
+            .text
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                expand_tabs(index, line, Style::default(), self.tab_width, width_mode)
+                    .map(|grapheme| width_mode.width(grapheme.grapheme.symbol))
+                    .sum::<usize>()
+            })
+            .max()
+            .unwrap_or_default();
         let (left, right) = self
             .block
             .as_ref()
@@ -407,6 +921,111 @@ impl<'a> Paragraph<'a> {
             .saturating_add(left as usize)
             .saturating_add(right as usize)
     }
+
+    /// Renders the paragraph and records the screen regions occupied by each of its spans.
+    ///
+    /// This renders exactly like the [`Widget`] implementation, and additionally replaces the
+    /// contents of `regions` with the area of every visible span. This can be used to find which
+    /// span was clicked after the text has been wrapped, aligned and scrolled. See [`SpanRegions`]
+    /// for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{Paragraph, SpanRegions},
+    /// };
+    ///
+    /// let area = Rect::new(0, 0, 20, 1);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut regions = SpanRegions::new();
+    /// Paragraph::new("Hello, world!").render_with_regions(area, &mut buf, &mut regions);
+    /// assert_eq!(regions.span_at((0, 0)), Some((0, 0)));
+    /// ```
+    pub fn render_with_regions(&self, area: Rect, buf: &mut Buffer, regions: &mut SpanRegions) {
+        regions.clear();
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.render_paragraph(inner, buf, Some(regions), None, None);
+    }
+
+    /// Renders the paragraph and calls `visitor` after drawing each row of text.
+    ///
+    /// This renders exactly like the [`Widget`] implementation, and additionally calls
+    /// `visitor(line, area, buf)` once per rendered row, from top to bottom, right after the row
+    /// is drawn. `line` is the index of the source line in the paragraph's [`Text`] and `area` is
+    /// the row of the text area (inside the block) that was drawn, one cell tall and spanning the
+    /// full width of the text area regardless of the alignment or width of the line. When a line
+    /// is wrapped, the visitor is called for each of its rows with the same `line` index. Rows that
+    /// are scrolled out of view are not visited.
+    ///
+    /// This can be used to decorate lines after they are drawn, for example to paint the
+    /// background of the rows of a line or to draw a gutter symbol next to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     style::{Color, Style},
+    ///     text::Text,
+    ///     widgets::Paragraph,
+    /// };
+    ///
+    /// let area = Rect::new(0, 0, 10, 2);
+    /// let mut buf = Buffer::empty(area);
+    /// let diff = Text::from_iter(["+added", "-removed"]);
+    /// let colors = [Color::Green, Color::Red];
+    /// Paragraph::new(diff).render_with_line_visitor(area, &mut buf, |line, area, buf| {
+    ///     buf.set_style(area, Style::new().bg(colors[line]));
+    /// });
+    /// assert_eq!(buf[(9, 1)].bg, Color::Red);
+    /// ```
+    pub fn render_with_line_visitor<F>(&self, area: Rect, buf: &mut Buffer, mut visitor: F)
+    where
+        F: FnMut(usize, Rect, &mut Buffer),
+    {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.render_paragraph(inner, buf, None, None, Some(&mut visitor));
+    }
+
+    /// Returns a [`StatefulWidget`] that renders the paragraph with a [`ParagraphState`].
+    ///
+    /// The state places a caret in the text and scrolls it. See [`ParagraphState`] for more
+    /// information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Rect,
+    ///     widgets::{Paragraph, ParagraphState},
+    ///     Frame,
+    /// };
+    ///
+    /// # fn ui(frame: &mut Frame, area: Rect) {
+    /// let mut state = ParagraphState::new().with_caret(3);
+    /// let paragraph = Paragraph::new("Hello, world!");
+    /// frame.render_stateful_widget(paragraph.with_cursor(), area, &mut state);
+    /// # }
+    /// ```
+    pub const fn with_cursor(&self) -> ParagraphWithCursor<'_, 'a> {
+        ParagraphWithCursor { paragraph: self }
+    }
+}
+
+/// A [`Paragraph`] rendered with a [`ParagraphState`].
+///
+/// This is created by [`Paragraph::with_cursor`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ParagraphWithCursor<'p, 'a> {
+    paragraph: &'p Paragraph<'a>,
 }
 
 impl Widget for Paragraph<'_> {
@@ -420,68 +1039,642 @@ impl Widget for &Paragraph<'_> {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        self.render_paragraph(inner, buf, None, None, None);
+    }
+}
+
+impl StatefulWidget for ParagraphWithCursor<'_, '_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let paragraph = self.paragraph;
+        buf.set_style(area, paragraph.style);
+        paragraph.block.as_ref().render(area, buf);
+        let inner = paragraph.block.inner_if_some(area);
+        paragraph.render_paragraph(inner, buf, None, Some(state), None);
     }
 }
 
 impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+    /// The number of rows of the text when rendered with the given width, excluding the block.
+    fn text_height(&self, width: u16, width_mode: WidthMode) -> usize {
+        if let Some(Wrap { trim }) = self.wrap {
+            let styled = self.text.iter().enumerate().map(|(index, line)| {
+                let graphemes = expand_tabs(index, line, self.style, self.tab_width, width_mode);
+                let alignment = line.alignment.unwrap_or(self.alignment);
+                (graphemes, alignment)
+            });
+            let mut line_composer = WordWrapper::new(styled, width, trim);
+            line_composer.set_width_mode(width_mode);
+            line_composer.set_preserve_whitespace(self.preserve_whitespace);
+            line_composer.set_indent(self.first_line_indent, self.hanging_indent);
+            let mut count = 0;
+            while line_composer.next_line().is_some() {
+                count += 1;
+            }
+            count
+        } else {
+            self.text.height()
+        }
+    }
+
+    /// Records the viewport in the state and returns the vertical scroll offset to render with.
+    fn update_scroll(
+        &self,
+        state: &mut ParagraphState,
+        text_area: Rect,
+        width_mode: WidthMode,
+    ) -> u16 {
+        state.viewport_height = text_area.height;
+        // wrapped rows are counted while rendering, unless the height is needed to clamp the
+        // scroll offset beforehand
+        if state.scroll_offset.is_some() || self.wrap.is_none() {
+            state.content_height = self.text_height(text_area.width, width_mode);
+        }
+        let Some(offset) = state.scroll_offset else {
+            return self.scroll.y;
+        };
+        let offset = offset.min(state.max_scroll_offset());
+        state.scroll_offset = Some(offset);
+        offset
+    }
+
+    fn render_paragraph(
+        &self,
+        text_area: Rect,
+        buf: &mut Buffer,
+        regions: Option<&mut SpanRegions>,
+        mut state: Option<&mut ParagraphState>,
+        visitor: Option<&mut VisitLine>,
+    ) {
         if text_area.is_empty() {
+            if let Some(state) = state {
+                state.caret_position = None;
+            }
             return;
         }
 
-        buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
-            let alignment = line.alignment.unwrap_or(self.alignment);
-            (graphemes, alignment)
+        let width_mode = WidthMode::current();
+        let scroll_y = state.as_deref_mut().map_or(self.scroll.y, |state| {
+            self.update_scroll(state, text_area, width_mode)
         });
 
+        buf.set_style(text_area, self.style);
+        // the number of lines read by the line composer, used to find the line of each row
+        let lines_read = Cell::new(0);
+        let styled = self
+            .text
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let graphemes =
+                    expand_tabs(index, line, self.text.style, self.tab_width, width_mode);
+                let alignment = line.alignment.unwrap_or(self.alignment);
+                (graphemes, alignment)
+            })
+            .inspect(|_| lines_read.set(lines_read.get() + 1));
+        let first_row = usize::from(scroll_y);
+        let mut trackers = RowTrackers {
+            regions,
+            caret: state.as_ref().map(|state| {
+                let caret = CaretTracker::new(
+                    &self.text,
+                    state.caret,
+                    self.tab_width,
+                    width_mode,
+                    &lines_read,
+                );
+                (caret, first_row)
+            }),
+            marker: self.trailing_whitespace.map(|(symbol, style)| {
+                TrailingWhitespace::new(&self.text, symbol, style, width_mode)
+            }),
+            visitor: visitor.map(|visit| LineVisitor {
+                lines_read: &lines_read,
+                visit,
+            }),
+        };
+
         if let Some(Wrap { trim }) = self.wrap {
-            let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
+            let mut word_wrapper = WordWrapper::new(styled, text_area.width, trim);
+            word_wrapper.set_width_mode(width_mode);
+            word_wrapper.set_preserve_whitespace(self.preserve_whitespace);
+            word_wrapper.set_indent(self.first_line_indent, self.hanging_indent);
+            let mut line_composer = RowCounter {
+                composer: word_wrapper,
+                rows: 0,
+            };
             // compute the lines iteratively until we reach the desired scroll offset.
-            for _ in 0..self.scroll.y {
-                if line_composer.next_line().is_none() {
-                    return;
+            for row in 0..first_row {
+                let Some(wrapped) = line_composer.next_line() else {
+                    break;
+                };
+                if let Some((caret, _)) = trackers.caret.as_mut() {
+                    caret.row(&wrapped, row, text_area.width);
+                }
+            }
+            render_lines(
+                &mut line_composer,
+                text_area,
+                buf,
+                &self.text.lines,
+                self.direction,
+                width_mode,
+                &mut trackers,
+            );
+            if let Some(state) = state.as_deref_mut() {
+                if state.scroll_offset.is_none() {
+                    while line_composer.next_line().is_some() {}
+                    state.content_height = line_composer.rows;
                 }
             }
-            render_lines(line_composer, text_area, buf);
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
-            let lines = styled.skip(self.scroll.y as usize);
+            let lines = styled.skip(first_row);
             let mut line_composer = LineTruncator::new(lines, text_area.width);
+            line_composer.set_width_mode(width_mode);
             line_composer.set_horizontal_offset(self.scroll.x);
-            render_lines(line_composer, text_area, buf);
+            if let Some((caret, _)) = trackers.caret.as_mut() {
+                caret.horizontal_offset = Some(self.scroll.x);
+            }
+            render_lines(
+                &mut line_composer,
+                text_area,
+                buf,
+                &self.text.lines,
+                self.direction,
+                width_mode,
+                &mut trackers,
+            );
+        }
+
+        if let (Some(state), Some((caret, _))) = (state, trackers.caret) {
+            state.caret_position = caret.position(text_area, first_row, self.wrap.is_some());
+        }
+    }
+}
+
+/// A grapheme of the text of a paragraph, along with the span it comes from.
+///
+/// The line composers carry it through wrapping and truncation, so each rendered grapheme can be
+/// mapped back to its position in the [`Text`].
+#[derive(Debug, Clone)]
+struct TextGrapheme<'a> {
+    grapheme: StyledGrapheme<'a>,
+    /// The index of the line of the grapheme in the text
+    line: usize,
+    /// The index of the span of the grapheme in its line
+    span: usize,
+    /// The byte offset of the grapheme in the content of its span
+    offset: usize,
+}
+
+impl<'a> Grapheme<'a> for TextGrapheme<'a> {
+    fn styled(&self) -> &StyledGrapheme<'a> {
+        &self.grapheme
+    }
+
+    fn set_symbol(&mut self, symbol: &'a str) {
+        self.grapheme.symbol = symbol;
+    }
+}
+
+/// Returns the graphemes of the line at index `line_index` with tab characters expanded to the
+/// next tab stop.
+///
+/// This is the same as [`Line::styled_graphemes`] except for tabs, which are replaced by as many
+/// spaces as needed to reach the next multiple of `tab_width` columns (or removed when `tab_width`
+/// is 0). Other control characters are removed. The spaces a tab is expanded to belong to the span
+/// of the tab.
+fn expand_tabs<'a>(
+    line_index: usize,
+    line: &'a Line<'a>,
+    base_style: Style,
+    tab_width: u16,
+    width_mode: WidthMode,
+) -> impl Iterator<Item = TextGrapheme<'a>> {
+    let style = base_style.patch(line.style);
+    let tab_width = usize::from(tab_width);
+    let mut column = 0;
+    line.spans
+        .iter()
+        .enumerate()
+        .flat_map(move |(span_index, span)| {
+            let style = style.patch(span.style);
+            span.content
+                .grapheme_indices(true)
+                .map(move |(offset, symbol)| TextGrapheme {
+                    grapheme: StyledGrapheme { symbol, style },
+                    line: line_index,
+                    span: span_index,
+                    offset,
+                })
+        })
+        .flat_map(move |grapheme| {
+            let symbol = grapheme.grapheme.symbol;
+            let (symbol, count) = if symbol == "\t" && tab_width > 0 {
+                (" ", tab_width - column % tab_width)
+            } else if symbol.contains(char::is_control) {
+                (symbol, 0)
+            } else {
+                (symbol, 1)
+            };
+            column += width_mode.width(symbol) * count;
+            let mut grapheme = grapheme;
+            grapheme.set_symbol(symbol);
+            std::iter::repeat(grapheme).take(count)
+        })
+}
+
+/// Finds the screen position of a caret given as a char offset in the text of a paragraph.
+///
+/// The line each row belongs to is found by counting the lines read by the line composer, which
+/// only reads the next line once all the rows of the current one were returned.
+struct CaretTracker<'t> {
+    /// The index of the line containing the caret
+    line: usize,
+    /// The span index and byte offset of the caret char, or `None` at the end of the line
+    target: Option<(usize, usize)>,
+    /// The number of lines read by the line composer
+    lines_read: &'t Cell<usize>,
+    /// The horizontal scroll offset, if the rows are truncated instead of wrapped
+    horizontal_offset: Option<u16>,
+    /// The column of the caret char in its line once tabs are expanded
+    column: usize,
+    /// Whether the caret char is drawn (i.e. it is neither a tab nor a control character)
+    drawn: bool,
+    width_mode: WidthMode,
+    /// The column and row of the caret found so far
+    position: Option<(u16, usize)>,
+    /// Whether a grapheme before the caret was seen
+    after_grapheme: bool,
+    /// Whether the final position of the caret was found (`position` is `None` if it is hidden)
+    found: bool,
+}
+
+impl<'t> CaretTracker<'t> {
+    fn new(
+        text: &Text<'_>,
+        caret: usize,
+        tab_width: u16,
+        width_mode: WidthMode,
+        lines_read: &'t Cell<usize>,
+    ) -> Self {
+        let mut remaining = caret;
+        let mut line = text.lines.len().saturating_sub(1);
+        let mut column = usize::MAX;
+        for (index, text_line) in text.iter().enumerate() {
+            let width = text_line
+                .iter()
+                .map(|span| span.content.chars().count())
+                .sum::<usize>();
+            if remaining <= width {
+                line = index;
+                column = remaining;
+                break;
+            }
+            // skip the line and its line break
+            remaining -= width + 1;
+        }
+
+        let mut target = None;
+        if let Some(text_line) = text.lines.get(line) {
+            for (index, span) in text_line.iter().enumerate() {
+                match span.content.char_indices().nth(column) {
+                    Some((offset, _)) => {
+                        target = Some((index, offset));
+                        break;
+                    }
+                    None => column -= span.content.chars().count(),
+                }
+            }
+        }
+
+        // find the column of the caret the same way `expand_tabs` lays out the line
+        let tab_width = usize::from(tab_width);
+        let mut column = 0;
+        let mut drawn = false;
+        let graphemes = text.lines.get(line).into_iter().flat_map(|line| {
+            line.iter().enumerate().flat_map(|(index, span)| {
+                span.content
+                    .grapheme_indices(true)
+                    .map(move |(offset, symbol)| (index, offset, symbol))
+            })
+        });
+        for (index, offset, symbol) in graphemes {
+            let end = offset + symbol.len();
+            if target.is_some_and(|(span, target)| span < index || (span == index && target < end))
+            {
+                drawn = symbol != "\t" && !symbol.contains(char::is_control);
+                break;
+            }
+            column += match symbol {
+                "\t" if tab_width > 0 => tab_width - column % tab_width,
+                _ if symbol.contains(char::is_control) => 0,
+                _ => width_mode.width(symbol),
+            };
+        }
+        Self {
+            line,
+            target,
+            lines_read,
+            horizontal_offset: None,
+            column,
+            drawn,
+            width_mode,
+            position: None,
+            after_grapheme: false,
+            found: false,
+        }
+    }
+
+    /// Returns `true` once the rows following the caret don't need to be inspected anymore.
+    fn is_done(&self) -> bool {
+        self.found || self.lines_read.get() > self.line + 1
+    }
+
+    /// Inspects a row of the paragraph, `row` being its index from the first (scrolled) row.
+    fn row(&mut self, wrapped: &WrappedLine<'_, TextGrapheme<'_>>, row: usize, width: u16) {
+        if self.found || self.lines_read.get() != self.line + 1 {
+            return;
+        }
+        let line_offset = get_wrapped_line_offset(wrapped, width);
+        let mut x = line_offset;
+        // the first row of the line, or the caret was after trimmed whitespace at the end of the
+        // previous row
+        if self.position.map_or(true, |(column, _)| column >= width) {
+            self.position = Some((x, row));
+        }
+        for grapheme in wrapped.graphemes {
+            let symbol = grapheme.grapheme.symbol;
+            let grapheme_width = u16::try_from(self.width_mode.width(symbol)).unwrap_or(u16::MAX);
+            if grapheme_width == 0 {
+                continue;
+            }
+            let (span, offset) = (grapheme.span, grapheme.offset);
+            let end = offset + symbol.len();
+            let is_before = self.target.map_or(true, |(target_span, target_offset)| {
+                span < target_span || (span == target_span && end <= target_offset)
+            });
+            if is_before {
+                self.position = Some((x + grapheme_width, row));
+                self.after_grapheme = true;
+            } else {
+                let contains = self.target.is_some_and(|(target_span, target_offset)| {
+                    span == target_span && offset <= target_offset
+                });
+                if contains {
+                    self.position = Some((x, row));
+                } else if !self.after_grapheme && self.horizontal_offset > Some(0) {
+                    // scrolled out of view to the left
+                    self.position = None;
+                } else {
+                    // the caret char wasn't rendered (e.g. a tab), so it sits after the
+                    // previous grapheme
+                }
+                self.found = true;
+                return;
+            }
+            x += grapheme_width;
+        }
+        // a truncated row holds the whole line, so the caret char was either cut off or is not
+        // drawn itself (tab or control character)
+        if let (Some(horizontal_offset), Some(_)) = (self.horizontal_offset, self.target) {
+            let offset = if wrapped.alignment == Alignment::Left {
+                usize::from(horizontal_offset)
+            } else {
+                0
+            };
+            self.position = match self.column.checked_sub(offset) {
+                Some(column) if !self.drawn => {
+                    let column = u16::try_from(column).unwrap_or(u16::MAX);
+                    Some((line_offset.saturating_add(column), row))
+                }
+                _ => None,
+            };
+            self.found = true;
+        }
+    }
+
+    /// Returns the screen position of the caret, or `None` if it is not visible.
+    fn position(&self, area: Rect, first_row: usize, wrap: bool) -> Option<Position> {
+        let (x, row) = self.position?;
+        let y = row.checked_sub(first_row)?;
+        if y >= usize::from(area.height) {
+            return None;
         }
+        let x = if x < area.width {
+            x
+        } else if wrap {
+            area.width - 1
+        } else {
+            return None;
+        };
+        Some(Position::new(area.x + x, area.y + y as u16))
     }
 }
 
-fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
+/// Replaces the trailing whitespace of each line of a paragraph with a visible symbol.
+///
+/// A grapheme is trailing whitespace when it comes after the last non-whitespace grapheme of its
+/// line, which is found from the span and offset the grapheme carries. The spaces a tab is
+/// expanded to share the position of the tab.
+struct TrailingWhitespace<'t, 'a> {
+    text: &'t Text<'a>,
+    symbol: &'a str,
+    style: Style,
+    /// The line of the last marked grapheme
+    line: Option<usize>,
+    /// The span index and byte offset of the last non-whitespace grapheme of `line`
+    last_word: Option<(usize, usize)>,
+    width_mode: WidthMode,
+}
+
+impl<'t, 'a> TrailingWhitespace<'t, 'a> {
+    const fn new(text: &'t Text<'a>, symbol: &'a str, style: Style, width_mode: WidthMode) -> Self {
+        Self {
+            text,
+            symbol,
+            style,
+            line: None,
+            last_word: None,
+            width_mode,
+        }
+    }
+
+    /// Returns the symbol and style to draw a grapheme with.
+    fn mark(&mut self, grapheme: &TextGrapheme<'a>) -> (&'a str, Style) {
+        if self.line != Some(grapheme.line) {
+            self.line = Some(grapheme.line);
+            self.last_word = self.text.lines.get(grapheme.line).and_then(|line| {
+                line.iter()
+                    .enumerate()
+                    .rev()
+                    .flat_map(|(index, span)| {
+                        span.content
+                            .grapheme_indices(true)
+                            .rev()
+                            .map(move |(offset, symbol)| (index, offset, symbol))
+                    })
+                    .find(|&(_, _, symbol)| {
+                        !StyledGrapheme::new(symbol, Style::default()).is_whitespace()
+                    })
+                    .map(|(index, offset, _)| (index, offset))
+            });
+        }
+        let StyledGrapheme { symbol, style } = grapheme.grapheme;
+        let position = (grapheme.span, grapheme.offset);
+        let trailing = match self.last_word {
+            Some(last_word) => position > last_word,
+            None => true,
+        };
+        if !trailing || !grapheme.grapheme.is_whitespace() {
+            return (symbol, style);
+        }
+        let style = style.patch(self.style);
+        if self.width_mode.width(symbol) == self.width_mode.width(self.symbol) {
+            (self.symbol, style)
+        } else {
+            (symbol, style)
+        }
+    }
+}
+
+/// Counts the rows returned by a line composer.
+struct RowCounter<C> {
+    composer: C,
+    rows: usize,
+}
+
+impl<'a, G: Grapheme<'a>, C: LineComposer<'a, G>> LineComposer<'a, G> for RowCounter<C> {
+    fn next_line(&mut self) -> Option<WrappedLine<'_, G>> {
+        let line = self.composer.next_line();
+        if line.is_some() {
+            self.rows += 1;
+        }
+        line
+    }
+}
+
+/// The callback of [`Paragraph::render_with_line_visitor`].
+type VisitLine<'v> = dyn FnMut(usize, Rect, &mut Buffer) + 'v;
+
+/// Calls a visitor with the source line of each rendered row.
+struct LineVisitor<'t, 'v> {
+    /// The number of lines read by the line composer
+    lines_read: &'t Cell<usize>,
+    visit: &'v mut VisitLine<'v>,
+}
+
+impl LineVisitor<'_, '_> {
+    /// Called after rendering each row of the paragraph.
+    fn visit(&mut self, row: Rect, buf: &mut Buffer) {
+        let line = self.lines_read.get().saturating_sub(1);
+        (self.visit)(line, row, buf);
+    }
+}
+
+/// The optional trackers updated with each row drawn by [`render_lines`].
+struct RowTrackers<'t, 'a, 'v> {
+    regions: Option<&'t mut SpanRegions>,
+    /// The caret tracker and the index of the first drawn row
+    caret: Option<(CaretTracker<'t>, usize)>,
+    marker: Option<TrailingWhitespace<'t, 'a>>,
+    visitor: Option<LineVisitor<'t, 'v>>,
+}
+
+fn render_lines<'a, C: LineComposer<'a, TextGrapheme<'a>>>(
+    composer: &mut C,
+    area: Rect,
+    buf: &mut Buffer,
+    lines: &[Line],
+    direction: TextDirection,
+    width_mode: WidthMode,
+    trackers: &mut RowTrackers<'_, 'a, '_>,
+) {
     let mut y = 0;
     while let Some(ref wrapped) = composer.next_line() {
-        render_line(wrapped, area, buf, y);
+        if let Some((caret, first_row)) = trackers.caret.as_mut() {
+            caret.row(wrapped, *first_row + usize::from(y), area.width);
+        }
+        // each row holds the graphemes of a single line
+        let direction = wrapped
+            .graphemes
+            .first()
+            .and_then(|grapheme| lines[grapheme.line].direction)
+            .unwrap_or(direction);
+        render_line(wrapped, area, buf, y, direction, width_mode, trackers);
+        if let Some(visitor) = trackers.visitor.as_mut() {
+            visitor.visit(Rect::new(area.x, area.y + y, area.width, 1), buf);
+        }
         y += 1;
         if y >= area.height {
             break;
         }
     }
+    // keep inspecting the rows of the caret line to know whether the caret is below the area
+    if let Some((caret, first_row)) = trackers.caret.as_mut() {
+        let mut row = *first_row + usize::from(y);
+        while !caret.is_done() {
+            let Some(wrapped) = composer.next_line() else {
+                break;
+            };
+            caret.row(&wrapped, row, area.width);
+            row += 1;
+        }
+    }
 }
 
-fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16) {
-    let mut x = get_line_offset(wrapped.width, area.width, wrapped.alignment);
-    for StyledGrapheme { symbol, style } in wrapped.graphemes {
-        let width = symbol.width();
+fn render_line<'a>(
+    wrapped: &WrappedLine<'_, TextGrapheme<'a>>,
+    area: Rect,
+    buf: &mut Buffer,
+    y: u16,
+    direction: TextDirection,
+    width_mode: WidthMode,
+    trackers: &mut RowTrackers<'_, 'a, '_>,
+) {
+    let line_offset = get_wrapped_line_offset(wrapped, area.width);
+    let visual_columns = direction.visual_columns(
+        wrapped
+            .graphemes
+            .iter()
+            .map(|grapheme| grapheme.grapheme.symbol),
+    );
+    let mut x = line_offset;
+    for (index, text_grapheme) in wrapped.graphemes.iter().enumerate() {
+        let grapheme = &text_grapheme.grapheme;
+        let StyledGrapheme { symbol, style } = grapheme;
+        let width = width_mode.width(symbol);
         if width == 0 {
             continue;
         }
+        let width = u16::try_from(width).unwrap_or(u16::MAX);
+        if let Some(columns) = &visual_columns {
+            x = line_offset.saturating_add(columns[index]);
+        }
+        if let Some(regions) = trackers.regions.as_deref_mut() {
+            let grapheme_area = Rect::new(area.left() + x, area.top() + y, width, 1);
+            regions.push(text_grapheme.line, text_grapheme.span, grapheme_area);
+        }
+        let (symbol, style) = match trackers.marker.as_mut() {
+            Some(marker) => marker.mark(text_grapheme),
+            None => (*symbol, *style),
+        };
         // Make sure to overwrite any previous character with a space (rather than a zero-width)
         let symbol = if symbol.is_empty() { " " } else { symbol };
         let position = Position::new(area.left() + x, area.top() + y);
-        buf[position].set_symbol(symbol).set_style(*style);
-        x += u16::try_from(width).unwrap_or(u16::MAX);
+        buf[position].set_symbol(symbol).set_style(style);
+        x += width;
     }
 }
 
+/// Returns the column at which a wrapped line starts, taking its indentation into account.
+const fn get_wrapped_line_offset<G>(wrapped: &WrappedLine<'_, G>, text_area_width: u16) -> u16 {
+    let width = text_area_width.saturating_sub(wrapped.indent);
+    wrapped.indent + get_line_offset(wrapped.width, width, wrapped.alignment)
+}
+
 const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
@@ -511,6 +1704,7 @@ mod tests {
         text::{Line, Span, Text},
         widgets::Widget,
     };
+    use rstest::rstest;
 
     use super::*;
     use crate::{block::Position, borders::Borders};
@@ -526,6 +1720,306 @@ mod tests {
         assert_eq!(buffer, *expected);
     }
 
+    #[rstest]
+    #[case::first_line("abc\ndef", None, (0, 0), 1, Some((1, 0)))]
+    #[case::second_line("abc\ndef", None, (0, 0), 6, Some((2, 1)))]
+    #[case::end_of_line("abc\ndef", None, (0, 0), 3, Some((3, 0)))]
+    #[case::end_of_text("abc\ndef", None, (0, 0), 100, Some((3, 1)))]
+    #[case::empty_line("abc\n\ndef", None, (0, 0), 4, Some((0, 1)))]
+    #[case::tab("a\tb", None, (0, 0), 1, Some((1, 0)))]
+    #[case::after_tab("a\tb", Some(false), (0, 0), 2, Some((3, 1)))]
+    #[case::truncated_after_tab("a\tb", None, (0, 0), 2, None)]
+    #[case::wide("你好a", None, (0, 0), 1, Some((2, 0)))]
+    #[case::truncated_wide("你好a", None, (0, 0), 2, None)]
+    #[case::control_at_end("ab\r", None, (0, 0), 2, Some((2, 0)))]
+    #[case::truncated_tab("abc\td", None, (0, 0), 3, Some((3, 0)))]
+    #[case::wrapped("abc def", Some(true), (0, 0), 5, Some((1, 1)))]
+    #[case::trimmed_whitespace("abc def", Some(true), (0, 0), 3, Some((3, 0)))]
+    #[case::full_row("abcd", Some(true), (0, 0), 4, Some((3, 0)))]
+    #[case::scrolled_above("abc\ndef", None, (1, 0), 1, None)]
+    #[case::scrolled("abc\ndef", None, (1, 0), 5, Some((1, 0)))]
+    #[case::wrapped_scrolled_above("abc def", Some(true), (1, 0), 1, None)]
+    #[case::wrapped_scrolled("abc def", Some(true), (1, 0), 6, Some((2, 0)))]
+    #[case::below("a\nb\nc\nd", None, (0, 0), 6, None)]
+    #[case::wrapped_below("a\nb\nc def", Some(true), (0, 0), 6, None)]
+    #[case::scrolled_left("abcdef", None, (0, 2), 1, None)]
+    #[case::scrolled_horizontally("abcdef", None, (0, 2), 3, Some((1, 0)))]
+    #[case::scrolled_right("abcdef", None, (0, 0), 5, None)]
+    fn caret_position(
+        #[case] text: &str,
+        #[case] trim: Option<bool>,
+        #[case] scroll: (u16, u16),
+        #[case] caret: usize,
+        #[case] expected: Option<(u16, u16)>,
+    ) {
+        let mut paragraph = Paragraph::new(text).scroll(scroll);
+        if let Some(trim) = trim {
+            paragraph = paragraph.wrap(Wrap { trim });
+        }
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let mut state = ParagraphState::new().with_caret(caret);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            state.caret_position(),
+            expected.map(ratatui_core::layout::Position::from)
+        );
+
+        // rendering as a stateful widget doesn't change the output
+        let mut expected_buffer = Buffer::empty(buffer.area);
+        Widget::render(&paragraph, buffer.area, &mut expected_buffer);
+        assert_eq!(buffer, expected_buffer);
+    }
+
+    #[test]
+    fn caret_position_with_block_and_alignment() {
+        let paragraph = Paragraph::new(vec![
+            Line::from(vec![Span::raw("ab"), Span::raw("cd")]),
+            Line::from("xy").right_aligned(),
+        ])
+        .block(Block::bordered());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        let mut state = ParagraphState::new().with_caret(3);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.caret_position(), Some((4, 1).into()));
+
+        state.set_caret(6);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.caret(), 6);
+        assert_eq!(state.caret_position(), Some((6, 2).into()));
+    }
+
+    #[rstest]
+    #[case::top(0, ["a b ", "c d "])]
+    #[case::scrolled(1, ["c d ", "e f "])]
+    #[case::clamped(10, ["e f ", "g   "])]
+    fn scroll_offset(#[case] offset: u16, #[case] expected: [&str; 2]) {
+        let paragraph = Paragraph::new("a b c d e f g")
+            .wrap(Wrap { trim: true })
+            .scroll((3, 0));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let mut state = ParagraphState::new().with_scroll_offset(offset);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+        assert_eq!(state.content_height(), 4);
+        assert_eq!(state.viewport_height(), 2);
+        assert_eq!(state.max_scroll_offset(), 2);
+        assert_eq!(state.scroll_offset(), offset.min(2));
+    }
+
+    #[rstest]
+    #[case::top(0)]
+    #[case::scrolled(1)]
+    #[case::past_the_end(10)]
+    fn content_height_without_scroll_offset(#[case] scroll: u16) {
+        let paragraph = Paragraph::new("a b c d e f g")
+            .wrap(Wrap { trim: true })
+            .scroll((scroll, 0));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let mut state = ParagraphState::new();
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.content_height(), 4);
+        assert_eq!(state.max_scroll_offset(), 2);
+    }
+
+    #[test]
+    fn scroll_offset_with_block() {
+        let paragraph = Paragraph::new("a\nb\nc\nd").block(Block::bordered());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        let mut state = ParagraphState::new().with_scroll_offset(5);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["┌─┐", "│c│", "│d│", "└─┘"]));
+        assert_eq!(state.content_height(), 4);
+        assert_eq!(state.scroll_offset(), 2);
+    }
+
+    #[test]
+    fn scroll_down_and_up() {
+        let paragraph = Paragraph::new("a\nb\nc\nd\ne");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 2));
+        let mut state = ParagraphState::new();
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.scroll_offset(), 0);
+
+        state.scroll_down(2);
+        assert_eq!(state.scroll_offset(), 2);
+        state.scroll_down(5);
+        assert_eq!(state.scroll_offset(), 3);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["d", "e"]));
+
+        state.scroll_up(1);
+        assert_eq!(state.scroll_offset(), 2);
+        state.scroll_up(5);
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn scrollbar_state() {
+        use crate::scrollbar::{Scrollbar, ScrollbarOrientation};
+
+        let state = ParagraphState::new();
+        assert_eq!(state.scrollbar_state(), ScrollbarState::default());
+
+        let paragraph = Paragraph::new("a\nb\nc\nd\ne\nf");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 3));
+        let mut state = ParagraphState::new().with_scroll_offset(10);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            state.scrollbar_state(),
+            ScrollbarState::new(4)
+                .position(3)
+                .viewport_content_length(3)
+        );
+
+        // the thumb is half of the track and reaches its end when scrolled to the bottom
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 6));
+        scrollbar.render(buffer.area, &mut buffer, &mut state.scrollbar_state());
+        assert_eq!(buffer, Buffer::with_lines(["-", "-", "-", "#", "#", "#"]));
+    }
+
+    #[test]
+    fn preserve_whitespace() {
+        let paragraph = Paragraph::new("ab  cd ef")
+            .wrap(Wrap { trim: true })
+            .preserve_whitespace(true)
+            .trailing_whitespace("·", Color::Red);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        // whitespace at the wrap points is kept but is not trailing whitespace
+        let expected = Buffer::with_lines(["ab  ", "cd  ", "ef  "]);
+        assert_eq!(buffer, expected);
+        assert_eq!(paragraph.line_count(4), 3);
+    }
+
+    #[rstest]
+    #[case::not_wrapped(None, (0, 0), ["ab· ", "····", "c d·"])]
+    #[case::wrapped(Some(false), (0, 0), ["ab· ", "····", "c d·"])]
+    #[case::wrapped_trimmed(Some(true), (0, 0), ["ab· ", "    ", "c d·"])]
+    #[case::scrolled(None, (1, 0), ["····", "c d·", "    "])]
+    #[case::scrolled_horizontally(None, (0, 2), ["·   ", "··  ", "d·  "])]
+    fn trailing_whitespace(
+        #[case] wrap: Option<bool>,
+        #[case] scroll: (u16, u16),
+        #[case] expected: [&str; 3],
+    ) {
+        let mut paragraph = Paragraph::new(vec![
+            Line::from(vec![Span::raw("ab"), Span::raw(" ")]),
+            Line::from("\t"),
+            Line::from("c d "),
+        ])
+        .tab_width(4)
+        .scroll(scroll)
+        .trailing_whitespace("·", Color::Red);
+        if let Some(trim) = wrap {
+            paragraph = paragraph.wrap(Wrap { trim });
+        }
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(expected);
+        for cell in &mut expected.content {
+            if cell.symbol() == "·" {
+                cell.set_fg(Color::Red);
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn trailing_whitespace_style() {
+        let paragraph = Paragraph::new("a \u{3000}").trailing_whitespace("·", Color::Red);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        // the wide whitespace is only styled
+        let mut expected = Buffer::with_lines(["a·\u{3000} "]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn tab_width() {
+        let paragraph = Paragraph::new("a\tb").tab_width(4);
+        test_case(&paragraph, &Buffer::with_lines(["a   b   "]));
+
+        let paragraph = Paragraph::new("a\tb\nabcd\te\n\t\tf").tab_width(4);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["a   b     ", "abcd    e ", "        f "]),
+        );
+    }
+
+    #[test]
+    fn tab_width_default_and_zero() {
+        test_case(&Paragraph::new("a\tb"), &Buffer::with_lines(["a       b "]));
+        test_case(
+            &Paragraph::new("a\tb").tab_width(0),
+            &Buffer::with_lines(["ab        "]),
+        );
+    }
+
+    #[test]
+    fn tab_width_wrapped() {
+        // tab stops are relative to the start of the line, not the start of each wrapped row
+        let paragraph = Paragraph::new("abc\tdefgh\ti")
+            .tab_width(4)
+            .wrap(Wrap { trim: false });
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["abc     ", "defgh   ", "i       "]),
+        );
+        assert_eq!(Paragraph::new("a\tb").tab_width(4).line_width(), 5);
+    }
+
+    #[rstest]
+    #[case::hanging(0, 2, ["- item text ", "  that wraps", "  nicely    ", "            "])]
+    #[case::first_line(4, 0, ["    - item  ", "text that   ", "wraps nicely", "            "])]
+    #[case::both(2, 4, ["  - item    ", "    text    ", "    that    ", "    wraps   "])]
+    fn indent(#[case] first: u16, #[case] hanging: u16, #[case] expected: [&str; 4]) {
+        let paragraph = Paragraph::new("- item text that wraps nicely")
+            .wrap(Wrap { trim: true })
+            .first_line_indent(first)
+            .hanging_indent(hanging);
+        test_case(&paragraph, &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn indent_with_alignment_and_caret() {
+        let paragraph = Paragraph::new("ab cd ef")
+            .wrap(Wrap { trim: true })
+            .hanging_indent(2)
+            .right_aligned();
+        assert_eq!(paragraph.line_count(5), 2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let mut state = ParagraphState::new().with_caret(6);
+        paragraph
+            .with_cursor()
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["ab cd", "   ef", "     "]));
+        assert_eq!(state.caret_position(), Some((3, 1).into()));
+    }
+
     #[test]
     fn zero_width_char_at_end_of_line() {
         let line = "foo\u{200B}";
@@ -891,6 +2385,56 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::rtl("שלום", ["  םולש"])]
+    #[case::mixed("שלום abc", ["abc םולש"])]
+    #[case::number("שלום 123", ["123 םולש"])]
+    #[case::ltr_only("abc def", [" abc def"])]
+    fn render_rtl(#[case] text: &str, #[case] expected: [&str; 1]) {
+        let paragraph = Paragraph::new(text)
+            .direction(TextDirection::Rtl)
+            .right_aligned();
+        test_case(&paragraph, &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_rtl_spans() {
+        let line = Line::from(vec!["אב".red(), " ".into(), "גד".blue()]);
+        let paragraph = Paragraph::new(line).direction(TextDirection::Rtl);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Widget::render(paragraph, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["דג בא "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().blue());
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_line_direction() {
+        let text = Text::from(vec![
+            Line::from("שלום").direction(TextDirection::Rtl),
+            Line::from("שלום"),
+        ]);
+        let paragraph = Paragraph::new(text);
+        test_case(&paragraph, &Buffer::with_lines(["םולש", "שלום"]));
+
+        let text = Text::from(vec![
+            Line::from("שלום").direction(TextDirection::Ltr),
+            Line::from("שלום"),
+        ]);
+        let paragraph = Paragraph::new(text).direction(TextDirection::Rtl);
+        test_case(&paragraph, &Buffer::with_lines(["שלום", "םולש"]));
+    }
+
+    #[test]
+    fn render_rtl_wrapped() {
+        let paragraph = Paragraph::new("אבג דהו")
+            .direction(TextDirection::Rtl)
+            .wrap(Wrap { trim: true })
+            .right_aligned();
+        test_case(&paragraph, &Buffer::with_lines([" גבא", " והד"]));
+    }
+
     #[test]
     fn test_render_paragraph_with_scroll_offset() {
         let text = "This is a\ncool\nmultiline\nparagraph.";
@@ -1061,6 +2605,40 @@ mod tests {
         assert_eq!(paragraph.line_count(6), 200);
     }
 
+    #[test]
+    fn wrap_keeps_grapheme_clusters_intact() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let flag = "\u{1F1FA}\u{1F1F8}";
+        let paragraph = Paragraph::new(format!("{flag}{family}ab")).wrap(Wrap { trim: true });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(paragraph, buf.area, &mut buf);
+        let expected = Buffer::with_lines([format!("{flag} "), format!("{family}a"), "b  ".into()]);
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::newlines("ab\ncd\n\nef", false, 4)]
+    #[case::wide_chars("你好世界", false, 2)]
+    #[case::wide_chars_and_newlines("你好世界\nab", false, 3)]
+    #[case::no_trim("    ab", false, 2)]
+    #[case::trim("    ab", true, 1)]
+    fn widgets_paragraph_line_count_matches_rendered_lines(
+        #[case] text: &str,
+        #[case] trim: bool,
+        #[case] expected: usize,
+    ) {
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim });
+        assert_eq!(paragraph.line_count(4), expected);
+
+        // the line count is the height needed to render every line
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 10));
+        Widget::render(&paragraph, buf.area, &mut buf);
+        let rendered = (0..buf.area.height)
+            .rposition(|y| (0..4).any(|x| buf[(x, y)].symbol() != " "))
+            .map_or(0, |y| y + 1);
+        assert_eq!(rendered, expected);
+    }
+
     #[test]
     fn widgets_paragraph_rendered_line_count_accounts_block() {
         let block = Block::new();
@@ -1203,4 +2781,176 @@ mod tests {
         expected.set_style(Rect::new(1, 1, 11, 1), Style::default().fg(Color::Green));
         assert_eq!(buf, expected);
     }
+
+    #[test]
+    fn span_regions_wrapped() {
+        let line = Line::from(vec![
+            Span::raw("<bob> "),
+            Span::raw("hello there "),
+            Span::raw("world"),
+        ]);
+        let paragraph = Paragraph::new(line).wrap(Wrap { trim: true });
+        let area = Rect::new(0, 0, 10, 4);
+        let mut buf = Buffer::empty(area);
+        let mut regions = SpanRegions::new();
+        paragraph.render_with_regions(area, &mut buf, &mut regions);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["<bob>", "hello", "there", "world"].map(|s| format!("{s:10.10}")))
+        );
+        // the second span is split over two rows
+        assert_eq!(
+            regions.iter().copied().collect::<Vec<_>>(),
+            [
+                SpanRegion {
+                    line: 0,
+                    span: 0,
+                    area: Rect::new(0, 0, 5, 1)
+                },
+                SpanRegion {
+                    line: 0,
+                    span: 1,
+                    area: Rect::new(0, 1, 5, 1)
+                },
+                SpanRegion {
+                    line: 0,
+                    span: 1,
+                    area: Rect::new(0, 2, 5, 1)
+                },
+                SpanRegion {
+                    line: 0,
+                    span: 2,
+                    area: Rect::new(0, 3, 5, 1)
+                },
+            ]
+        );
+        assert_eq!(regions.span_at((3, 0)), Some((0, 0)));
+        assert_eq!(regions.span_at((4, 3)), Some((0, 2)));
+        assert_eq!(regions.span_at((9, 0)), None);
+        assert_eq!(regions.areas_of(0, 1).count(), 2);
+    }
+
+    #[test]
+    fn span_regions_duplicate_content() {
+        // identical string literals may share the same address
+        let paragraph = Paragraph::new(Line::from(vec![Span::raw("ab"), Span::raw("ab")]));
+        let area = Rect::new(0, 0, 6, 1);
+        let mut buf = Buffer::empty(area);
+        let mut regions = SpanRegions::new();
+        paragraph.render_with_regions(area, &mut buf, &mut regions);
+
+        assert_eq!(regions.span_at((1, 0)), Some((0, 0)));
+        assert_eq!(regions.span_at((2, 0)), Some((0, 1)));
+        assert_eq!(regions.span_at((4, 0)), None);
+    }
+
+    #[test]
+    fn span_regions_tabs() {
+        let paragraph =
+            Paragraph::new(Line::from(vec![Span::raw("a\t"), Span::raw("\tb")])).tab_width(4);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        let mut regions = SpanRegions::new();
+        paragraph.render_with_regions(area, &mut buf, &mut regions);
+
+        assert_eq!(regions.span_at((3, 0)), Some((0, 0)));
+        assert_eq!(regions.span_at((4, 0)), Some((0, 1)));
+        assert_eq!(regions.span_at((8, 0)), Some((0, 1)));
+        assert_eq!(regions.span_at((9, 0)), None);
+    }
+
+    #[rstest]
+    #[case::wrapped(Some(Wrap { trim: true }), 1, &[(0, 1), (1, 2), (1, 3)])]
+    #[case::truncated(None, 1, &[(1, 1), (2, 2)])]
+    #[case::wrapped_no_scroll(Some(Wrap { trim: true }), 0, &[(0, 1), (0, 2), (1, 3)])]
+    fn line_visitor(
+        #[case] wrap: Option<Wrap>,
+        #[case] scroll: u16,
+        #[case] expected: &[(usize, u16)],
+    ) {
+        let text = Text::from(vec![
+            Line::from("one two"),
+            Line::from("three four"),
+            Line::from("five"),
+        ]);
+        let mut paragraph = Paragraph::new(text)
+            .block(Block::bordered())
+            .scroll((scroll, 0));
+        if let Some(wrap) = wrap {
+            paragraph = paragraph.wrap(wrap);
+        }
+        let area = Rect::new(0, 0, 8, 5);
+        let mut buf = Buffer::empty(area);
+        let mut visited = vec![];
+        paragraph.render_with_line_visitor(area, &mut buf, |line, row, _| {
+            visited.push((line, row));
+        });
+        let expected = expected
+            .iter()
+            .map(|&(line, y)| (line, Rect::new(1, y, 6, 1)))
+            .collect::<Vec<_>>();
+        assert_eq!(visited, expected);
+    }
+
+    #[test]
+    fn line_visitor_decorates_rows() {
+        let text = Text::from(vec![Line::from("+ab"), Line::from("-cd")]);
+        let paragraph = Paragraph::new(text);
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        paragraph.render_with_line_visitor(area, &mut buf, |line, row, buf| {
+            let style = if line == 0 {
+                Style::new().green()
+            } else {
+                Style::new().red()
+            };
+            buf.set_style(row, style);
+            buf.set_string(row.right() - 1, row.y, "|", Style::new());
+        });
+        let mut expected = Buffer::with_lines(["+ab |", "-cd |"]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().green());
+        expected.set_style(Rect::new(0, 1, 5, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn span_regions_scroll_and_block() {
+        let text = Text::from(vec![
+            Line::from("first"),
+            Line::from(vec![Span::raw("sec"), Span::raw("ond")]).centered(),
+            Line::from("third"),
+        ]);
+        let paragraph = Paragraph::new(text).block(Block::bordered()).scroll((1, 0));
+        let area = Rect::new(0, 0, 10, 4);
+        let mut buf = Buffer::empty(area);
+        let mut regions = SpanRegions::new();
+        paragraph.render_with_regions(area, &mut buf, &mut regions);
+
+        assert_eq!(
+            regions.iter().copied().collect::<Vec<_>>(),
+            [
+                SpanRegion {
+                    line: 1,
+                    span: 0,
+                    area: Rect::new(2, 1, 3, 1)
+                },
+                SpanRegion {
+                    line: 1,
+                    span: 1,
+                    area: Rect::new(5, 1, 3, 1)
+                },
+                SpanRegion {
+                    line: 2,
+                    span: 0,
+                    area: Rect::new(1, 2, 5, 1)
+                },
+            ]
+        );
+
+        // rendering again replaces the previous regions
+        let paragraph = Paragraph::new("");
+        paragraph.render_with_regions(area, &mut buf, &mut regions);
+        assert!(regions.is_empty());
+    }
 }
@@ -1,56 +1,90 @@
 //! Internal module for reflowing text to fit into a certain width.
 use std::{collections::VecDeque, mem};
 
-use ratatui_core::{layout::Alignment, text::StyledGrapheme};
+use ratatui_core::{
+    layout::Alignment,
+    text::{StyledGrapheme, WidthMode},
+};
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+
+/// A grapheme that can be packed into lines by a [`LineComposer`].
+///
+/// Besides [`StyledGrapheme`] itself, this lets callers attach information to each grapheme (e.g.
+/// where it comes from in the text) that is carried through wrapping and truncation.
+pub trait Grapheme<'a>: Clone {
+    /// The styled grapheme to lay out
+    fn styled(&self) -> &StyledGrapheme<'a>;
+    /// Replaces the symbol of the grapheme, e.g. to cut off its start
+    fn set_symbol(&mut self, symbol: &'a str);
+}
+
+impl<'a> Grapheme<'a> for StyledGrapheme<'a> {
+    fn styled(&self) -> &Self {
+        self
+    }
+
+    fn set_symbol(&mut self, symbol: &'a str) {
+        self.symbol = symbol;
+    }
+}
 
 /// A state machine to pack styled symbols into lines.
 /// Cannot implement it as Iterator since it yields slices of the internal buffer (need streaming
 /// iterators for that).
-pub trait LineComposer<'a> {
-    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>>;
+pub trait LineComposer<'a, G: Grapheme<'a> = StyledGrapheme<'a>> {
+    fn next_line(&mut self) -> Option<WrappedLine<'_, G>>;
 }
 
 /// A line that has been wrapped to a certain width.
-pub struct WrappedLine<'lend, 'text> {
+pub struct WrappedLine<'lend, G> {
     /// One line reflowed to the correct width
-    pub graphemes: &'lend [StyledGrapheme<'text>],
+    pub graphemes: &'lend [G],
     /// The width of the line
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
+    /// The number of columns the line is indented by
+    pub indent: u16,
 }
 
 /// A state machine that wraps lines on word boundaries.
 #[derive(Debug, Default, Clone)]
-pub struct WordWrapper<'a, O, I>
+pub struct WordWrapper<O, I>
 where
     // Outer iterator providing the individual lines
     O: Iterator<Item = (I, Alignment)>,
     // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
     // a series of symbols
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator,
 {
     /// The given, unprocessed lines
     input_lines: O,
     max_line_width: u16,
-    wrapped_lines: VecDeque<Vec<StyledGrapheme<'a>>>,
+    wrapped_lines: VecDeque<Vec<I::Item>>,
     current_alignment: Alignment,
-    current_line: Vec<StyledGrapheme<'a>>,
+    current_line: Vec<I::Item>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Keeps the whitespace that fits at the end of a line when wrapping
+    preserve_whitespace: bool,
+    /// Columns the first row and the following rows of each line are indented by
+    indent: (u16, u16),
+    /// Index of the next row of the current line to emit
+    current_row: usize,
+    /// Measures the width of the symbols
+    width_mode: WidthMode,
 
     // These are cached allocations that hold no state across next_line invocations
-    pending_word: Vec<StyledGrapheme<'a>>,
-    pending_whitespace: VecDeque<StyledGrapheme<'a>>,
-    pending_line_pool: Vec<Vec<StyledGrapheme<'a>>>,
+    pending_word: Vec<I::Item>,
+    pending_whitespace: VecDeque<I::Item>,
+    pending_line_pool: Vec<Vec<I::Item>>,
 }
 
-impl<'a, O, I> WordWrapper<'a, O, I>
+impl<'a, O, I> WordWrapper<O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator,
+    I::Item: Grapheme<'a>,
 {
     /// Create a new `WordWrapper` with the given lines and maximum line width.
     pub const fn new(lines: O, max_line_width: u16, trim: bool) -> Self {
@@ -61,6 +95,10 @@ where
             current_alignment: Alignment::Left,
             current_line: vec![],
             trim,
+            preserve_whitespace: false,
+            indent: (0, 0),
+            current_row: 0,
+            width_mode: WidthMode::Narrow,
 
             pending_word: Vec::new(),
             pending_line_pool: Vec::new(),
@@ -68,41 +106,74 @@ where
         }
     }
 
+    /// Set the width mode used to measure the symbols, which defaults to [`WidthMode::Narrow`].
+    pub fn set_width_mode(&mut self, width_mode: WidthMode) {
+        self.width_mode = width_mode;
+    }
+
+    /// Keep the whitespace at the end of wrapped lines as far as it fits instead of removing it.
+    pub fn set_preserve_whitespace(&mut self, preserve_whitespace: bool) {
+        self.preserve_whitespace = preserve_whitespace;
+    }
+
+    /// Indent the first row of each line by `first` columns and the following rows by `hanging`
+    /// columns, reducing the width available to these rows.
+    ///
+    /// At least one column is always left for the text.
+    pub fn set_indent(&mut self, first: u16, hanging: u16) {
+        self.indent = (first, hanging);
+    }
+
+    /// Returns the number of columns the given row of a line is indented by
+    fn row_indent(&self, row: usize) -> u16 {
+        let indent = if row == 0 {
+            self.indent.0
+        } else {
+            self.indent.1
+        };
+        indent.min(self.max_line_width.saturating_sub(1))
+    }
+
+    /// Returns the width available to the given row of a line
+    fn row_width(&self, row: usize) -> u16 {
+        self.max_line_width - self.row_indent(row)
+    }
+
     /// Split an input line (`line_symbols`) into wrapped lines
     /// and cache them to be emitted later
-    fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
+    fn process_input(&mut self, line_symbols: impl IntoIterator<Item = I::Item>) {
         let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
         let mut line_width = 0;
         let mut word_width = 0;
         let mut whitespace_width = 0;
         let mut non_whitespace_previous = false;
+        let mut max_line_width = self.row_width(0);
 
         self.pending_word.clear();
         self.pending_whitespace.clear();
         pending_line.clear();
 
         for grapheme in line_symbols {
-            let is_whitespace = grapheme.is_whitespace();
-            let symbol_width = grapheme.symbol.width() as u16;
+            let is_whitespace = grapheme.styled().is_whitespace();
+            let symbol_width = self.width_mode.width(grapheme.styled().symbol) as u16;
 
             // ignore symbols wider than line limit
-            if symbol_width > self.max_line_width {
+            if symbol_width > max_line_width {
                 continue;
             }
 
             let word_found = non_whitespace_previous && is_whitespace;
             // current word would overflow after removing whitespace
-            let trimmed_overflow = pending_line.is_empty()
-                && self.trim
-                && word_width + symbol_width > self.max_line_width;
+            let trimmed_overflow =
+                pending_line.is_empty() && self.trim && word_width + symbol_width > max_line_width;
             // separated whitespace would overflow on its own
             let whitespace_overflow = pending_line.is_empty()
                 && self.trim
-                && whitespace_width + symbol_width > self.max_line_width;
+                && whitespace_width + symbol_width > max_line_width;
             // current full word (including whitespace) would overflow
             let untrimmed_overflow = pending_line.is_empty()
                 && !self.trim
-                && word_width + whitespace_width + symbol_width > self.max_line_width;
+                && word_width + whitespace_width + symbol_width > max_line_width;
 
             // append finished segment to current line
             if word_found || trimmed_overflow || whitespace_overflow || untrimmed_overflow {
@@ -120,21 +191,21 @@ where
             }
 
             // pending line fills up limit
-            let line_full = line_width >= self.max_line_width;
+            let line_full = line_width >= max_line_width;
             // pending word would overflow line limit
-            let pending_word_overflow = symbol_width > 0
-                && line_width + whitespace_width + word_width >= self.max_line_width;
+            let pending_word_overflow =
+                symbol_width > 0 && line_width + whitespace_width + word_width >= max_line_width;
 
             // add finished wrapped line to remaining lines
             if line_full || pending_word_overflow {
-                let mut remaining_width = u16::saturating_sub(self.max_line_width, line_width);
+                let mut remaining_width = u16::saturating_sub(max_line_width, line_width);
+                // leading whitespace is never kept when trimming
+                let keep_whitespace =
+                    self.preserve_whitespace && (!pending_line.is_empty() || !self.trim);
 
-                self.wrapped_lines.push_back(mem::take(&mut pending_line));
-                line_width = 0;
-
-                // remove whitespace up to the end of line
+                // remove (or keep) whitespace up to the end of line
                 while let Some(grapheme) = self.pending_whitespace.front() {
-                    let width = grapheme.symbol.width() as u16;
+                    let width = self.width_mode.width(grapheme.styled().symbol) as u16;
 
                     if width > remaining_width {
                         break;
@@ -142,9 +213,24 @@ where
 
                     whitespace_width -= width;
                     remaining_width -= width;
-                    self.pending_whitespace.pop_front();
+                    if let Some(grapheme) = self.pending_whitespace.pop_front() {
+                        if keep_whitespace {
+                            pending_line.push(grapheme);
+                        }
+                    }
+                }
+                if keep_whitespace
+                    && is_whitespace
+                    && self.pending_whitespace.is_empty()
+                    && symbol_width <= remaining_width
+                {
+                    pending_line.push(grapheme.clone());
                 }
 
+                self.wrapped_lines.push_back(mem::take(&mut pending_line));
+                line_width = 0;
+                max_line_width = self.row_width(self.wrapped_lines.len());
+
                 // don't count first whitespace toward next word
                 if is_whitespace && self.pending_whitespace.is_empty() {
                     continue;
@@ -187,7 +273,7 @@ where
         }
     }
 
-    fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
+    fn replace_current_line(&mut self, line: Vec<I::Item>) {
         let cache = mem::replace(&mut self.current_line, line);
         if cache.capacity() > 0 {
             self.pending_line_pool.push(cache);
@@ -195,13 +281,14 @@ where
     }
 }
 
-impl<'a, O, I> LineComposer<'a> for WordWrapper<'a, O, I>
+impl<'a, O, I> LineComposer<'a, I::Item> for WordWrapper<O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator,
+    I::Item: Grapheme<'a>,
 {
     #[allow(clippy::too_many_lines)]
-    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+    fn next_line(&mut self) -> Option<WrappedLine<'_, I::Item>> {
         if self.max_line_width == 0 {
             return None;
         }
@@ -211,20 +298,24 @@ where
             if let Some(line) = self.wrapped_lines.pop_front() {
                 let line_width = line
                     .iter()
-                    .map(|grapheme| grapheme.symbol.width() as u16)
+                    .map(|grapheme| self.width_mode.width(grapheme.styled().symbol) as u16)
                     .sum();
 
+                let indent = self.row_indent(self.current_row);
+                self.current_row += 1;
                 self.replace_current_line(line);
                 return Some(WrappedLine {
                     graphemes: &self.current_line,
                     width: line_width,
                     alignment: self.current_alignment,
+                    indent,
                 });
             }
 
             // otherwise, process pending wrapped lines from input
             let (line_symbols, line_alignment) = self.input_lines.next()?;
             self.current_alignment = line_alignment;
+            self.current_row = 0;
             self.process_input(line_symbols);
         }
     }
@@ -232,26 +323,28 @@ where
 
 /// A state machine that truncates overhanging lines.
 #[derive(Debug, Default, Clone)]
-pub struct LineTruncator<'a, O, I>
+pub struct LineTruncator<O, I>
 where
     // Outer iterator providing the individual lines
     O: Iterator<Item = (I, Alignment)>,
     // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
     // a series of symbols
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator,
 {
     /// The given, unprocessed lines
     input_lines: O,
     max_line_width: u16,
-    current_line: Vec<StyledGrapheme<'a>>,
+    current_line: Vec<I::Item>,
     /// Record the offset to skip render
     horizontal_offset: u16,
+    /// Measures the width of the symbols
+    width_mode: WidthMode,
 }
 
-impl<'a, O, I> LineTruncator<'a, O, I>
+impl<O, I> LineTruncator<O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator,
 {
     /// Create a new `LineTruncator` with the given lines and maximum line width.
     pub const fn new(lines: O, max_line_width: u16) -> Self {
@@ -259,22 +352,29 @@ where
             input_lines: lines,
             max_line_width,
             horizontal_offset: 0,
+            width_mode: WidthMode::Narrow,
             current_line: vec![],
         }
     }
 
+    /// Set the width mode used to measure the symbols, which defaults to [`WidthMode::Narrow`].
+    pub fn set_width_mode(&mut self, width_mode: WidthMode) {
+        self.width_mode = width_mode;
+    }
+
     /// Set the horizontal offset to skip render.
     pub fn set_horizontal_offset(&mut self, horizontal_offset: u16) {
         self.horizontal_offset = horizontal_offset;
     }
 }
 
-impl<'a, O, I> LineComposer<'a> for LineTruncator<'a, O, I>
+impl<'a, O, I> LineComposer<'a, I::Item> for LineTruncator<O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator,
+    I::Item: Grapheme<'a>,
 {
-    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+    fn next_line(&mut self) -> Option<WrappedLine<'_, I::Item>> {
         if self.max_line_width == 0 {
             return None;
         }
@@ -289,13 +389,15 @@ where
             lines_exhausted = false;
             current_alignment = *alignment;
 
-            for StyledGrapheme { symbol, style } in current_line {
+            for mut grapheme in current_line {
+                let symbol = grapheme.styled().symbol;
+                let symbol_width = self.width_mode.width(symbol) as u16;
                 // Ignore characters wider that the total max width.
-                if symbol.width() as u16 > self.max_line_width {
+                if symbol_width > self.max_line_width {
                     continue;
                 }
 
-                if current_line_width + symbol.width() as u16 > self.max_line_width {
+                if current_line_width + symbol_width > self.max_line_width {
                     // Truncate line
                     break;
                 }
@@ -303,9 +405,9 @@ where
                 let symbol = if horizontal_offset == 0 || Alignment::Left != *alignment {
                     symbol
                 } else {
-                    let w = symbol.width();
+                    let w = usize::from(symbol_width);
                     if w > horizontal_offset {
-                        let t = trim_offset(symbol, horizontal_offset);
+                        let t = trim_offset(symbol, horizontal_offset, self.width_mode);
                         horizontal_offset = 0;
                         t
                     } else {
@@ -313,8 +415,9 @@ where
                         ""
                     }
                 };
-                current_line_width += symbol.width() as u16;
-                self.current_line.push(StyledGrapheme { symbol, style });
+                current_line_width += self.width_mode.width(symbol) as u16;
+                grapheme.set_symbol(symbol);
+                self.current_line.push(grapheme);
             }
         }
 
@@ -325,6 +428,7 @@ where
                 graphemes: &self.current_line,
                 width: current_line_width,
                 alignment: current_alignment,
+                indent: 0,
             })
         }
     }
@@ -332,10 +436,10 @@ where
 
 /// This function will return a str slice which start at specified offset.
 /// As src is a unicode str, start offset has to be calculated with each character.
-fn trim_offset(src: &str, mut offset: usize) -> &str {
+fn trim_offset(src: &str, mut offset: usize, width_mode: WidthMode) -> &str {
     let mut start = 0;
     for c in UnicodeSegmentation::graphemes(src, true) {
-        let w = c.width();
+        let w = width_mode.width(c);
         if w <= offset {
             offset -= w;
             start += c.len();
@@ -353,12 +457,14 @@ mod tests {
         style::Style,
         text::{Line, Text},
     };
+    use rstest::rstest;
 
     use super::*;
 
     #[derive(Clone, Copy)]
     enum Composer {
         WordWrapper { trim: bool },
+        PreservingWordWrapper { trim: bool },
         LineTruncator,
     }
 
@@ -376,11 +482,24 @@ mod tests {
             )
         });
 
+        let width_mode = WidthMode::current();
         let mut composer: Box<dyn LineComposer> = match which {
             Composer::WordWrapper { trim } => {
-                Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
+                let mut composer = WordWrapper::new(styled_lines, text_area_width, trim);
+                composer.set_width_mode(width_mode);
+                Box::new(composer)
+            }
+            Composer::PreservingWordWrapper { trim } => {
+                let mut composer = WordWrapper::new(styled_lines, text_area_width, trim);
+                composer.set_preserve_whitespace(true);
+                composer.set_width_mode(width_mode);
+                Box::new(composer)
+            }
+            Composer::LineTruncator => {
+                let mut composer = LineTruncator::new(styled_lines, text_area_width);
+                composer.set_width_mode(width_mode);
+                Box::new(composer)
             }
-            Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
         };
         let mut lines = vec![];
         let mut widths = vec![];
@@ -389,6 +508,7 @@ mod tests {
             graphemes,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = graphemes
@@ -521,6 +641,26 @@ mod tests {
         assert_eq!(line_truncator, ["", "a", "a"]);
     }
 
+    #[test]
+    fn line_composer_keeps_grapheme_clusters_intact() {
+        let width = 3;
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let flag = "\u{1F1FA}\u{1F1F8}";
+        let accent = "e\u{301}";
+        let text = format!("{family}{flag}{accent}{family}");
+        let (word_wrapper, widths, _) =
+            run_composer(Composer::WordWrapper { trim: true }, text.as_str(), width);
+        assert_eq!(
+            word_wrapper,
+            [
+                family.to_string(),
+                format!("{flag}{accent}"),
+                family.to_string()
+            ]
+        );
+        assert_eq!(widths, [2, 3, 2]);
+    }
+
     /// Tests `WordWrapper` with words some of which exceed line length and some not.
     #[test]
     fn line_composer_word_wrapper_mixed_length() {
@@ -559,6 +699,22 @@ mod tests {
         assert_eq!(word_wrapper_width, [width, width, width, width, 4]);
     }
 
+    #[rstest]
+    #[case::narrow(WidthMode::Narrow, &["①②③④"], &["①②③④"])]
+    #[case::wide(WidthMode::Wide, &["①②", "③④"], &["①②"])]
+    fn line_composer_ambiguous_width_chars(
+        #[case] mode: WidthMode,
+        #[case] wrapped: &[&str],
+        #[case] truncated: &[&str],
+    ) {
+        WidthMode::set_current(mode);
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, "①②③④", 4);
+        let (line_truncator, _, _) = run_composer(Composer::LineTruncator, "①②③④", 4);
+        WidthMode::set_current(WidthMode::Narrow);
+        assert_eq!(word_wrapper, wrapped);
+        assert_eq!(line_truncator, truncated);
+    }
+
     #[test]
     fn line_composer_leading_whitespace_removal() {
         let width = 20;
@@ -724,4 +880,49 @@ mod tests {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+
+    #[test]
+    fn line_composer_preserve_whitespace() {
+        let cases = [
+            ("ab  cd", true, vec!["ab  ", "cd"]),
+            ("ab  cd", false, vec!["ab  ", "cd"]),
+            ("abc   def", true, vec!["abc ", "def"]),
+            ("abc   def", false, vec!["abc ", " def"]),
+            ("abcd efgh", false, vec!["abcd", "efgh"]),
+            ("ab cd  ", true, vec!["ab ", "cd  "]),
+            ("  ab cd", true, vec!["ab ", "cd"]),
+            ("  ab cd", false, vec!["  ab", "cd"]),
+        ];
+        for (text, trim, expected) in cases {
+            let (lines, _, _) = run_composer(Composer::PreservingWordWrapper { trim }, text, 4);
+            assert_eq!(lines, expected, "{text:?} with trim: {trim}");
+        }
+    }
+
+    #[rstest]
+    #[case::hanging("aaa bbb ccc ddd eee", 0, 2, vec![("aaa bbb", 0), ("ccc", 2), ("ddd", 2), ("eee", 2)])]
+    #[case::first_line("aaa bbb ccc ddd eee", 3, 0, vec![("aaa", 3), ("bbb ccc", 0), ("ddd eee", 0)])]
+    #[case::clamped("a a a", 10, 10, vec![("a", 6), ("a", 6), ("a", 6)])]
+    fn line_composer_word_wrapper_indent(
+        #[case] text: &str,
+        #[case] first: u16,
+        #[case] hanging: u16,
+        #[case] expected: Vec<(&str, u16)>,
+    ) {
+        let line = Line::from(text);
+        let styled_lines = [(line.styled_graphemes(Style::default()), Alignment::Left)];
+        let mut composer = WordWrapper::new(styled_lines.into_iter(), 7, true);
+        composer.set_indent(first, hanging);
+        let mut lines = vec![];
+        while let Some(wrapped) = composer.next_line() {
+            let symbols: String = wrapped.graphemes.iter().map(|g| g.symbol).collect();
+            assert!(wrapped.width + wrapped.indent <= 7);
+            lines.push((symbols, wrapped.indent));
+        }
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(line, indent)| (line.to_string(), indent))
+            .collect();
+        assert_eq!(lines, expected);
+    }
 }
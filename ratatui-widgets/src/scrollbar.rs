@@ -11,13 +11,19 @@ use std::iter;
 
 use ratatui_core::{
     buffer::Buffer,
-    layout::Rect,
-    style::Style,
-    symbols::scrollbar::{Set, DOUBLE_HORIZONTAL, DOUBLE_VERTICAL},
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    symbols::scrollbar::{
+        Set, DOUBLE_HORIZONTAL, DOUBLE_VERTICAL, SMOOTH_HORIZONTAL, SMOOTH_VERTICAL,
+    },
+    text::WidthMode,
     widgets::StatefulWidget,
 };
 use strum::{Display, EnumString};
-use unicode_width::UnicodeWidthStr;
+
+pub use self::pair::ScrollbarPair;
+
+mod pair;
 
 /// A widget to display a scrollbar
 ///
@@ -96,6 +102,10 @@ pub struct Scrollbar<'a> {
     begin_style: Style,
     end_symbol: Option<&'a str>,
     end_style: Style,
+    track_padding_start: u16,
+    track_padding_end: u16,
+    smooth: bool,
+    thumb_length: Option<u16>,
 }
 
 /// This is the position of the scrollbar around a given area.
@@ -206,6 +216,10 @@ impl<'a> Scrollbar<'a> {
             begin_style: Style::new(),
             end_symbol: Some(symbols.end),
             end_style: Style::new(),
+            track_padding_start: 0,
+            track_padding_end: 0,
+            smooth: false,
+            thumb_length: None,
         }
     }
 
@@ -273,6 +287,22 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets a fixed length for the thumb, independent of the content and viewport length.
+    ///
+    /// By default (`None`), the length of the thumb is proportional to the viewport length
+    /// compared to the content length. A fixed length is useful for progress-style indicators. The
+    /// thumb is still positioned from [`ScrollbarState::position`] and
+    /// [`ScrollbarState::content_length`], so it is at the start of the track for the first
+    /// position and at the end of the track for the last one. The length is clamped between one
+    /// cell and the length of the track.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn thumb_length(mut self, thumb_length: Option<u16>) -> Self {
+        self.thumb_length = thumb_length;
+        self
+    }
+
     /// Sets the symbol that represents the track of the scrollbar.
     ///
     /// See [`Scrollbar`] for a visual example of what this represents.
@@ -354,6 +384,55 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets the number of empty cells between the begin symbol and the track, and between the
+    /// track and the end symbol.
+    ///
+    /// The padding shrinks the track, so the thumb only moves in the remaining cells. Nothing is
+    /// drawn in the padding. This is useful to align the track with a widget that has a header or
+    /// a footer, or to inset the scrollbar from the edges of its area.
+    ///
+    /// ```text
+    /// < ══█████═════ >
+    ///  ^            ^
+    ///  │            └ end padding
+    ///  └───────────── start padding
+    /// ```
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn track_padding(mut self, start: u16, end: u16) -> Self {
+        self.track_padding_start = start;
+        self.track_padding_end = end;
+        self
+    }
+
+    /// Sets whether the thumb moves smoothly by eighths of a cell.
+    ///
+    /// By default, the ends of the thumb are rounded to whole cells, so the thumb jumps between
+    /// cells when scrolling through long content. When smooth, the ends of the thumb are drawn
+    /// with the partial block glyphs of [`SMOOTH_VERTICAL`] or [`SMOOTH_HORIZONTAL`] depending on
+    /// the orientation. The end of the thumb that would need a glyph filling the top or right of a
+    /// cell uses the complementary glyph with the thumb style [reversed](Modifier::REVERSED), so
+    /// the track symbol is not drawn in the partial cells.
+    ///
+    /// Smooth scrolling assumes the thumb symbol is a full block. Tracks shorter than three cells
+    /// are always rendered in whole cells.
+    ///
+    /// ```text
+    /// ║
+    /// ▃
+    /// █
+    /// ▃ <- reversed
+    /// ║
+    /// ```
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+
     /// Sets the symbols used for the various parts of the scrollbar from a [`Set`].
     ///
     /// ```text
@@ -412,6 +491,66 @@ impl<'a> Scrollbar<'a> {
         self.end_style = style;
         self
     }
+
+    /// Returns the content position corresponding to a click on the track of the scrollbar.
+    ///
+    /// `area` and `state` are the same as the ones used to render the scrollbar, and `column` and
+    /// `row` are the coordinates of the click, e.g. from a mouse event. The cells of the track are
+    /// mapped evenly over the content, so the first cell corresponds to the first position and the
+    /// last cell to the last position.
+    ///
+    /// Returns `None` when the coordinates are outside the scrollbar, on the begin or end symbols,
+    /// on the track padding, or when the content is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     layout::Rect,
+    ///     widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+    /// };
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+    /// let mut state = ScrollbarState::new(100);
+    /// let area = Rect::new(0, 0, 10, 12);
+    /// // a click on the last cell of the track, just above the end symbol
+    /// if let Some(position) = scrollbar.position_at(area, &state, 9, 10) {
+    ///     state.scroll_to(position);
+    /// }
+    /// assert_eq!(state.get_position(), 99);
+    /// ```
+    #[must_use = "returns the content position at the given coordinates"]
+    pub fn position_at(
+        &self,
+        area: Rect,
+        state: &ScrollbarState,
+        column: u16,
+        row: u16,
+    ) -> Option<usize> {
+        let bar = self.scrollbar_area(area)?;
+        if state.content_length == 0 || !bar.contains(Position::new(column, row)) {
+            return None;
+        }
+        let offset = if self.orientation.is_vertical() {
+            row - bar.y
+        } else {
+            column - bar.x
+        };
+        let begin_length = self
+            .begin_symbol
+            .map_or(0, |s| WidthMode::current().width(s) as u16);
+        let cell = offset.checked_sub(begin_length.saturating_add(self.track_padding_start))?;
+        let track_length = self.track_length_excluding_arrow_heads(area);
+        if cell >= track_length {
+            return None;
+        }
+        let max_position = state.content_length - 1;
+        if track_length == 1 {
+            return Some(0);
+        }
+        let position = f64::from(cell) * max_position as f64 / f64::from(track_length - 1);
+        Some(position.round() as usize)
+    }
 }
 
 impl ScrollbarState {
@@ -495,6 +634,21 @@ impl ScrollbarState {
         }
     }
 
+    /// Moves the scroll position by `delta`, e.g. to scroll by a page.
+    ///
+    /// Negative values scroll backward. The position stays between the start and the end of the
+    /// scrollable content.
+    pub const fn scroll_by(&mut self, delta: isize) {
+        let position = self.position.saturating_add_signed(delta);
+        self.scroll_to(position);
+    }
+
+    /// Sets the scroll position, clamped to the end of the scrollable content.
+    pub const fn scroll_to(&mut self, position: usize) {
+        let last = self.content_length.saturating_sub(1);
+        self.position = if position > last { last } else { position };
+    }
+
     /// Returns the current position within the scrollable content.
     #[must_use = "returns the current position within the scrollable content"]
     pub const fn get_position(&self) -> usize {
@@ -529,26 +683,87 @@ impl Scrollbar<'_> {
         area: Rect,
         state: &ScrollbarState,
     ) -> impl Iterator<Item = Option<(&str, Style)>> {
-        let (track_start_len, thumb_len, track_end_len) = self.part_lengths(area, state);
-
         let begin = self.begin_symbol.map(|s| Some((s, self.begin_style)));
-        let track = Some(self.track_symbol.map(|s| (s, self.track_style)));
-        let thumb = Some(Some((self.thumb_symbol, self.thumb_style)));
         let end = self.end_symbol.map(|s| Some((s, self.end_style)));
+        let padding = Some(None);
 
         // `<`
         iter::once(begin)
-            // `<═══`
-            .chain(iter::repeat(track).take(track_start_len))
-            // `<═══█████`
-            .chain(iter::repeat(thumb).take(thumb_len))
-            // `<═══█████═══════`
-            .chain(iter::repeat(track).take(track_end_len))
-            // `<═══█████═══════>`
+            // `< `
+            .chain(iter::repeat(padding).take(self.track_padding_start as usize))
+            // `< ═══█████═══════`
+            .chain(self.track_symbols(area, state).map(Some))
+            // `< ═══█████═══════ `
+            .chain(iter::repeat(padding).take(self.track_padding_end as usize))
+            // `< ═══█████═══════ >`
             .chain(iter::once(end))
             .flatten()
     }
 
+    /// Returns the symbols and styles of the track and thumb, excluding the arrow heads and the
+    /// track padding.
+    fn track_symbols(
+        &self,
+        area: Rect,
+        state: &ScrollbarState,
+    ) -> impl Iterator<Item = Option<(&str, Style)>> {
+        let track = self.track_symbol.map(|s| (s, self.track_style));
+        let thumb = Some((self.thumb_symbol, self.thumb_style));
+        let thumb_style = self.thumb_style;
+        let is_vertical = self.orientation.is_vertical();
+        let smooth_symbols = if is_vertical {
+            &SMOOTH_VERTICAL
+        } else {
+            &SMOOTH_HORIZONTAL
+        };
+        let (thumb_start, thumb_end) =
+            self.smooth_thumb_eighths(area, state).unwrap_or_else(|| {
+                // a thumb that is not smooth always covers whole cells
+                let (track_start_len, thumb_len, _) = self.part_lengths(area, state);
+                (track_start_len * 8, (track_start_len + thumb_len) * 8)
+            });
+        let track_length = self.track_length_excluding_arrow_heads(area) as usize;
+        (0..track_length).map(move |cell| {
+            let (cell_start, cell_end) = (cell * 8, cell * 8 + 8);
+            let covered = thumb_end
+                .min(cell_end)
+                .saturating_sub(thumb_start.max(cell_start));
+            match covered {
+                // `═══`
+                0 => track,
+                // `█████`
+                8.. => thumb,
+                // the glyphs fill the bottom or left of the cell, which is the leading end of a
+                // vertical thumb and the trailing end of a horizontal one
+                _ if (thumb_start > cell_start) == is_vertical => {
+                    Some((smooth_symbols.eighths[covered - 1], thumb_style))
+                }
+                _ => Some((
+                    smooth_symbols.eighths[7 - covered],
+                    thumb_style.add_modifier(Modifier::REVERSED),
+                )),
+            }
+        })
+    }
+
+    /// Returns the start and end of a smooth thumb in eighths of a cell, or `None` when the thumb
+    /// is not smooth.
+    ///
+    /// The thumb is at least one cell long.
+    fn smooth_thumb_eighths(&self, area: Rect, state: &ScrollbarState) -> Option<(usize, usize)> {
+        let track_length = self.track_length_excluding_arrow_heads(area) as usize;
+        if !self.smooth || track_length < 3 {
+            return None;
+        }
+        let (thumb_start, thumb_end) = self.thumb_bounds(area, state);
+        let track_eighths = track_length * 8;
+        let thumb_start = (thumb_start * 8.0)
+            .round()
+            .clamp(0.0, (track_eighths - 8) as f64) as usize;
+        let thumb_end = ((thumb_end * 8.0).round() as usize).clamp(thumb_start + 8, track_eighths);
+        Some((thumb_start, thumb_end))
+    }
+
     /// Returns the lengths of the parts of a scrollbar
     ///
     /// The scrollbar has 3 parts of note:
@@ -560,6 +775,34 @@ impl Scrollbar<'_> {
     /// This method returns the length of the start, thumb, and end as a tuple.
     fn part_lengths(&self, area: Rect, state: &ScrollbarState) -> (usize, usize, usize) {
         let track_length = f64::from(self.track_length_excluding_arrow_heads(area));
+        let (thumb_start, thumb_end) = self.thumb_bounds(area, state);
+
+        // Make sure that the thumb is at least 1 cell long by ensuring that the start of the thumb
+        // is less than the track_len. We use the positions instead of the sizes and use nearest
+        // integer instead of floor / ceil to avoid problems caused by rounding errors.
+        let thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
+        let thumb_end = thumb_end.round().clamp(0.0, track_length) as usize;
+
+        let thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
+        let track_end_length = (track_length as usize).saturating_sub(thumb_start + thumb_length);
+
+        (thumb_start, thumb_length, track_end_length)
+    }
+
+    /// Returns the unrounded start and end of the thumb in cells along the track.
+    fn thumb_bounds(&self, area: Rect, state: &ScrollbarState) -> (f64, f64) {
+        let track_length = f64::from(self.track_length_excluding_arrow_heads(area));
+        if let Some(thumb_length) = self.thumb_length {
+            let thumb_length = f64::from(thumb_length.max(1)).min(track_length);
+            let max_position = state.content_length.saturating_sub(1);
+            let progress = if max_position == 0 {
+                0.0
+            } else {
+                state.position.min(max_position) as f64 / max_position as f64
+            };
+            let thumb_start = progress * (track_length - thumb_length);
+            return (thumb_start, thumb_start + thumb_length);
+        }
         let viewport_length = self.viewport_length(state, area) as f64;
 
         // Ensure that the position of the thumb is within the bounds of the content taking into
@@ -574,17 +817,7 @@ impl Scrollbar<'_> {
         // viewport length compared to the total amount of possible visible rows.
         let thumb_start = start_position * track_length / max_viewport_position;
         let thumb_end = end_position * track_length / max_viewport_position;
-
-        // Make sure that the thumb is at least 1 cell long by ensuring that the start of the thumb
-        // is less than the track_len. We use the positions instead of the sizes and use nearest
-        // integer instead of floor / ceil to avoid problems caused by rounding errors.
-        let thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
-        let thumb_end = thumb_end.round().clamp(0.0, track_length) as usize;
-
-        let thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
-        let track_end_length = (track_length as usize).saturating_sub(thumb_start + thumb_length);
-
-        (thumb_start, thumb_length, track_end_length)
+        (thumb_start, thumb_end)
     }
 
     fn scrollbar_area(&self, area: Rect) -> Option<Rect> {
@@ -596,17 +829,21 @@ impl Scrollbar<'_> {
         }
     }
 
-    /// Calculates length of the track excluding the arrow heads
+    /// Calculates length of the track excluding the arrow heads and the track padding
     ///
     /// ```plain
-    ///        ┌────────── track_length
-    ///  vvvvvvvvvvvvvvv
-    /// <═══█████═══════>
+    ///         ┌────────── track_length
+    ///   vvvvvvvvvvvvvvv
+    /// < ═══█████═══════ >
     /// ```
     fn track_length_excluding_arrow_heads(&self, area: Rect) -> u16 {
-        let start_len = self.begin_symbol.map_or(0, |s| s.width() as u16);
-        let end_len = self.end_symbol.map_or(0, |s| s.width() as u16);
-        let arrows_len = start_len.saturating_add(end_len);
+        let width_mode = WidthMode::current();
+        let start_len = self.begin_symbol.map_or(0, |s| width_mode.width(s) as u16);
+        let end_len = self.end_symbol.map_or(0, |s| width_mode.width(s) as u16);
+        let arrows_len = start_len
+            .saturating_add(end_len)
+            .saturating_add(self.track_padding_start)
+            .saturating_add(self.track_padding_end);
         if self.orientation.is_vertical() {
             area.height.saturating_sub(arrows_len)
         } else {
@@ -643,9 +880,10 @@ impl ScrollbarOrientation {
 mod tests {
     use std::str::FromStr;
 
-    use ratatui_core::{text::Text, widgets::Widget};
+    use ratatui_core::{style::Stylize, text::Text, widgets::Widget};
     use rstest::{fixture, rstest};
     use strum::ParseError;
+    use unicode_width::UnicodeWidthStr;
 
     use super::*;
 
@@ -687,6 +925,30 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::forward(3, 8)]
+    #[case::backward(-3, 2)]
+    #[case::before_start(-10, 0)]
+    #[case::past_end(10, 9)]
+    #[case::overflow(isize::MAX, 9)]
+    #[case::underflow(isize::MIN, 0)]
+    fn scroll_by(#[case] delta: isize, #[case] expected: usize) {
+        let mut state = ScrollbarState::new(10).position(5);
+        state.scroll_by(delta);
+        assert_eq!(state.get_position(), expected);
+    }
+
+    #[rstest]
+    #[case::start(0, 10, 0)]
+    #[case::middle(4, 10, 4)]
+    #[case::past_end(20, 10, 9)]
+    #[case::empty(3, 0, 0)]
+    fn scroll_to(#[case] position: usize, #[case] content_length: usize, #[case] expected: usize) {
+        let mut state = ScrollbarState::new(content_length);
+        state.scroll_to(position);
+        assert_eq!(state.get_position(), expected);
+    }
+
     #[fixture]
     fn scrollbar_no_arrows() -> Scrollbar<'static> {
         Scrollbar::new(ScrollbarOrientation::HorizontalTop)
@@ -1071,6 +1333,214 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
 
+    #[rstest]
+    #[case::position_0(["█", "▆", "║", "║"], Some(1), 0)]
+    #[case::position_1(["▆", "▄", "║", "║"], Some(1), 1)]
+    #[case::position_3(["▁", "█", "▇", "║"], Some(2), 3)]
+    #[case::position_9(["║", "║", "▂", "█"], None, 9)]
+    fn render_scrollbar_smooth_vertical(
+        #[case] expected: [&str; 4],
+        #[case] reversed_row: Option<u16>,
+        #[case] position: usize,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 4));
+        let mut state = ScrollbarState::new(10).position(position);
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .smooth(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(expected);
+        if let Some(y) = reversed_row {
+            expected[(0, y)].set_style(Style::new().reversed());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::position_0("█▎══", None, 0)]
+    #[case::position_1("▎▌══", Some(0), 1)]
+    #[case::position_3("▉█▏═", Some(0), 3)]
+    #[case::position_9("══▊█", Some(2), 9)]
+    fn render_scrollbar_smooth_horizontal(
+        #[case] expected: &str,
+        #[case] reversed_column: Option<u16>,
+        #[case] position: usize,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = ScrollbarState::new(10).position(position);
+        Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .smooth(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines([expected]);
+        if let Some(x) = reversed_column {
+            expected[(x, 0)].set_style(Style::new().reversed());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::position_0("#-", 0, 2)]
+    #[case::position_1("-#", 1, 2)]
+    #[case::position_1_of_10("#-", 1, 10)]
+    fn render_scrollbar_smooth_small_track(
+        #[case] expected: &str,
+        #[case] position: usize,
+        #[case] content_length: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let size = expected.width() as u16;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, size, 1));
+        let mut state = ScrollbarState::new(content_length).position(position);
+        scrollbar_no_arrows
+            .smooth(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::position_0("###-------", 0)]
+    #[case::position_2("--###-----", 2)]
+    #[case::position_5("----###---", 5)]
+    #[case::position_9("-------###", 9)]
+    #[case::position_out_of_bounds("-------###", 20)]
+    fn render_scrollbar_with_thumb_length(
+        #[case] expected: &str,
+        #[case] position: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let size = expected.width() as u16;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, size, 1));
+        let mut state = ScrollbarState::new(10).position(position);
+        scrollbar_no_arrows
+            .thumb_length(Some(3))
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::zero("#---", Some(0))]
+    #[case::track_length("####", Some(4))]
+    #[case::larger_than_track("####", Some(10))]
+    #[case::max("####", Some(u16::MAX))]
+    fn render_scrollbar_with_clamped_thumb_length(
+        #[case] expected: &str,
+        #[case] thumb_length: Option<u16>,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let size = expected.width() as u16;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, size, 1));
+        let mut state = ScrollbarState::new(100);
+        scrollbar_no_arrows
+            .thumb_length(thumb_length)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_scrollbar_with_thumb_length_and_arrows() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = ScrollbarState::new(10).position(9);
+        scrollbar_no_arrows()
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .thumb_length(Some(10))
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["<####>"]));
+    }
+
+    #[rstest]
+    #[case::begin_symbol(0, None)]
+    #[case::track_padding(1, None)]
+    #[case::track_start(2, Some(0))]
+    #[case::track_middle(5, Some(50))]
+    #[case::track_end(8, Some(100))]
+    #[case::end_symbol(9, None)]
+    #[case::outside(10, None)]
+    fn position_at_horizontal(#[case] column: u16, #[case] expected: Option<usize>) {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .track_padding(1, 0);
+        let state = ScrollbarState::new(101);
+        let area = Rect::new(0, 5, 10, 3);
+        assert_eq!(scrollbar.position_at(area, &state, column, 7), expected);
+    }
+
+    #[rstest]
+    #[case::begin_symbol(9, 0, None)]
+    #[case::track_start(9, 1, Some(0))]
+    #[case::track_end(9, 3, Some(9))]
+    #[case::end_symbol(9, 4, None)]
+    #[case::other_column(8, 2, None)]
+    fn position_at_vertical(
+        #[case] column: u16,
+        #[case] row: u16,
+        #[case] expected: Option<usize>,
+    ) {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+        let state = ScrollbarState::new(10);
+        let area = Rect::new(0, 0, 10, 5);
+        assert_eq!(scrollbar.position_at(area, &state, column, row), expected);
+    }
+
+    #[rstest]
+    #[case::empty_content(Rect::new(0, 0, 1, 5), 0, None)]
+    #[case::single_cell_track(Rect::new(0, 0, 1, 3), 10, Some(0))]
+    #[case::empty_area(Rect::new(0, 0, 0, 5), 10, None)]
+    fn position_at_edge_cases(
+        #[case] area: Rect,
+        #[case] content_length: usize,
+        #[case] expected: Option<usize>,
+    ) {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalLeft);
+        let state = ScrollbarState::new(content_length);
+        assert_eq!(scrollbar.position_at(area, &state, 0, 1), expected);
+    }
+
+    #[rstest]
+    #[case::position_0(" ####---- ", 0, 10)]
+    #[case::position_5(" --####-- ", 5, 10)]
+    #[case::position_9(" ----#### ", 9, 10)]
+    fn render_scrollbar_with_track_padding(
+        #[case] expected: &str,
+        #[case] position: usize,
+        #[case] content_length: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let size = expected.width() as u16;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, size, 1));
+        let mut state = ScrollbarState::new(content_length).position(position);
+        scrollbar_no_arrows
+            .track_padding(1, 1)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::position_0("< ###--- >", 0, 10)]
+    #[case::position_9("< ---### >", 9, 10)]
+    fn render_scrollbar_with_symbols_and_track_padding(
+        #[case] expected: &str,
+        #[case] position: usize,
+        #[case] content_length: usize,
+    ) {
+        let size = expected.width() as u16;
+        let mut buffer = Buffer::with_lines(["xxxxxxxxxx"]);
+        let mut state = ScrollbarState::new(content_length).position(position);
+        Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .track_padding(1, 1)
+            .render(Rect::new(0, 0, size, 1), &mut buffer, &mut state);
+        let expected = expected.replace(' ', "x");
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
     #[rstest]
     #[case::scrollbar_height_0(10, 0)]
     #[case::scrollbar_width_0(0, 10)]
@@ -0,0 +1,198 @@
+use ratatui_core::{buffer::Buffer, layout::Rect, style::Style, widgets::StatefulWidget};
+
+use super::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+
+/// A vertical and a horizontal [`Scrollbar`] rendered together around a 2D scrollable area.
+///
+/// Rendering two scrollbars separately in the same area makes them overlap in the cell where they
+/// meet. `ScrollbarPair` instead stops the track of each scrollbar one cell short of the shared
+/// corner and draws a corner symbol there, so the thumbs never collide.
+///
+/// ```text
+/// content   ▲
+/// content   █
+/// content   ▼
+/// ◄══███═══►┘
+/// ```
+///
+/// The corner is the cell where the column of the vertical scrollbar and the row of the
+/// horizontal scrollbar cross. It is drawn with a blank symbol by default.
+///
+/// The state of a `ScrollbarPair` is a tuple of the [`ScrollbarState`] of the vertical scrollbar
+/// and the [`ScrollbarState`] of the horizontal scrollbar, in that order.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Scrollbar, ScrollbarOrientation, ScrollbarPair, ScrollbarState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame, area: Rect) {
+/// let scrollbars = ScrollbarPair::new(
+///     Scrollbar::new(ScrollbarOrientation::VerticalRight),
+///     Scrollbar::new(ScrollbarOrientation::HorizontalBottom),
+/// )
+/// .corner_symbol("┘");
+/// let mut state = (
+///     ScrollbarState::new(100).position(10),
+///     ScrollbarState::new(200).position(50),
+/// );
+/// frame.render_stateful_widget(scrollbars, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ScrollbarPair<'a> {
+    vertical: Scrollbar<'a>,
+    horizontal: Scrollbar<'a>,
+    corner_symbol: &'a str,
+    corner_style: Style,
+}
+
+impl<'a> ScrollbarPair<'a> {
+    /// Creates a new pair from a vertical and a horizontal scrollbar.
+    ///
+    /// The orientation of each scrollbar decides on which side of the area it is drawn, and
+    /// therefore which corner they share. `vertical` is expected to have a vertical orientation
+    /// and `horizontal` a horizontal one.
+    #[must_use = "creates the ScrollbarPair"]
+    pub const fn new(vertical: Scrollbar<'a>, horizontal: Scrollbar<'a>) -> Self {
+        debug_assert!(
+            vertical.orientation.is_vertical() && horizontal.orientation.is_horizontal(),
+            "ScrollbarPair expects a vertical and a horizontal scrollbar"
+        );
+        Self {
+            vertical,
+            horizontal,
+            corner_symbol: " ",
+            corner_style: Style::new(),
+        }
+    }
+
+    /// Sets the symbol drawn in the corner shared by the two scrollbars.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn corner_symbol(mut self, corner_symbol: &'a str) -> Self {
+        self.corner_symbol = corner_symbol;
+        self
+    }
+
+    /// Sets the style of the corner shared by the two scrollbars.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn corner_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.corner_style = style.into();
+        self
+    }
+
+    /// Returns the areas of the vertical scrollbar, the horizontal scrollbar and the corner.
+    ///
+    /// Each scrollbar area excludes the row or column of the other scrollbar, so the tracks stop
+    /// one cell short of the corner.
+    fn areas(&self, area: Rect) -> (Rect, Rect, Rect) {
+        let mut vertical = area;
+        let mut horizontal = area;
+        let corner_x = if self.vertical.orientation == ScrollbarOrientation::VerticalLeft {
+            horizontal.x = horizontal.x.saturating_add(1).min(area.right());
+            area.x
+        } else {
+            area.right().saturating_sub(1)
+        };
+        horizontal.width = area.width.saturating_sub(1);
+        let corner_y = if self.horizontal.orientation == ScrollbarOrientation::HorizontalTop {
+            vertical.y = vertical.y.saturating_add(1).min(area.bottom());
+            area.y
+        } else {
+            area.bottom().saturating_sub(1)
+        };
+        vertical.height = area.height.saturating_sub(1);
+        (vertical, horizontal, Rect::new(corner_x, corner_y, 1, 1))
+    }
+}
+
+impl StatefulWidget for ScrollbarPair<'_> {
+    type State = (ScrollbarState, ScrollbarState);
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (vertical_area, horizontal_area, corner) = self.areas(area);
+        self.vertical.render(vertical_area, buf, &mut state.0);
+        self.horizontal.render(horizontal_area, buf, &mut state.1);
+        buf.set_string(corner.x, corner.y, self.corner_symbol, self.corner_style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_scrollbar_pair() {
+        let mut buffer = Buffer::with_lines(["xxxxxxxxxx"; 5]);
+        let mut state = (
+            ScrollbarState::new(8).position(2),
+            ScrollbarState::new(16).position(16),
+        );
+        let pair = ScrollbarPair::new(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(Some("^"))
+                .end_symbol(Some("v"))
+                .track_symbol(Some("|"))
+                .thumb_symbol("#"),
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                .begin_symbol(Some("<"))
+                .end_symbol(Some(">"))
+                .track_symbol(Some("-"))
+                .thumb_symbol("#"),
+        )
+        .corner_symbol("+");
+        pair.render(buffer.area, &mut buffer, &mut state);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "xxxxxxxxx^",
+            "xxxxxxxxx#",
+            "xxxxxxxxx|",
+            "xxxxxxxxxv",
+            "<----###>+",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_scrollbar_pair_top_left() {
+        let mut buffer = Buffer::with_lines(["xxxxx"; 3]);
+        let mut state = (ScrollbarState::new(2), ScrollbarState::new(4));
+        let pair = ScrollbarPair::new(
+            Scrollbar::new(ScrollbarOrientation::VerticalLeft)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("|"))
+                .thumb_symbol("#"),
+            Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("-"))
+                .thumb_symbol("#"),
+        );
+        pair.render(buffer.area, &mut buffer, &mut state);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            " ##--",
+            "#xxxx",
+            "|xxxx",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+}
@@ -30,6 +30,11 @@ use crate::block::{Block, BlockExt};
 /// The bars are rendered using a set of symbols. The default set is [`symbols::bar::NINE_LEVELS`].
 /// You can change the set using [`Sparkline::bar_set`].
 ///
+/// The bars use the full height of the area they are rendered in, so a sparkline rendered over
+/// several rows acts as a compact column chart. Each row holds 8 levels: the rows below the top of
+/// a bar are drawn with the full symbol and the top row with the partial symbol matching the
+/// remaining height.
+///
 /// If the data provided is a slice of `u64` or `Option<u64>`, the bars will be styled with the
 /// style of the sparkline. If the data is a slice of [`SparklineBar`], the bars will be
 /// styled with the style of the sparkline combined with the style provided in the [`SparklineBar`]
@@ -43,6 +48,7 @@ use crate::block::{Block, BlockExt};
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
 /// - [`Sparkline::max`] sets the maximum value of bars
+/// - [`Sparkline::bounds`] sets the range of values mapped to the bar heights
 /// - [`Sparkline::direction`] sets the render direction
 ///
 /// # Examples
@@ -78,6 +84,8 @@ pub struct Sparkline<'a> {
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     max: Option<u64>,
+    /// The value mapped to an empty bar
+    min: u64,
     /// A set of bar symbols used to represent the give data
     bar_set: symbols::bar::Set,
     /// The direction to render the sparkline, either from left to right, or from right to left
@@ -223,6 +231,34 @@ impl<'a> Sparkline<'a> {
         self
     }
 
+    /// Sets the range of values mapped to the bar heights.
+    ///
+    /// `bounds` is the `[min, max]` pair of values drawn as an empty and a full bar. This is useful
+    /// to show the variations of values that stay far from zero, or to plot signed values shifted
+    /// by an offset, the middle of the range then being the zero line. Values below the minimum
+    /// are drawn as empty bars and values above the maximum as full bars. If the maximum is not
+    /// greater than the minimum, all the bars are empty.
+    ///
+    /// This replaces the maximum set with [`Sparkline::max`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Sparkline;
+    ///
+    /// // temperatures in Kelvin, drawn from freezing to boiling point
+    /// let sparkline = Sparkline::default()
+    ///     .data([293, 310, 350])
+    ///     .bounds([273, 373]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds(mut self, bounds: [u64; 2]) -> Self {
+        let [min, max] = bounds;
+        self.min = min;
+        self.max = Some(max);
+        self
+    }
+
     /// Sets the characters used to display the bars.
     ///
     /// Can be [`symbols::bar::THREE_LEVELS`], [`symbols::bar::NINE_LEVELS`] (default) or a custom
@@ -353,10 +389,11 @@ impl Sparkline<'_> {
         if spark_area.is_empty() {
             return;
         }
-        // determine the maximum height across all bars
-        let max_height = self
+        // determine the range of values mapped to the height of the bars
+        let max_value = self
             .max
             .unwrap_or_else(|| self.data.iter().filter_map(|s| s.value).max().unwrap_or(1));
+        let range = max_value.saturating_sub(self.min);
 
         // determine the maximum index to render
         let max_index = min(spark_area.width as usize, self.data.len());
@@ -384,11 +421,10 @@ impl Sparkline<'_> {
                     value: Some(value),
                     style,
                 } => {
-                    let height = if max_height == 0 {
-                        0
-                    } else {
-                        *value * u64::from(spark_area.height) * 8 / max_height
-                    };
+                    let height =
+                        (value.saturating_sub(self.min) * u64::from(spark_area.height) * 8)
+                            .checked_div(range)
+                            .unwrap_or(0);
                     (height, None, *style)
                 }
                 _ => (
@@ -570,6 +606,22 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([" ▁▂▃▄▅▆▇█xxx"]));
     }
 
+    #[test]
+    fn it_draws_within_bounds() {
+        let widget = Sparkline::default()
+            .data([5, 10, 11, 14, 17, 18, 20])
+            .bounds([10, 18]);
+        let buffer = render(widget, 8);
+        assert_eq!(buffer, Buffer::with_lines(["  ▁▄▇██x"]));
+    }
+
+    #[test]
+    fn it_draws_nothing_with_empty_bounds() {
+        let widget = Sparkline::default().data([0, 5, 10]).bounds([5, 5]);
+        let buffer = render(widget, 4);
+        assert_eq!(buffer, Buffer::with_lines(["   x"]));
+    }
+
     #[test]
     fn it_draws_double_height() {
         let widget = Sparkline::default().data([0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -579,6 +631,17 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["     ▂▄▆█xxx", " ▂▄▆█████xxx"]));
     }
 
+    #[test]
+    fn it_fills_the_bottom_row_before_the_top_row() {
+        let widget = Sparkline::default()
+            .data([0, 4, 7, 8, 9, 12, 15, 16])
+            .max(16);
+        let area = Rect::new(0, 0, 8, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    ▁▄▇█", " ▄▇█████"]));
+    }
+
     #[test]
     fn it_renders_left_to_right() {
         let widget = Sparkline::default()
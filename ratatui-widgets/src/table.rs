@@ -6,7 +6,7 @@ use ratatui_core::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Rect},
     style::{Style, Styled},
-    text::Text,
+    text::{Text, TextOverflow},
     widgets::{StatefulWidget, Widget},
 };
 
@@ -254,6 +254,12 @@ pub struct Table<'a> {
     /// Space between each column
     column_spacing: u16,
 
+    /// Whether cell styles also cover the spacing to the next column
+    fill_column_spacing: bool,
+
+    /// How cell contents that do not fit in their column are truncated
+    overflow: TextOverflow<'a>,
+
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
 
@@ -277,6 +283,12 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Number of leading columns that stay in place when scrolling horizontally
+    frozen_columns: usize,
+
+    /// Which columns are shown, by column index. Columns without an entry are shown.
+    visible_columns: Vec<bool>,
 }
 
 impl Default for Table<'_> {
@@ -287,6 +299,8 @@ impl Default for Table<'_> {
             footer: None,
             widths: Vec::new(),
             column_spacing: 1,
+            fill_column_spacing: false,
+            overflow: TextOverflow::Clip,
             block: None,
             style: Style::new(),
             row_highlight_style: Style::new(),
@@ -295,6 +309,8 @@ impl Default for Table<'_> {
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            frozen_columns: 0,
+            visible_columns: Vec::new(),
         }
     }
 }
@@ -481,6 +497,64 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set whether the style of each [`Cell`] also covers the spacing to the next column
+    ///
+    /// The style of a cell always covers the full width of its column, even where the content is
+    /// shorter. By default, the spacing between columns is left with the row style, so rows whose
+    /// cells have different backgrounds show gaps between the columns. When this is `true`, the
+    /// style of each cell (except the last visible one) is extended over the spacing that follows
+    /// it, so the backgrounds join up.
+    ///
+    /// Row styles and the row highlight style always cover the full width of the row, including
+    /// the spacing between columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::Stylize,
+    ///     widgets::{Cell, Row, Table},
+    /// };
+    ///
+    /// let rows = [Row::new([
+    ///     Cell::new("A").on_blue(),
+    ///     Cell::new("B").on_green(),
+    /// ])];
+    /// let table = Table::new(rows, [5, 5]).fill_column_spacing(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fill_column_spacing(mut self, fill: bool) -> Self {
+        self.fill_column_spacing = fill;
+        self
+    }
+
+    /// Set how the content of cells that do not fit in their column is truncated
+    ///
+    /// Defaults to [`TextOverflow::Clip`], which cuts the content at the edge of the column. With
+    /// [`TextOverflow::Ellipsis`], the end of each line that does not fit is replaced by the given
+    /// symbol. This applies to the cells of the rows, the header and the footer.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     text::TextOverflow,
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let rows = [Row::new(["A long cell", "Another long cell"])];
+    /// let table = Table::new(rows, [5, 5]).overflow(TextOverflow::Ellipsis("…"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn overflow(mut self, overflow: TextOverflow<'a>) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
     /// Wraps the table with a custom [`Block`] widget.
     ///
     /// The `block` parameter is of type [`Block`]. This holds the specified block to be
@@ -748,6 +822,72 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Set the number of leading columns that stay in place when scrolling horizontally.
+    ///
+    /// The table can be scrolled horizontally by setting [`TableState::column_offset`], which
+    /// hides that many columns after the frozen columns. This is useful for keeping row labels
+    /// visible in wide tables.
+    ///
+    /// When scrolled, the table is laid out as if the hidden columns did not exist: the frozen
+    /// columns and the remaining visible columns are laid out together using their own
+    /// constraints, [`Flex`] and column spacing. Frozen columns with fixed size constraints (e.g.
+    /// [`Constraint::Length`]) therefore keep their natural width while scrolling, while frozen
+    /// columns with flexible constraints (e.g. [`Constraint::Fill`]) share the available space
+    /// with the visible scrolling columns. Hidden columns are not rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let rows = [Row::new(["Mon", "8", "12", "3", "7"])];
+    /// let table = Table::new(rows, [5; 5]).frozen_columns(1);
+    /// // show the first column, then the third column onwards
+    /// let mut state = TableState::new().with_column_offset(1);
+    /// ```
+    ///
+    /// [`TableState::column_offset`]: crate::table::TableState::column_offset
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
+    /// Set which columns of the table are shown
+    ///
+    /// Each entry of `visible` corresponds to the column at the same index, and columns without an
+    /// entry are shown. Hidden columns are skipped when rendering and take no space (including
+    /// column spacing), so the remaining columns are laid out as if the hidden columns did not
+    /// exist. The [`widths`](Table::widths) and the cells of each [`Row`] are still indexed by the
+    /// original column, so columns can be toggled without rebuilding the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let rows = [Row::new(["Name", "Size", "Modified"])];
+    /// let widths = [
+    ///     Constraint::Length(10),
+    ///     Constraint::Length(6),
+    ///     Constraint::Length(10),
+    /// ];
+    /// // hide the "Size" column
+    /// let table = Table::new(rows, widths).visible_columns(&[true, false, true]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn visible_columns(mut self, visible: &[bool]) -> Self {
+        self.visible_columns = visible.to_vec();
+        self
+    }
 }
 
 impl Widget for Table<'_> {
@@ -797,16 +937,33 @@ impl StatefulWidget for &Table<'_> {
         if column_count == 0 {
             state.select_column(None);
         }
+        let max_column_offset = column_count.saturating_sub(self.frozen_columns.saturating_add(1));
+        state.column_offset = state.column_offset.min(max_column_offset);
 
         let selection_width = self.selection_width(state);
-        let column_widths = self.get_column_widths(table_area.width, selection_width, column_count);
+        let column_widths = self.get_scrolled_column_widths(
+            table_area.width,
+            selection_width,
+            column_count,
+            state.column_offset,
+        );
         let (header_area, rows_area, footer_area) = self.layout(table_area);
 
-        self.render_header(header_area, buf, &column_widths);
+        // clips the widgets of the cells, allocated on the first widget cell and then reused
+        let mut scratch = Buffer::default();
+
+        self.render_header(header_area, buf, &column_widths, &mut scratch);
 
-        self.render_rows(rows_area, buf, state, selection_width, &column_widths);
+        self.render_rows(
+            rows_area,
+            buf,
+            state,
+            selection_width,
+            &column_widths,
+            &mut scratch,
+        );
 
-        self.render_footer(footer_area, buf, &column_widths);
+        self.render_footer(footer_area, buf, &column_widths, &mut scratch);
     }
 }
 
@@ -834,20 +991,57 @@ impl Table<'_> {
         (header_area, rows_area, footer_area)
     }
 
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_header(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[(u16, u16)],
+        scratch: &mut Buffer,
+    ) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
-            }
+            self.render_cells(area, buf, column_widths, &header.cells, scratch);
         }
     }
 
-    fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_footer(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[(u16, u16)],
+        scratch: &mut Buffer,
+    ) {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
-            for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            self.render_cells(area, buf, column_widths, &footer.cells, scratch);
+        }
+    }
+
+    /// Renders the cells of a row in their columns, filling the column spacing with the cell
+    /// styles if enabled
+    fn render_cells(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[(u16, u16)],
+        cells: &[Cell],
+        scratch: &mut Buffer,
+    ) {
+        for (i, ((x, width), cell)) in column_widths.iter().zip(cells).enumerate() {
+            let cell_area = Rect::new(area.x + x, area.y, *width, area.height);
+            cell.render(cell_area, buf, self.overflow, scratch);
+            if !self.fill_column_spacing {
+                continue;
+            }
+            if let (Some((next_x, _)), Some(_)) = (column_widths.get(i + 1), cells.get(i + 1)) {
+                let end = x + width;
+                let spacing = Rect::new(
+                    area.x + end,
+                    area.y,
+                    next_x.saturating_sub(end),
+                    area.height,
+                );
+                buf.set_style(spacing, Styled::style(cell));
             }
         }
     }
@@ -859,6 +1053,7 @@ impl Table<'_> {
         state: &mut TableState,
         selection_width: u16,
         columns_widths: &[(u16, u16)],
+        scratch: &mut Buffer,
     ) {
         if self.rows.is_empty() {
             return;
@@ -891,16 +1086,14 @@ impl Table<'_> {
                 buf.set_style(selection_area, row.style);
                 (&self.highlight_symbol).render(selection_area, buf);
             };
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
-                );
-            }
+            self.render_cells(row_area, buf, columns_widths, &row.cells, scratch);
             if is_selected {
                 selected_row_area = Some(row_area);
             }
-            y_offset += row.height_with_margin();
+            if Self::is_expanded(state, i) {
+                Self::render_detail(row, area, y + row.height, buf, selection_width);
+            }
+            y_offset += self.row_height(state, i);
         }
 
         let selected_column_area = state.selected_column.and_then(|s| {
@@ -944,23 +1137,29 @@ impl Table<'_> {
         let mut end = start;
         let mut height = 0;
 
-        for item in self.rows.iter().skip(start) {
-            if height + item.height > area.height {
+        for (index, item) in self.rows.iter().enumerate().skip(start) {
+            let detail_height = if Self::is_expanded(state, index) {
+                item.detail_height()
+            } else {
+                0
+            };
+            if height + item.height + detail_height > area.height {
                 break;
             }
-            height += item.height_with_margin();
+            height += self.row_height(state, index);
             end += 1;
         }
 
         if let Some(selected) = state.selected {
             let selected = selected.min(last_row);
 
-            // scroll down until the selected row is visible
+            // scroll down until the selected row is visible, keeping at least the selected row
+            // when it is taller than the area
             while selected >= end {
-                height = height.saturating_add(self.rows[end].height_with_margin());
+                height = height.saturating_add(self.row_height(state, end));
                 end += 1;
-                while height > area.height {
-                    height = height.saturating_sub(self.rows[start].height_with_margin());
+                while height > area.height && start < selected {
+                    height = height.saturating_sub(self.row_height(state, start));
                     start += 1;
                 }
             }
@@ -968,10 +1167,10 @@ impl Table<'_> {
             // scroll up until the selected row is visible
             while selected < start {
                 start -= 1;
-                height = height.saturating_add(self.rows[start].height_with_margin());
-                while height > area.height {
+                height = height.saturating_add(self.row_height(state, start));
+                while height > area.height && end > start + 1 {
                     end -= 1;
-                    height = height.saturating_sub(self.rows[end].height_with_margin());
+                    height = height.saturating_sub(self.row_height(state, end));
                 }
             }
         }
@@ -984,6 +1183,42 @@ impl Table<'_> {
         (start, end)
     }
 
+    /// Returns whether the detail of the row at `index` is displayed.
+    fn is_expanded(state: &TableState, index: usize) -> bool {
+        state.selected == Some(index) || state.expanded == Some(index)
+    }
+
+    /// Returns the height of the row at `index`, including its margins and its detail if the row
+    /// is expanded.
+    fn row_height(&self, state: &TableState, index: usize) -> u16 {
+        let row = &self.rows[index];
+        let height = row.height_with_margin();
+        if Self::is_expanded(state, index) {
+            height.saturating_add(row.detail_height())
+        } else {
+            height
+        }
+    }
+
+    /// Renders the detail of `row` at `y`, clipped to `area`.
+    fn render_detail(row: &Row, area: Rect, y: u16, buf: &mut Buffer, selection_width: u16) {
+        let Some(detail) = &row.detail else {
+            return;
+        };
+        if y >= area.bottom() {
+            return;
+        }
+        let height = row.detail_height().min(area.bottom() - y);
+        let detail_area = Rect { y, height, ..area };
+        buf.set_style(detail_area, row.style);
+        let detail_area = Rect {
+            x: area.x + selection_width,
+            width: area.width.saturating_sub(selection_width),
+            ..detail_area
+        };
+        detail.render(detail_area, buf);
+    }
+
     /// Get all offsets and widths of all user specified columns.
     ///
     /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
@@ -994,12 +1229,78 @@ impl Table<'_> {
         selection_width: u16,
         col_count: usize,
     ) -> Vec<(u16, u16)> {
-        let widths = if self.widths.is_empty() {
+        let widths = self.column_constraints(max_width, col_count);
+        self.layout_columns(widths, max_width, selection_width)
+    }
+
+    /// Get all offsets and widths of the columns, taking frozen columns, the horizontal scroll
+    /// offset and the visible columns into account.
+    ///
+    /// The `column_offset` columns following the frozen columns and the columns hidden by
+    /// [`Table::visible_columns`] are hidden. The remaining columns are laid out as if the hidden
+    /// columns did not exist, and the hidden columns are returned with a width of 0 at the
+    /// position of the next shown column.
+    fn get_scrolled_column_widths(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        col_count: usize,
+        column_offset: usize,
+    ) -> Vec<(u16, u16)> {
+        let widths = self.column_constraints(max_width, col_count);
+        let frozen = self.frozen_columns.min(widths.len());
+        let scrolled = frozen..frozen + column_offset.min(widths.len() - frozen);
+        let is_shown = |index: usize| {
+            !scrolled.contains(&index) && self.visible_columns.get(index).copied().unwrap_or(true)
+        };
+        if (0..widths.len()).all(is_shown) {
+            return self.get_column_widths(max_width, selection_width, col_count);
+        }
+        let shown_widths = widths
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| is_shown(*index))
+            .map(|(_, constraint)| *constraint)
+            .collect();
+        let mut shown_column_widths = self
+            .layout_columns(shown_widths, max_width, selection_width)
+            .into_iter()
+            .rev();
+        let mut next_x = 0;
+        let mut column_widths = (0..widths.len())
+            .rev()
+            .map(|index| {
+                if is_shown(index) {
+                    let (x, width) = shown_column_widths.next().unwrap_or_default();
+                    next_x = x;
+                    (x, width)
+                } else {
+                    (next_x, 0)
+                }
+            })
+            .collect::<Vec<_>>();
+        column_widths.reverse();
+        column_widths
+    }
+
+    /// Returns the user specified column constraints, or equal widths when `.widths()` has not
+    /// been called.
+    fn column_constraints(&self, max_width: u16, col_count: usize) -> Vec<Constraint> {
+        if self.widths.is_empty() {
             // Divide the space between each column equally
             vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
         } else {
             self.widths.clone()
-        };
+        }
+    }
+
+    /// Lays out the given column constraints in the area to the right of the selection column.
+    fn layout_columns(
+        &self,
+        widths: Vec<Constraint>,
+        max_width: u16,
+        selection_width: u16,
+    ) -> Vec<(u16, u16)> {
         // this will always allocate a selection area
         let [_selection_area, columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
@@ -1100,6 +1401,7 @@ mod tests {
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.flex, Flex::Start);
+        assert_eq!(table.frozen_columns, 0);
     }
 
     #[test]
@@ -1116,6 +1418,7 @@ mod tests {
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.flex, Flex::Start);
+        assert_eq!(table.frozen_columns, 0);
     }
 
     #[test]
@@ -1167,6 +1470,18 @@ mod tests {
         assert_eq!(table.rows, rows);
     }
 
+    #[test]
+    fn frozen_columns() {
+        let table = Table::default().frozen_columns(2);
+        assert_eq!(table.frozen_columns, 2);
+    }
+
+    #[test]
+    fn visible_columns() {
+        let table = Table::default().visible_columns(&[true, false, true]);
+        assert_eq!(table.visible_columns, [true, false, true]);
+    }
+
     #[test]
     fn column_spacing() {
         let table = Table::default().column_spacing(2);
@@ -1351,6 +1666,7 @@ mod tests {
         use ratatui_core::layout::Alignment;
 
         use super::*;
+        use crate::gauge::LineGauge;
 
         #[test]
         fn render_empty_area() {
@@ -1554,6 +1870,75 @@ mod tests {
             StatefulWidget::render(table, Rect::new(0, 0, 20, 3), &mut buf, &mut state);
         }
 
+        #[rstest]
+        #[case::not_scrolled(0, 0, ["A1 B1 C1  D1 E1", "A2 B2 C2  D2 E2"])]
+        #[case::scrolled(1, 1, ["A1  B1  D1  E1 ", "A2  B2  D2  E2 "])]
+        #[case::scrolled_to_last(2, 2, ["A1  B1  E1     ", "A2  B2  E2     "])]
+        #[case::clamped(5, 2, ["A1  B1  E1     ", "A2  B2  E2     "])]
+        fn render_with_frozen_columns(
+            #[case] column_offset: usize,
+            #[case] expected_offset: usize,
+            #[case] expected: [&str; 2],
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![
+                Row::new(vec!["A1", "B1", "C1", "D1", "E1"]),
+                Row::new(vec!["A2", "B2", "C2", "D2", "E2"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(3); 5]).frozen_columns(2);
+            let mut state = TableState::new().with_column_offset(column_offset);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(expected));
+            assert_eq!(state.column_offset, expected_offset);
+        }
+
+        #[rstest]
+        #[case::all_shown(&[], ["A1  B1 C1     ", "Aaa Bb Cccc   "])]
+        #[case::middle_hidden(&[true, false, true], ["A1  C1        ", "Aaa Cccc      "])]
+        #[case::first_hidden(&[false], ["B1 C1         ", "Bb Cccc       "])]
+        #[case::last_hidden(&[true, true, false], ["A1  B1        ", "Aaa Bb        "])]
+        #[case::all_hidden(&[false; 3], ["              ", "              "])]
+        fn render_with_visible_columns(#[case] visible: &[bool], #[case] expected: [&str; 2]) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 2));
+            let rows = vec![
+                Row::new(vec!["A1", "B1", "C1"]),
+                Row::new(vec!["Aaa", "Bb", "Cccc"]),
+            ];
+            let table =
+                Table::new(rows, [Length(3), Length(2), Length(4)]).visible_columns(visible);
+            Widget::render(table, Rect::new(0, 0, 14, 2), &mut buf);
+            assert_eq!(buf, Buffer::with_lines(expected));
+        }
+
+        #[test]
+        fn render_with_visible_columns_and_frozen_columns() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 1));
+            let rows = vec![Row::new(vec!["A1", "B1", "C1", "D1", "E1"])];
+            let table = Table::new(rows, [Constraint::Length(3); 5])
+                .frozen_columns(1)
+                .visible_columns(&[true, true, true, false]);
+            let mut state = TableState::new().with_column_offset(1);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 1), &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["A1  C1  E1     "]));
+        }
+
+        #[test]
+        fn render_with_frozen_columns_header_and_selection() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![Row::new(vec!["A1", "B1", "C1", "D1", "E1"])];
+            let table = Table::new(rows, [Length(2), Length(2), Fill(1), Fill(1), Fill(1)])
+                .header(Row::new(vec!["A", "B", "C", "D", "E"]))
+                .frozen_columns(2)
+                .column_highlight_style(Style::new().red());
+            let mut state = TableState::new()
+                .with_column_offset(2)
+                .with_selected_column(4);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["A  B  E        ", "A1 B1 E1       "]);
+            expected.set_style(Rect::new(6, 1, 9, 1), Style::new().red());
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_selected() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1574,6 +1959,74 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_selected_row_highlights_column_spacing() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![
+                Row::new(vec![Cell::new("A").on_blue(), Cell::new("B").on_green()]),
+                Row::new(vec!["C", "D"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .column_spacing(2)
+                .row_highlight_style(Style::new().on_red());
+            let mut state = TableState::new().with_selected(Some(0));
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            let expected =
+                Buffer::with_lines(["A      B       ".on_red(), "C      D       ".into()]);
+            assert_eq!(buf, expected);
+        }
+
+        #[rstest]
+        #[case::disabled(false, Rect::new(5, 0, 2, 1))]
+        #[case::enabled(true, Rect::ZERO)]
+        fn render_with_fill_column_spacing(#[case] fill: bool, #[case] gap: Rect) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 1));
+            let rows = vec![Row::new(vec![
+                Cell::new("A").on_blue(),
+                Cell::new("B").on_green(),
+            ])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .column_spacing(2)
+                .fill_column_spacing(fill);
+            Widget::render(table, Rect::new(0, 0, 15, 1), &mut buf);
+            let mut expected = Buffer::with_lines(["A      B       "]);
+            expected.set_style(Rect::new(0, 0, 7, 1), Style::new().on_blue());
+            expected.set_style(Rect::new(7, 0, 5, 1), Style::new().on_green());
+            expected.set_style(gap, Style::new().bg(Color::Reset));
+            assert_eq!(buf, expected);
+        }
+
+        #[rstest]
+        #[case::clip(TextOverflow::Clip, ["Hello Short ", "Lorem Two   "])]
+        #[case::ellipsis(TextOverflow::Ellipsis("…"), ["Hell… Short ", "Lore… Two   "])]
+        fn render_with_overflow(#[case] overflow: TextOverflow, #[case] expected: [&str; 2]) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+            let rows = vec![
+                Row::new(vec!["Hello world", "Short"]),
+                Row::new(vec!["Lorem ipsum", "Two"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]).overflow(overflow);
+            Widget::render(table, Rect::new(0, 0, 12, 2), &mut buf);
+            assert_eq!(buf, Buffer::with_lines(expected));
+        }
+
+        #[test]
+        fn render_with_widget_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+            let gauge = LineGauge::default()
+                .ratio(0.5)
+                .label("")
+                .filled_symbol("#")
+                .unfilled_symbol("-");
+            let rows = vec![
+                Row::new(vec![Cell::new("CPU"), Cell::from_widget(gauge)]),
+                Row::new(vec!["Mem", "Text"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(3), Constraint::Length(8)]);
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["CPU  ###----", "Mem Text    "]));
+        }
+
         #[test]
         fn render_with_selected_column() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1673,6 +2126,65 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[rstest]
+        #[case::collapsed(TableState::new(), [
+            "  a    ",
+            "  b    ",
+            "  c    ",
+            "       ",
+            "       ",
+        ])]
+        #[case::selected(TableState::new().with_selected(1), [
+            "  a    ",
+            ">>b    ",
+            "  pid 2",
+            "  cpu 5",
+            "  c    ",
+        ])]
+        #[case::expanded(TableState::new().with_expanded(1), [
+            "  a    ",
+            "  b    ",
+            "  pid 2",
+            "  cpu 5",
+            "  c    ",
+        ])]
+        #[case::selected_and_expanded(TableState::new().with_selected(0).with_expanded(1), [
+            ">>a    ",
+            "  b    ",
+            "  pid 2",
+            "  cpu 5",
+            "  c    ",
+        ])]
+        fn render_with_detail(#[case] mut state: TableState, #[case] expected: [&str; 5]) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 5));
+            let rows = vec![
+                Row::new(["a"]),
+                Row::new(["b"]).detail("pid 2\ncpu 5"),
+                Row::new(["c"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .highlight_symbol(">>")
+                .highlight_spacing(HighlightSpacing::Always);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(expected));
+        }
+
+        #[test]
+        fn render_with_detail_taller_than_the_table() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let rows = vec![
+                Row::new(["a"]),
+                Row::new(["b"]),
+                Row::new(["c"]).detail("1\n2\n3\n4"),
+                Row::new(["d"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5)]).highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(2);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines([">>c    ", "  1    ", "  2    "]));
+            assert_eq!(state.offset(), 2);
+        }
+
         /// Note that this includes a regression test for a bug where the table would not render the
         /// correct rows when there is no selection.
         /// <https://github.com/ratatui/ratatui/issues/1179>
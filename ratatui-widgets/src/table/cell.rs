@@ -1,8 +1,16 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+    sync::Arc,
+};
+
 use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
     style::{Style, Styled},
-    text::Text,
+    text::{Line, Span, Text, TextOverflow},
     widgets::Widget,
 };
 
@@ -15,6 +23,8 @@ use ratatui_core::{
 ///
 /// You can use [`Text::alignment`] when creating a cell to align its content.
 ///
+/// A cell can also display any widget instead of text, see [`Cell::from_widget`].
+///
 /// # Examples
 ///
 /// You can create a `Cell` from anything that can be converted to a [`Text`].
@@ -54,6 +64,35 @@ use ratatui_core::{
 pub struct Cell<'a> {
     content: Text<'a>,
     style: Style,
+    widget: Option<CellWidget<'a>>,
+}
+
+/// A widget rendered in a [`Cell`] in place of its text.
+///
+/// The widget is shared between clones of the cell. Widgets cannot be compared, so a cell widget is
+/// never equal to another one (nor to itself) and does not contribute to the hash of the cell.
+#[derive(Clone)]
+struct CellWidget<'a>(Arc<RenderFn<'a>>);
+
+/// Renders a widget in the given area.
+type RenderFn<'a> = dyn Fn(Rect, &mut Buffer) + Send + Sync + 'a;
+
+impl fmt::Debug for CellWidget<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CellWidget")
+    }
+}
+
+impl PartialEq for CellWidget<'_> {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for CellWidget<'_> {}
+
+impl Hash for CellWidget<'_> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
 }
 
 impl<'a> Cell<'a> {
@@ -85,12 +124,55 @@ impl<'a> Cell<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            widget: None,
+        }
+    }
+
+    /// Creates a new [`Cell`] that renders a widget instead of text.
+    ///
+    /// The widget can be any [`Widget`] that implements [`Clone`], [`Send`] and [`Sync`], such as a
+    /// [`Sparkline`] or a [`Gauge`]. It is cloned and rendered in the area of the cell each
+    /// time the table is rendered, and it is clipped to the width of the column and the height
+    /// of the row: anything it draws outside of the cell is discarded. The [`Cell::style`]
+    /// is applied to the area of the cell before the widget is rendered.
+    ///
+    /// The overflow of the table doesn't apply to widgets. Setting the [`Cell::content`] replaces
+    /// the widget.
+    ///
+    /// Widgets cannot be compared, so a cell created from a widget is never equal to another cell,
+    /// including its own clones. This also applies to the [`Row`] and [`Table`] containing it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Cell, Gauge, Row, Sparkline};
+    ///
+    /// let row = Row::new([
+    ///     Cell::new("CPU"),
+    ///     Cell::from_widget(Sparkline::default().data([1, 4, 2, 8])),
+    ///     Cell::from_widget(Gauge::default().percent(40)),
+    /// ]);
+    /// ```
+    ///
+    /// [`Sparkline`]: crate::sparkline::Sparkline
+    /// [`Gauge`]: crate::gauge::Gauge
+    pub fn from_widget<W>(widget: W) -> Self
+    where
+        W: Widget + Clone + Send + Sync + 'a,
+    {
+        Self {
+            content: Text::default(),
+            style: Style::default(),
+            widget: Some(CellWidget(Arc::new(move |area, buf| {
+                widget.clone().render(area, buf);
+            }))),
         }
     }
 
     /// Set the content of the [`Cell`]
     ///
-    /// The `content` parameter accepts any value that can be converted into a [`Text`].
+    /// The `content` parameter accepts any value that can be converted into a [`Text`]. This
+    /// replaces the widget of a cell created with [`Cell::from_widget`].
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -117,6 +199,7 @@ impl<'a> Cell<'a> {
         T: Into<Text<'a>>,
     {
         self.content = content.into();
+        self.widget = None;
         self
     }
 
@@ -161,9 +244,56 @@ impl<'a> Cell<'a> {
 }
 
 impl Cell<'_> {
-    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+    /// Renders the cell in `area`.
+    ///
+    /// A widget is clipped to the cell by rendering it into `scratch`, which is resized to the area
+    /// of the cell. The cells of `buf` are swapped in and out of it, and its allocation is reused
+    /// between the cells of a table.
+    pub(crate) fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        overflow: TextOverflow,
+        scratch: &mut Buffer,
+    ) {
         buf.set_style(area, self.style);
-        Widget::render(&self.content, area, buf);
+        if let Some(CellWidget(render)) = &self.widget {
+            let area = area.intersection(buf.area);
+            scratch.resize(area);
+            for position in area.positions() {
+                mem::swap(&mut scratch[position], &mut buf[position]);
+            }
+            render(area, scratch);
+            for position in area.positions() {
+                mem::swap(&mut scratch[position], &mut buf[position]);
+            }
+            return;
+        }
+        match overflow {
+            TextOverflow::Clip => Widget::render(&self.content, area, buf),
+            TextOverflow::Ellipsis(_) => {
+                let lines = self.content.lines.iter();
+                let content = Text {
+                    alignment: self.content.alignment,
+                    style: self.content.style,
+                    lines: lines
+                        .map(|line| match overflow.truncate(line, area.width) {
+                            // borrow the spans instead of cloning their content
+                            Cow::Borrowed(line) => Line {
+                                spans: line
+                                    .spans
+                                    .iter()
+                                    .map(|span| Span::styled(span.content.as_ref(), span.style))
+                                    .collect(),
+                                ..*line
+                            },
+                            Cow::Owned(line) => line,
+                        })
+                        .collect(),
+                };
+                Widget::render(&content, area, buf);
+            }
+        }
     }
 }
 
@@ -175,6 +305,7 @@ where
         Self {
             content: content.into(),
             style: Style::default(),
+            widget: None,
         }
     }
 }
@@ -209,6 +340,50 @@ mod tests {
         assert_eq!(cell.content, Text::from(""));
     }
 
+    #[test]
+    fn from_widget() {
+        let cell = Cell::from_widget(Text::from("widget"));
+        assert_eq!(cell.content, Text::default());
+        assert!(cell.widget.is_some());
+        // widgets cannot be compared, so widget cells are never equal
+        assert_ne!(cell.clone(), cell);
+        assert_ne!(cell, Cell::from_widget(Text::from("widget")));
+        assert_ne!(cell, Cell::from("widget"));
+        assert!(cell.content("text").widget.is_none());
+    }
+
+    #[test]
+    fn render_widget() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        let cell = Cell::from_widget(Text::from("ab\ncd\nef")).on_blue();
+        let mut scratch = Buffer::default();
+        cell.render(
+            Rect::new(1, 0, 4, 1),
+            &mut buf,
+            TextOverflow::Clip,
+            &mut scratch,
+        );
+        let mut expected = Buffer::with_lines([" ab   ", "      "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), Style::new().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_widget_clipped() {
+        #[derive(Clone)]
+        struct Overflowing;
+        impl Widget for Overflowing {
+            fn render(self, area: Rect, buf: &mut Buffer) {
+                buf.set_string(area.x, area.y, "overflowing", Style::new());
+            }
+        }
+        let mut buf = Buffer::with_lines(["xxxxxxxx"]);
+        let mut scratch = Buffer::default();
+        let area = Rect::new(2, 0, 3, 1);
+        Cell::from_widget(Overflowing).render(area, &mut buf, TextOverflow::Clip, &mut scratch);
+        assert_eq!(buf, Buffer::with_lines(["xxovexxx"]));
+    }
+
     #[test]
     fn style() {
         let style = Style::default().red().italic();
@@ -1,4 +1,7 @@
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::{
+    style::{Style, Styled},
+    text::Text,
+};
 
 use super::Cell;
 
@@ -77,6 +80,7 @@ pub struct Row<'a> {
     pub(crate) top_margin: u16,
     pub(crate) bottom_margin: u16,
     pub(crate) style: Style,
+    pub(crate) detail: Option<Text<'a>>,
 }
 
 impl<'a> Row<'a> {
@@ -239,6 +243,34 @@ impl<'a> Row<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set a detail panel displayed under the row when it is expanded
+    ///
+    /// The detail is rendered beneath the cells of the row (before its bottom margin) when the row
+    /// is selected, or when it is expanded with [`TableState::expand`]. It spans all the columns,
+    /// takes as many lines as the detail [`Text`] has, and pushes the following rows down. It uses
+    /// the style of the row, but is not highlighted when the row is selected.
+    ///
+    /// If the expanded row and its detail do not fit in the table, the table is scrolled so that
+    /// the row is the first visible one and the detail is clipped at the bottom of the table.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Row;
+    ///
+    /// let row = Row::new(vec!["1234", "top", "2.5%"]).detail("command: top -d 1\nuser: root");
+    /// ```
+    ///
+    /// [`TableState::expand`]: crate::table::TableState::expand
+    /// [`Text`]: ratatui_core::text::Text
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn detail<T: Into<Text<'a>>>(mut self, detail: T) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
 }
 
 // private methods for rendering
@@ -249,6 +281,13 @@ impl Row<'_> {
             .saturating_add(self.top_margin)
             .saturating_add(self.bottom_margin)
     }
+
+    /// Returns the number of lines of the detail of the row.
+    pub(crate) fn detail_height(&self) -> u16 {
+        self.detail.as_ref().map_or(0, |detail| {
+            u16::try_from(detail.height()).unwrap_or(u16::MAX)
+        })
+    }
 }
 
 impl Styled for Row<'_> {
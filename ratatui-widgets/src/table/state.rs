@@ -5,15 +5,21 @@
 /// table will be shifted to ensure that the selected row is visible. This will modify the
 /// [`TableState`] object passed to the `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of the following fields:
 /// - [`offset`]: the index of the first row to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 /// - [`selected_column`]: the index of the selected column, which can be `None` if no column is
 ///   selected
+/// - [`column_offset`]: the number of columns hidden after the frozen columns when scrolling
+///   horizontally
+/// - [`expanded`]: the index of a row whose [detail](crate::table::Row::detail) is displayed even
+///   when it is not selected
 ///
 /// [`offset`]: TableState::offset()
 /// [`selected`]: TableState::selected()
 /// [`selected_column`]: TableState::selected_column()
+/// [`column_offset`]: TableState::column_offset()
+/// [`expanded`]: TableState::expanded()
 ///
 /// See the `table` example and the `recipe` and `traceroute` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
@@ -58,6 +64,10 @@ pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
     pub(crate) selected_column: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) column_offset: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) expanded: Option<usize>,
 }
 
 impl TableState {
@@ -75,6 +85,8 @@ impl TableState {
             offset: 0,
             selected: None,
             selected_column: None,
+            column_offset: 0,
+            expanded: None,
         }
     }
 
@@ -95,6 +107,25 @@ impl TableState {
         self
     }
 
+    /// Sets the number of columns to scroll past horizontally
+    ///
+    /// See [`TableState::column_offset`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_column_offset(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
     /// Sets the index of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -160,6 +191,28 @@ impl TableState {
         self
     }
 
+    /// Sets the index of the expanded row
+    ///
+    /// See [`TableState::expand`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_expanded(Some(1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_expanded<T>(mut self, expanded: T) -> Self
+    where
+        T: Into<Option<usize>>,
+    {
+        self.expanded = expanded.into();
+        self
+    }
+
     /// Index of the first row to be displayed
     ///
     /// # Examples
@@ -188,6 +241,40 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Number of columns hidden after the frozen columns
+    ///
+    /// The table is scrolled horizontally by hiding this many columns after the columns frozen
+    /// with [`Table::frozen_columns`]. The offset is clamped when rendering so that at least one
+    /// scrolling column stays visible. It is not adjusted to keep the selected column visible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_offset(), 0);
+    /// ```
+    ///
+    /// [`Table::frozen_columns`]: crate::table::Table::frozen_columns
+    pub const fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Mutable reference to the number of columns hidden after the frozen columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// *state.column_offset_mut() = 1;
+    /// ```
+    pub fn column_offset_mut(&mut self) -> &mut usize {
+        &mut self.column_offset
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -237,6 +324,23 @@ impl TableState {
         None
     }
 
+    /// Index of the expanded row
+    ///
+    /// Returns `None` if no row is explicitly expanded. The selected row is always expanded, even
+    /// if it is not returned here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.expanded(), None);
+    /// ```
+    pub const fn expanded(&self) -> Option<usize> {
+        self.expanded
+    }
+
     /// Mutable reference to the index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -287,6 +391,23 @@ impl TableState {
         }
     }
 
+    /// Sets the index of the expanded row
+    ///
+    /// The [detail](crate::table::Row::detail) of the expanded row is displayed under it, in
+    /// addition to the detail of the selected row. Set to `None` to only expand the selected row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.expand(Some(1));
+    /// ```
+    pub fn expand(&mut self, index: Option<usize>) {
+        self.expanded = index;
+    }
+
     /// Sets the index of the selected column
     ///
     /// # Examples
@@ -553,6 +674,19 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn with_column_offset() {
+        let state = TableState::new().with_column_offset(2);
+        assert_eq!(state.column_offset, 2);
+    }
+
+    #[test]
+    fn column_offset_mut() {
+        let mut state = TableState::new();
+        *state.column_offset_mut() = 3;
+        assert_eq!(state.column_offset(), 3);
+    }
+
     #[test]
     fn with_selected() {
         let state = TableState::new().with_selected(Some(1));
@@ -1,11 +1,13 @@
 //! The [`Tabs`] widget displays a horizontal set of tabs with a single tab selected.
+use std::borrow::Cow;
+
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
     style::{Modifier, Style, Styled},
     symbols::{self},
-    text::{Line, Span},
+    text::{Line, Span, TextOverflow, WidthMode},
     widgets::Widget,
 };
 
@@ -13,12 +15,17 @@ use crate::block::{Block, BlockExt};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
 
+/// The close button rendered after each title of closable tabs, including its leading space
+const CLOSE_BUTTON: &str = " ✕";
+
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
 ///
 /// Each tab title is stored as a [`Line`] which can be individually styled. The selected tab is set
-/// using [`Tabs::select`] and styled using [`Tabs::highlight_style`]. The divider can be customized
+/// using [`Tabs::select`] and styled using [`Tabs::highlight_style`]. An underline can be drawn
+/// below the selected tab with [`Tabs::highlight_underline`]. The divider can be customized
 /// with [`Tabs::divider`]. Padding can be set with [`Tabs::padding`] or [`Tabs::padding_left`] and
-/// [`Tabs::padding_right`].
+/// [`Tabs::padding_right`]. A close button can be shown after each title with
+/// [`Tabs::closable`].
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
@@ -60,12 +67,18 @@ pub struct Tabs<'a> {
     style: Style,
     /// Style to apply to the selected item
     highlight_style: Style,
+    /// Style of the underline drawn below the selected item
+    highlight_underline: Option<Style>,
     /// Tab divider
     divider: Span<'a>,
     /// Tab Left Padding
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// How titles that do not fit are truncated
+    overflow: TextOverflow<'a>,
+    /// Whether a close button is rendered after each title
+    closable: bool,
 }
 
 impl Default for Tabs<'_> {
@@ -138,9 +151,12 @@ impl<'a> Tabs<'a> {
             selected,
             style: Style::default(),
             highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            highlight_underline: None,
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            overflow: TextOverflow::Clip,
+            closable: false,
         }
     }
 
@@ -251,6 +267,43 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Draws an underline below the highlighted tab.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The underline is drawn with [`symbols::line::HORIZONTAL`] on the row below the titles and
+    /// spans the columns of the selected tab's title. This requires the tabs to be rendered in an
+    /// area that is at least two rows high; the underline is not drawn otherwise.
+    ///
+    /// The second row of the area is reserved for the underline: it is overwritten below the
+    /// selected title, so it should not be shared with another widget. Give the tabs a
+    /// two-row area (e.g. `Constraint::Length(2)`) and render the content below it.
+    ///
+    /// The underline is independent of [`Tabs::highlight_style`], which is still applied to the
+    /// selected title. To keep the title's normal style and only show the underline, set the
+    /// highlight style to [`Style::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Tabs,
+    /// };
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"])
+    ///     .highlight_style(Style::new())
+    ///     .highlight_underline(Style::new().yellow());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_underline<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_underline = Some(style.into());
+        self
+    }
+
     /// Sets the string to use as tab divider.
     ///
     /// By default, the divider is a pipe (`|`).
@@ -348,6 +401,77 @@ impl<'a> Tabs<'a> {
         self.padding_left = padding.into();
         self
     }
+
+    /// Sets how titles that do not fit in the remaining width are truncated.
+    ///
+    /// Defaults to [`TextOverflow::Clip`], which cuts the last visible title at the edge of the
+    /// area. With [`TextOverflow::Ellipsis`], the end of the title is replaced by the given symbol.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{text::TextOverflow, widgets::Tabs};
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).overflow(TextOverflow::Ellipsis("…"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn overflow(mut self, overflow: TextOverflow<'a>) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Shows a close button (`✕`) after the title of each tab.
+    ///
+    /// The button is separated from the title by a space and is part of the tab, so it is styled
+    /// with the [`Tabs::highlight_style`] when the tab is selected. Use [`Tabs::close_button_at`]
+    /// to find the tab whose close button is at a given column. Closing the tab is left to the
+    /// application.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["main.rs", "lib.rs"]).closable(true);
+    /// // Renders
+    /// //  main.rs ✕ │ lib.rs ✕
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Returns the index of the tab whose close button is rendered at the given column.
+    ///
+    /// `area` is the area the tabs are rendered in (including the block, if any). Returns `None`
+    /// when the tabs are not [closable](Tabs::closable), or when there is no visible close button
+    /// at the column. This is typically used to map a mouse click to the tab to close.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{layout::Rect, widgets::Tabs};
+    ///
+    /// let tabs = Tabs::new(vec!["main.rs", "lib.rs"]).closable(true);
+    /// let area = Rect::new(0, 0, 30, 1);
+    /// assert_eq!(tabs.close_button_at(area, 9), Some(0));
+    /// assert_eq!(tabs.close_button_at(area, 20), Some(1));
+    /// assert_eq!(tabs.close_button_at(area, 4), None);
+    /// ```
+    pub fn close_button_at(&self, area: Rect, column: u16) -> Option<usize> {
+        if !self.closable {
+            return None;
+        }
+        let tabs_area = self.block.inner_if_some(area);
+        self.layout(tabs_area).find_map(|tab| {
+            // the close button symbol follows a space
+            let button = tab.close_button?;
+            (button.width > 1 && column == button.x + 1).then_some(tab.index)
+        })
+    }
 }
 
 impl Styled for Tabs<'_> {
@@ -383,57 +507,119 @@ impl Tabs<'_> {
             return;
         }
 
-        let mut x = tabs_area.left();
-        let titles_length = self.titles.len();
-        for (i, title) in self.titles.iter().enumerate() {
-            let last_title = titles_length - 1 == i;
-            let remaining_width = tabs_area.right().saturating_sub(x);
-
-            if remaining_width == 0 {
-                break;
-            }
-
-            // Left Padding
-            let pos = buf.set_line(x, tabs_area.top(), &self.padding_left, remaining_width);
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
-            if remaining_width == 0 {
-                break;
-            }
-
-            // Title
-            let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
-            if Some(i) == self.selected {
-                buf.set_style(
-                    Rect {
-                        x,
-                        y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
-                        height: 1,
-                    },
-                    self.highlight_style,
+        let y = tabs_area.top();
+        for tab in self.layout(tabs_area) {
+            let TabLayout {
+                padding_left,
+                title_area,
+                close_button,
+                padding_right,
+                divider,
+                ..
+            } = tab;
+            buf.set_line(padding_left.x, y, &self.padding_left, padding_left.width);
+            buf.set_line(title_area.x, y, &tab.title, title_area.width);
+            if let Some(button) = close_button {
+                buf.set_stringn(
+                    button.x,
+                    y,
+                    CLOSE_BUTTON,
+                    button.width as usize,
+                    Style::new(),
                 );
             }
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
-            if remaining_width == 0 {
-                break;
+            if Some(tab.index) == self.selected {
+                let highlight_area = close_button.map_or(title_area, |b| title_area.union(b));
+                buf.set_style(highlight_area, self.highlight_style);
+                self.render_underline(tabs_area, highlight_area, buf);
             }
-
-            // Right Padding
-            let pos = buf.set_line(x, tabs_area.top(), &self.padding_right, remaining_width);
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
-            if remaining_width == 0 || last_title {
-                break;
+            buf.set_line(padding_right.x, y, &self.padding_right, padding_right.width);
+            if let Some(divider) = divider {
+                buf.set_span(divider.x, y, &self.divider, divider.width);
             }
+        }
+    }
 
-            let pos = buf.set_span(x, tabs_area.top(), &self.divider, remaining_width);
-            x = pos.0;
+    /// Lays out the tabs on the first row of `tabs_area`, from left to right.
+    ///
+    /// This is shared by the rendering and [`Tabs::close_button_at`], so that hit tests always
+    /// match what is drawn. The parts of a tab that do not fit are clipped at the right edge of
+    /// the area, and the tabs that start past it are skipped.
+    fn layout(&self, tabs_area: Rect) -> impl Iterator<Item = TabLayout<'_>> {
+        let last_index = self.titles.len().saturating_sub(1);
+        // takes the next `width` columns after `x`, clipped to the area
+        let part = move |x: &mut u16, width: usize| {
+            let remaining_width = tabs_area.right().saturating_sub(*x);
+            let width = u16::try_from(width)
+                .unwrap_or(u16::MAX)
+                .min(remaining_width);
+            let area = Rect::new(*x, tabs_area.top(), width, 1);
+            *x += width;
+            area
+        };
+        let mut x = tabs_area.left();
+        self.titles
+            .iter()
+            .enumerate()
+            .map_while(move |(index, title)| {
+                if x >= tabs_area.right() {
+                    return None;
+                }
+                let padding_left = part(&mut x, self.padding_left.width());
+                let remaining_width = tabs_area.right().saturating_sub(x);
+                let title = self.overflow.truncate(title, remaining_width);
+                let title_area = part(&mut x, title.width());
+                let close_button = self
+                    .closable
+                    .then(|| part(&mut x, WidthMode::current().width(CLOSE_BUTTON)));
+                let padding_right = part(&mut x, self.padding_right.width());
+                let divider = (index != last_index).then(|| part(&mut x, self.divider.width()));
+                Some(TabLayout {
+                    index,
+                    title,
+                    padding_left,
+                    title_area,
+                    close_button,
+                    padding_right,
+                    divider,
+                })
+            })
+    }
+
+    /// Draws the highlight underline on the row below the selected title, if enabled.
+    fn render_underline(&self, tabs_area: Rect, title_area: Rect, buf: &mut Buffer) {
+        let Some(style) = self.highlight_underline else {
+            return;
+        };
+        if tabs_area.height < 2 {
+            return;
+        }
+        let y = title_area.y + 1;
+        for x in title_area.left()..title_area.right() {
+            buf[(x, y)]
+                .set_symbol(symbols::line::HORIZONTAL)
+                .set_style(style);
         }
     }
 }
 
+/// The areas of the parts of a single tab, as laid out by `Tabs::layout`.
+///
+/// Every area is on the first row of the tabs and is empty when the part was clipped.
+struct TabLayout<'a> {
+    /// Index of the tab in the titles
+    index: usize,
+    /// The title, truncated to the width left after the left padding
+    title: Cow<'a, Line<'a>>,
+    padding_left: Rect,
+    title_area: Rect,
+    /// Only set when the tabs are closable
+    close_button: Option<Rect>,
+    padding_right: Rect,
+    /// Not set after the last tab
+    divider: Option<Rect>,
+}
+
 impl<'a, Item> FromIterator<Item> for Tabs<'a>
 where
     Item: Into<Line<'a>>,
@@ -446,6 +632,7 @@ where
 #[cfg(test)]
 mod tests {
     use ratatui_core::style::{Color, Stylize};
+    use rstest::rstest;
 
     use super::*;
 
@@ -466,9 +653,12 @@ mod tests {
                 selected: Some(0),
                 style: Style::default(),
                 highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+                highlight_underline: None,
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                overflow: TextOverflow::Clip,
+                closable: false,
             }
         );
     }
@@ -483,9 +673,12 @@ mod tests {
                 selected: None,
                 style: Style::default(),
                 highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+                highlight_underline: None,
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                overflow: TextOverflow::Clip,
+                closable: false,
             }
         );
     }
@@ -540,6 +733,37 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_highlight_underline() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"])
+            .select(1)
+            .highlight_style(Style::new())
+            .highlight_underline(Style::new().red());
+        let mut expected =
+            Buffer::with_lines([" Tab1 │ Tab2 │ Tab3    ", "        ────           "]);
+        expected.set_style(Rect::new(8, 1, 4, 1), Style::new().red());
+        test_case(tabs, Rect::new(0, 0, 23, 2), &expected);
+    }
+
+    #[test]
+    fn render_highlight_underline_with_highlight_style() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"])
+            .padding("", "")
+            .highlight_underline(Style::new().red());
+        let mut expected = Buffer::with_lines(["Tab1│Tab2", "────     "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().red());
+        test_case(tabs, Rect::new(0, 0, 9, 2), &expected);
+    }
+
+    #[test]
+    fn render_highlight_underline_single_row() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]).highlight_underline(Style::new().red());
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 13, 1), &expected);
+    }
+
     #[test]
     fn render_no_padding() {
         let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).padding("", "");
@@ -558,6 +782,73 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[rstest]
+    #[case::clip(TextOverflow::Clip, " Tab1 │ Tab2 │ Lon")]
+    #[case::ellipsis(TextOverflow::Ellipsis("…"), " Tab1 │ Tab2 │ Lo…")]
+    #[case::wide_ellipsis(TextOverflow::Ellipsis("..."), " Tab1 │ Tab2 │ ...")]
+    fn render_overflow(#[case] overflow: TextOverflow, #[case] expected: &str) {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Long title"]).overflow(overflow);
+        let mut expected = Buffer::with_lines([expected]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 18, 1), &expected);
+    }
+
+    #[test]
+    fn render_closable() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"])
+            .select(1)
+            .closable(true);
+        let mut expected = Buffer::with_lines([" Tab1 ✕ │ Tab2 ✕ │ Tab3 ✕     "]);
+        expected.set_style(Rect::new(10, 0, 6, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
+    }
+
+    #[test]
+    fn render_closable_clipped() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]).closable(true);
+        let mut expected = Buffer::with_lines([" Tab1 ✕ │ Tab2 "]);
+        expected.set_style(Rect::new(1, 0, 6, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 15, 1), &expected);
+    }
+
+    #[rstest]
+    #[case::first_close_button(6, Some(0))]
+    #[case::second_close_button(15, Some(1))]
+    #[case::third_close_button(24, Some(2))]
+    #[case::title(2, None)]
+    #[case::space_before_close_button(5, None)]
+    #[case::divider(8, None)]
+    #[case::after_last_tab(27, None)]
+    fn close_button_at(#[case] column: u16, #[case] expected: Option<usize>) {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"]).closable(true);
+        let area = Rect::new(0, 0, 30, 1);
+        assert_eq!(tabs.close_button_at(area, column), expected);
+    }
+
+    #[test]
+    fn close_button_at_with_block() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"])
+            .closable(true)
+            .block(Block::bordered());
+        let area = Rect::new(10, 0, 30, 3);
+        assert_eq!(tabs.close_button_at(area, 17), Some(0));
+        assert_eq!(tabs.close_button_at(area, 26), Some(1));
+    }
+
+    #[test]
+    fn close_button_at_clipped() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]).closable(true);
+        let area = Rect::new(0, 0, 15, 1);
+        assert_eq!(tabs.close_button_at(area, 6), Some(0));
+        assert_eq!(tabs.close_button_at(area, 15), None);
+    }
+
+    #[test]
+    fn close_button_at_not_closable() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]);
+        assert_eq!(tabs.close_button_at(Rect::new(0, 0, 30, 1), 6), None);
+    }
+
     #[test]
     fn render_with_block() {
         let tabs =
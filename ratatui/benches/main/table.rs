@@ -44,6 +44,31 @@ fn table(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark for rebuilding a large table on each frame versus keeping it and rendering it by
+/// reference.
+fn table_ref(c: &mut Criterion) {
+    let mut group = c.benchmark_group("table_ref");
+    let row_count = 10_000;
+    let build_table = || {
+        let rows = (0..row_count).map(|i| Row::new([format!("{i}"), format!("row {i}")]));
+        Table::new(rows, [Constraint::Length(5), Constraint::Fill(1)])
+    };
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
+
+    // Build the table in each iteration, as when rendering it with `Frame::render_widget`
+    group.bench_function("rebuild", |b| {
+        b.iter(|| Widget::render(build_table(), buffer.area, &mut buffer));
+    });
+
+    // Build the table once and render it by reference, as with `FrameExt::render_widget_ref`
+    let table = build_table();
+    group.bench_function("render_ref", |b| {
+        b.iter(|| Widget::render(&table, buffer.area, &mut buffer));
+    });
+
+    group.finish();
+}
+
 fn render(bencher: &mut Bencher, table: &Table) {
     let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
     bencher.iter_batched(
@@ -66,4 +91,4 @@ fn render_stateful(bencher: &mut Bencher, table: &Table, mut state: TableState)
     );
 }
 
-criterion_group!(benches, table);
+criterion_group!(benches, table, table_ref);
@@ -83,7 +83,7 @@ pub fn try_init() -> io::Result<DefaultTerminal> {
     set_panic_hook();
     enable_raw_mode()?;
     execute!(stdout(), EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout());
+    let backend = CrosstermBackend::new(stdout()).detect_tty();
     Terminal::new(backend)
 }
 
@@ -171,7 +171,7 @@ pub fn init_with_options(options: TerminalOptions) -> DefaultTerminal {
 pub fn try_init_with_options(options: TerminalOptions) -> io::Result<DefaultTerminal> {
     set_panic_hook();
     enable_raw_mode()?;
-    let backend = CrosstermBackend::new(stdout());
+    let backend = CrosstermBackend::new(stdout()).detect_tty();
     Terminal::with_options(backend, options)
 }
 
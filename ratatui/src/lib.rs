@@ -351,7 +351,10 @@ pub use crate::init::{
 
 /// Re-exports for the backend implementations.
 pub mod backend {
-    pub use ratatui_core::backend::{Backend, ClearType, TestBackend, WindowSize};
+    pub use ratatui_core::backend::{
+        Backend, BackendOp, ClearType, ColorMode, ModifierFallbacks, ResizeReceiver, TestBackend,
+        WindowSize,
+    };
     #[cfg(feature = "crossterm")]
     pub use ratatui_crossterm::{CrosstermBackend, FromCrossterm, IntoCrossterm};
     #[cfg(all(not(windows), feature = "termion"))]
@@ -7,19 +7,26 @@
 //! these types directly from the `ratatui` crate without having to import the `ratatui_widgets`
 //! crate.
 //!
-//! Widgets are created for each frame as they are consumed after rendered. They are not meant to be
-//! stored but used as *commands* to draw common figures in the UI.
+//! Widgets are usually created for each frame as they are consumed after rendered, and used as
+//! *commands* to draw common figures in the UI. All the widgets also implement [`Widget`] (or
+//! [`StatefulWidget`]) for a reference to themselves, which means that they implement [`WidgetRef`]
+//! (or [`StatefulWidgetRef`]) too. Widgets that are expensive to build can therefore be stored and
+//! rendered by reference on each frame, e.g. using [`FrameExt::render_widget_ref`].
 //!
 //! The available widgets are:
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
+//! - [`Blink`]: alternates the style of another widget between two styles.
 //! - [`calendar::Monthly`]: displays a single month.
+//! - [`calendar::Yearly`]: displays the months of a year in a grid.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`KeyValueList`]: displays aligned `key: value` pairs.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`Padded`]: renders another widget with padding around it.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: display a single data set as a sparkline.
@@ -36,24 +43,27 @@ pub use ratatui_widgets::block;
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
 pub use ratatui_widgets::{
-    barchart::{Bar, BarChart, BarGroup},
+    barchart::{Bar, BarChart, BarGroup, ValuePlacement},
+    blink::Blink,
     block::{Block, Padding},
-    borders::{BorderType, Borders},
+    borders::{BorderType, BorderTypes, Borders},
     canvas,
     chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
     clear::Clear,
     gauge::{Gauge, LineGauge},
-    list::{List, ListDirection, ListItem, ListState},
+    key_value::{KeyValue, KeyValueList},
+    list::{List, ListDirection, ListItem, ListNavigation, ListState},
     logo::{RatatuiLogo, Size as RatatuiLogoSize},
     mascot::{MascotEyeColor, RatatuiMascot},
-    paragraph::{Paragraph, Wrap},
-    scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    padded::Padded,
+    paragraph::{Paragraph, ParagraphState, ParagraphWithCursor, SpanRegion, SpanRegions, Wrap},
+    scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarPair, ScrollbarState},
     sparkline::{RenderDirection, Sparkline, SparklineBar},
     table::{Cell, HighlightSpacing, Row, Table, TableState},
     tabs::Tabs,
 };
-#[instability::unstable(feature = "widget-ref")]
-pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
+pub use stateful_widget_ref::StatefulWidgetRef;
+pub use widget_ref::WidgetRef;
 
 mod stateful_widget_ref;
 mod widget_ref;
@@ -62,7 +72,47 @@ use ratatui_core::layout::Rect;
 
 /// Extension trait for [`Frame`] that provides methods to render [`WidgetRef`] and
 /// [`StatefulWidgetRef`] to the current buffer.
-#[instability::unstable(feature = "widget-ref")]
+///
+/// Unlike [`Frame::render_widget`] and [`Frame::render_stateful_widget`], these methods do not
+/// consume the widget. This is useful for widgets that are expensive to build (e.g. a [`Table`]
+/// with many rows) and that can be kept between frames and rendered again. All the built-in widgets
+/// implement [`Widget`] (or [`StatefulWidget`]) for a reference to themselves, so a reference to
+/// any of them can be passed to these methods.
+///
+/// The widget is only borrowed for the duration of the call, so it can be kept in the application
+/// state and rendered from the [`Terminal::draw`] closure.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{backend::TestBackend, Terminal};
+/// use ratatui::widgets::{FrameExt, Row, Table, TableState};
+///
+/// struct App {
+///     table: Table<'static>,
+///     state: TableState,
+/// }
+///
+/// # let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+/// let rows = (0..10_000).map(|i| Row::new([format!("row {i}")]));
+/// let mut app = App {
+///     table: Table::new(rows, [10]),
+///     state: TableState::default(),
+/// };
+/// // the table is built once and rendered by reference on each frame
+/// for _ in 0..3 {
+///     terminal.draw(|frame| {
+///         frame.render_stateful_widget_ref(&app.table, frame.area(), &mut app.state);
+///     })?;
+///     app.state.select_next();
+/// }
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [`Terminal::draw`]: crate::Terminal::draw
+/// [`Frame`]: ratatui_core::terminal::Frame
+/// [`Frame::render_widget`]: ratatui_core::terminal::Frame::render_widget
+/// [`Frame::render_stateful_widget`]: ratatui_core::terminal::Frame::render_stateful_widget
 pub trait FrameExt {
     /// Render a [`WidgetRef`] to the current buffer using [`WidgetRef::render_ref`].
     ///
@@ -72,7 +122,6 @@ pub trait FrameExt {
     /// # Example
     ///
     /// ```rust
-    /// # #[cfg(feature = "unstable-widget-ref")] {
     /// # use ratatui::{backend::TestBackend, Terminal};
     /// # let backend = TestBackend::new(5, 5);
     /// # let mut terminal = Terminal::new(backend).unwrap();
@@ -85,7 +134,6 @@ pub trait FrameExt {
     /// let block = Block::new();
     /// let area = Rect::new(0, 0, 5, 5);
     /// frame.render_widget_ref(&block, area);
-    /// # }
     /// ```
     ///
     /// [`Layout`]: crate::layout::Layout
@@ -104,7 +152,6 @@ pub trait FrameExt {
     /// # Example
     ///
     /// ```rust
-    /// # #[cfg(feature = "unstable-widget-ref")] {
     /// # use ratatui::{backend::TestBackend, Terminal};
     /// # let backend = TestBackend::new(5, 5);
     /// # let mut terminal = Terminal::new(backend).unwrap();
@@ -118,7 +165,6 @@ pub trait FrameExt {
     /// let list = List::new(vec![ListItem::new("Item 1"), ListItem::new("Item 2")]);
     /// let area = Rect::new(0, 0, 5, 5);
     /// frame.render_stateful_widget_ref(&list, area, &mut state);
-    /// # }
     /// ```
     /// [`Layout`]: crate::layout::Layout
     #[allow(clippy::needless_pass_by_value)]
@@ -127,7 +173,6 @@ pub trait FrameExt {
         W: StatefulWidgetRef;
 }
 
-#[cfg(feature = "unstable-widget-ref")]
 impl FrameExt for ratatui_core::terminal::Frame<'_> {
     #[allow(clippy::needless_pass_by_value)]
     fn render_widget_ref<W: WidgetRef>(&mut self, widget: W, area: Rect) {
@@ -142,3 +187,28 @@ impl FrameExt for ratatui_core::terminal::Frame<'_> {
         widget.render_ref(area, self.buffer_mut(), state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn render_widgets_by_reference() {
+        let mut terminal = Terminal::new(TestBackend::new(7, 3)).unwrap();
+        let paragraph = Paragraph::new("Hello");
+        let table = Table::new([Row::new(["a", "b"]), Row::new(["c", "d"])], [1, 1]);
+        let mut state = TableState::new().with_selected(Some(1));
+        for _ in 0..2 {
+            terminal
+                .draw(|frame| {
+                    frame.render_widget_ref(&paragraph, Rect::new(0, 0, 7, 1));
+                    frame.render_stateful_widget_ref(&table, Rect::new(0, 1, 7, 2), &mut state);
+                })
+                .unwrap();
+        }
+        terminal
+            .backend()
+            .assert_buffer_lines(["Hello  ", "a b    ", "c d    "]);
+    }
+}
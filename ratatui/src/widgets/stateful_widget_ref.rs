@@ -7,13 +7,20 @@ use crate::{buffer::Buffer, layout::Rect};
 /// This is the stateful equivalent of `WidgetRef`. It is useful when you need to store a reference
 /// to a stateful widget and render it later. It also allows you to render boxed stateful widgets.
 ///
-/// This trait was introduced in Ratatui 0.26.0. It is currently marked as unstable as we are still
-/// evaluating the API and may make changes in the future. See
-/// <https://github.com/ratatui/ratatui/issues/1287> for more information.
+/// This trait was introduced in Ratatui 0.26.0. See <https://github.com/ratatui/ratatui/issues/1287>
+/// for the history of its design.
 ///
-/// A blanket implementation of `StatefulWidgetRef` for `&W` where `W` implements `StatefulWidget`
-/// is provided. Most of the time you will want to implement `StatefulWidget` against a reference to
-/// the widget instead of implementing `StatefulWidgetRef` directly.
+/// A blanket implementation of `StatefulWidgetRef` for `&W` where `&W` implements `StatefulWidget`
+/// is provided. The built-in stateful widgets (e.g. [`List`] and [`Table`]) implement
+/// `StatefulWidget` for a reference to themselves, so they can be rendered by reference with
+/// [`FrameExt::render_stateful_widget_ref`]. Most of the time you will want to implement
+/// `StatefulWidget` against a reference to the widget, and for the widget itself by delegating to
+/// the reference, instead of implementing `StatefulWidgetRef` directly.
+///
+/// [`WidgetRef`]: super::WidgetRef
+/// [`List`]: super::List
+/// [`Table`]: super::Table
+/// [`FrameExt::render_stateful_widget_ref`]: super::FrameExt::render_stateful_widget_ref
 ///
 /// See the documentation for [`WidgetRef`] for more information on boxed widgets. See the
 /// documentation for [`StatefulWidget`] for more information on stateful widgets.
@@ -21,7 +28,6 @@ use crate::{buffer::Buffer, layout::Rect};
 /// # Examples
 ///
 /// ```rust
-/// # #[cfg(feature = "unstable-widget-ref")] {
 /// use ratatui::widgets::StatefulWidgetRef;
 /// use ratatui_core::{
 ///     buffer::Buffer,
@@ -52,9 +58,7 @@ use crate::{buffer::Buffer, layout::Rect};
 ///     let mut state = "world".to_string();
 ///     widget.render(area, buf, &mut state);
 /// }
-/// # }
 /// ```
-#[instability::unstable(feature = "widget-ref")]
 pub trait StatefulWidgetRef {
     /// State associated with the stateful widget.
     ///
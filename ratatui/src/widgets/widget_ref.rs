@@ -10,11 +10,21 @@ use crate::{buffer::Buffer, layout::Rect, style::Style};
 /// useful when you want to store a collection of widgets with different types. You can then iterate
 /// over the collection and render each widget.
 ///
-/// This trait was introduced in Ratatui 0.26.0 and is implemented for all the internal widgets. It
-/// is currently marked as unstable as we are still evaluating the API and may make changes in the
-/// future. See <https://github.com/ratatui/ratatui/issues/1287> for more information.
+/// This trait was introduced in Ratatui 0.26.0. See <https://github.com/ratatui/ratatui/issues/1287>
+/// for the history of its design.
 ///
-/// A blanket implementation of `Widget` for `&W` where `W` implements `WidgetRef` is provided.
+/// # Relationship with `Widget`
+///
+/// A blanket implementation of `WidgetRef` for `&W` where `&W` implements [`Widget`] is provided.
+/// All the built-in widgets implement `Widget` for a reference to themselves (e.g.
+/// `impl Widget for &Table`), so a reference to any of them is a `WidgetRef` and can be rendered
+/// with [`FrameExt::render_widget_ref`] without being consumed. When writing a widget, prefer this
+/// pattern: implement `Widget` for `&MyWidget` with the rendering logic and implement `Widget` for
+/// `MyWidget` by delegating to it. Both impls then always render the same output. Only implement
+/// `WidgetRef` directly for types that cannot implement `Widget` for a reference, and in that case
+/// implement `Widget` by calling [`WidgetRef::render_ref`] (see the example below).
+///
+/// [`FrameExt::render_widget_ref`]: super::FrameExt::render_widget_ref
 ///
 /// A blanket implementation of `WidgetRef` for `Option<W>` where `W` implements `WidgetRef` is
 /// provided. This is a convenience approach to make it easier to attach child widgets to parent
@@ -23,7 +33,6 @@ use crate::{buffer::Buffer, layout::Rect, style::Style};
 /// # Examples
 ///
 /// ```rust
-/// # #[cfg(feature = "unstable-widget-ref")] {
 /// use ratatui::widgets::WidgetRef;
 /// use ratatui_core::{buffer::Buffer, layout::Rect, text::Line, widgets::Widget};
 ///
@@ -71,9 +80,7 @@ use crate::{buffer::Buffer, layout::Rect, style::Style};
 ///     widget.render_ref(area, buf);
 /// }
 /// # }
-/// # }
 /// ```
-#[instability::unstable(feature = "widget-ref")]
 pub trait WidgetRef {
     /// Draws the current state of the widget in the given buffer. That is the only method required
     /// to implement a custom widget.
@@ -125,7 +132,6 @@ impl WidgetRef for String {
 /// # Examples
 ///
 /// ```rust
-/// # #[cfg(feature = "unstable-widget-ref")] {
 /// use ratatui::widgets::WidgetRef;
 /// use ratatui_core::{buffer::Buffer, layout::Rect, text::Line, widgets::Widget};
 ///
@@ -146,7 +152,6 @@ impl WidgetRef for String {
 ///         self.child.render_ref(area, buf);
 ///     }
 /// }
-/// # }
 /// ```
 impl<W: WidgetRef> WidgetRef for Option<W> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
@@ -103,12 +103,17 @@ const DEFAULT_STATE_BUFFER: [&str; 5] = [
 const DEFAULT_STATE_REPR: &str = r#"{
   "list": {
     "offset": 0,
-    "selected": null
+    "line_offset": 0,
+    "horizontal_offset": 0,
+    "selected": null,
+    "selected_indices": []
   },
   "table": {
     "offset": 0,
     "selected": null,
-    "selected_column": null
+    "selected_column": null,
+    "column_offset": 0,
+    "expanded": null
   },
   "scrollbar": {
     "content_length": 10,
@@ -141,12 +146,17 @@ const SELECTED_STATE_BUFFER: [&str; 5] = [
 const SELECTED_STATE_REPR: &str = r#"{
   "list": {
     "offset": 0,
-    "selected": 1
+    "line_offset": 0,
+    "horizontal_offset": 0,
+    "selected": 1,
+    "selected_indices": []
   },
   "table": {
     "offset": 0,
     "selected": 1,
-    "selected_column": 0
+    "selected_column": 0,
+    "column_offset": 0,
+    "expanded": null
   },
   "scrollbar": {
     "content_length": 10,
@@ -181,12 +191,17 @@ const SCROLLED_STATE_BUFFER: [&str; 5] = [
 const SCROLLED_STATE_REPR: &str = r#"{
   "list": {
     "offset": 4,
-    "selected": 8
+    "line_offset": 0,
+    "horizontal_offset": 0,
+    "selected": 8,
+    "selected_indices": []
   },
   "table": {
     "offset": 4,
     "selected": 8,
-    "selected_column": 0
+    "selected_column": 0,
+    "column_offset": 0,
+    "expanded": null
   },
   "scrollbar": {
     "content_length": 10,
@@ -228,4 +243,6 @@ fn table_state_backwards_compatibility() {
     let old_state: TableState = serde_json::from_str(OLD_TABLE_DESERIALIZE).unwrap();
     let new_state: TableState = serde_json::from_str(NEW_TABLE_DESERIALIZE).unwrap();
     assert_eq!(old_state, new_state);
+    assert_eq!(old_state.column_offset(), 0);
+    assert_eq!(old_state.expanded(), None);
 }
@@ -1,5 +1,3 @@
-#![cfg(feature = "unstable-widget-ref")]
-
 use std::{
     any::{type_name, Any},
     cell::RefCell,
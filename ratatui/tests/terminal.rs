@@ -1,8 +1,9 @@
 use std::error::Error;
 
 use ratatui::{
-    backend::TestBackend,
-    layout::Rect,
+    backend::{Backend, BackendOp, TestBackend},
+    buffer::Cell,
+    layout::{Position, Rect, Size},
     widgets::{Block, Paragraph, Widget},
     Terminal, TerminalOptions, Viewport,
 };
@@ -39,6 +40,81 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_tracks_last_known_size() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    assert_eq!(terminal.last_known_size(), Size::new(10, 10));
+
+    // the size is only updated when the terminal is resized
+    terminal.backend_mut().resize(8, 6);
+    assert_eq!(terminal.last_known_size(), Size::new(10, 10));
+    terminal.draw(|_| {})?;
+    assert_eq!(terminal.last_known_size(), Size::new(8, 6));
+
+    terminal.backend_mut().resize(12, 4);
+    terminal.set_viewport(Viewport::Fullscreen)?;
+    assert_eq!(terminal.last_known_size(), Size::new(12, 4));
+    Ok(())
+}
+
+#[test]
+fn terminal_resize_if_needed() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    assert!(!terminal.resize_if_needed()?);
+
+    terminal.backend_mut().resize(8, 6);
+    assert!(terminal.resize_if_needed()?);
+    assert_eq!(terminal.last_known_size(), Size::new(8, 6));
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 0, 8, 6));
+
+    // a burst of resize events only resizes once
+    assert!(!terminal.resize_if_needed()?);
+    Ok(())
+}
+
+#[test]
+fn terminal_resize_if_needed_inline() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(3),
+        },
+    )?;
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 0, 10, 3));
+    assert!(!terminal.resize_if_needed()?);
+
+    // the viewport follows the width and keeps its height
+    terminal.backend_mut().resize(12, 8);
+    assert!(terminal.resize_if_needed()?);
+    assert_eq!(terminal.last_known_size(), Size::new(12, 8));
+    assert_eq!(terminal.get_frame().area().as_size(), Size::new(12, 3));
+    assert!(!terminal.resize_if_needed()?);
+
+    // the height of the viewport is clamped to the height of the terminal
+    terminal.backend_mut().resize(12, 2);
+    assert!(terminal.resize_if_needed()?);
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 0, 12, 2));
+    Ok(())
+}
+
+#[test]
+fn terminal_resize_if_needed_fixed() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Fixed(Rect::new(1, 1, 4, 4)),
+        },
+    )?;
+    terminal.backend_mut().resize(8, 6);
+    assert!(!terminal.resize_if_needed()?);
+    assert_eq!(terminal.get_frame().area(), Rect::new(1, 1, 4, 4));
+    Ok(())
+}
+
 #[test]
 fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(10, 10);
@@ -191,6 +267,46 @@ fn terminal_insert_before_scrolls_on_large_input() -> Result<(), Box<dyn Error>>
     Ok(())
 }
 
+#[test]
+fn terminal_insert_before_taller_than_terminal() -> Result<(), Box<dyn Error>> {
+    // Inserting more lines than the terminal height pushes the extra lines into the scrollback
+    // and leaves the viewport at the bottom of the terminal.
+
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+
+    terminal.insert_before(8, |buf| {
+        let lines = (1..=8).map(|i| format!("------ Line {i} ------"));
+        Paragraph::new(lines.collect::<Vec<_>>().join("\n")).render(buf.area, buf);
+    })?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("[---- Viewport ----]");
+        f.render_widget(paragraph, f.area());
+    })?;
+
+    terminal.backend().assert_buffer_lines([
+        "------ Line 5 ------",
+        "------ Line 6 ------",
+        "------ Line 7 ------",
+        "------ Line 8 ------",
+        "[---- Viewport ----]",
+    ]);
+    terminal.backend().assert_scrollback_lines([
+        "------ Line 1 ------",
+        "------ Line 2 ------",
+        "------ Line 3 ------",
+        "------ Line 4 ------",
+    ]);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "scrolling-regions")]
 fn terminal_insert_before_scrolls_on_large_input_does_not_clobber() -> Result<(), Box<dyn Error>> {
@@ -482,3 +598,217 @@ fn terminal_insert_before_large_viewport_does_not_clobber() -> Result<(), Box<dy
 
     Ok(())
 }
+
+#[test]
+fn terminal_set_viewport_inline_to_fullscreen() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::new(10, 5);
+    backend.set_cursor_position((0, 2))?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+        },
+    )?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("inline"), f.area()))?;
+    terminal.backend().assert_buffer_lines([
+        "          ",
+        "          ",
+        "inline    ",
+        "          ",
+        "          ",
+    ]);
+
+    terminal.set_viewport(Viewport::Fullscreen)?;
+    terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(0, 0, 10, 5));
+        f.render_widget(Paragraph::new("fullscreen"), f.area());
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "fullscreen",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_set_viewport_fullscreen_to_inline() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("fullscreen"), f.area()))?;
+
+    // the inline viewport starts at the top of the screen and the fullscreen content is cleared
+    terminal.set_viewport(Viewport::Inline(2))?;
+    terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(0, 0, 10, 2));
+        f.render_widget(Paragraph::new("inline"), f.area());
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "inline    ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+    ]);
+    assert_eq!(terminal.viewport(), &Viewport::Inline(2));
+    Ok(())
+}
+
+#[test]
+fn terminal_set_viewport_inline_preserves_content_above() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::new(10, 5);
+    backend.set_cursor_position((0, 1))?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+    terminal.insert_before(1, |buf| Paragraph::new("history").render(buf.area, buf))?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("small"), f.area()))?;
+
+    terminal.set_viewport(Viewport::Inline(3))?;
+    terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(0, 2, 10, 3));
+        f.render_widget(Paragraph::new("large"), f.area());
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "          ",
+        "history   ",
+        "large     ",
+        "          ",
+        "          ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_set_viewport_fixed() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("fullscreen"), f.area()))?;
+
+    terminal.set_viewport(Viewport::Fixed(Rect::new(2, 3, 5, 1)))?;
+    terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(2, 3, 5, 1));
+        f.render_widget(Paragraph::new("fixed"), f.area());
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "          ",
+        "          ",
+        "          ",
+        "  fixed   ",
+        "          ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_render_offscreen_composites_in_a_single_draw() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    let area = Rect::new(0, 0, 10, 2);
+    let previous = terminal.render_offscreen(area, |f| {
+        assert_eq!(f.area(), area);
+        f.render_widget(Paragraph::new("aaaaaaaaaa\naaaaaaaaaa"), f.area());
+    });
+    let next = terminal.render_offscreen(area, |f| {
+        f.render_widget(Block::bordered(), f.area());
+    });
+    terminal
+        .backend()
+        .assert_buffer_lines(["          ", "          "]);
+
+    // wipe the next screen in from the left
+    terminal.draw(|f| {
+        f.buffer_mut().merge(&previous);
+        for x in 0..4 {
+            for y in 0..2 {
+                f.buffer_mut()[(x, y)] = next[(x, y)].clone();
+            }
+        }
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["┌───aaaaaa", "└───aaaaaa"]);
+    Ok(())
+}
+
+#[test]
+fn terminal_hides_cursor_during_draw() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(3, 1).recording_ops();
+    let mut terminal = Terminal::new(backend)?;
+    terminal.set_hide_cursor_during_draw(true);
+    assert!(terminal.hides_cursor_during_draw());
+    terminal.draw(|f| {
+        f.buffer_mut()[(0, 0)].set_symbol("a");
+        f.set_cursor_position((1, 0));
+    })?;
+    assert_eq!(
+        terminal.backend_mut().take_ops(),
+        [
+            BackendOp::HideCursor,
+            BackendOp::Draw(vec![(0, 0, Cell::new("a"))]),
+            BackendOp::SetCursorPosition(Position::new(1, 0)),
+            BackendOp::ShowCursor,
+            BackendOp::Flush,
+        ]
+    );
+
+    // a hidden cursor is not hidden again before drawing
+    terminal.draw(|f| {
+        f.buffer_mut()[(0, 0)].set_symbol("b");
+    })?;
+    terminal.backend_mut().take_ops();
+    terminal.draw(|f| {
+        f.buffer_mut()[(0, 0)].set_symbol("c");
+    })?;
+    assert_eq!(
+        terminal.backend_mut().take_ops(),
+        [
+            BackendOp::Draw(vec![(0, 0, Cell::new("c"))]),
+            BackendOp::HideCursor,
+            BackendOp::Flush,
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn terminal_keeps_cursor_visible_during_draw_by_default() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(3, 1).recording_ops();
+    let mut terminal = Terminal::new(backend)?;
+    assert!(!terminal.hides_cursor_during_draw());
+    terminal.draw(|f| {
+        f.buffer_mut()[(0, 0)].set_symbol("a");
+        f.set_cursor_position((1, 0));
+    })?;
+    assert_eq!(
+        terminal.backend_mut().take_ops(),
+        [
+            BackendOp::Draw(vec![(0, 0, Cell::new("a"))]),
+            BackendOp::ShowCursor,
+            BackendOp::SetCursorPosition(Position::new(1, 0)),
+            BackendOp::Flush,
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn terminal_cell_size() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24))?;
+    assert_eq!(terminal.window_size()?.columns_rows, Size::new(80, 24));
+    assert_eq!(terminal.cell_size()?, Some(Size::new(8, 20)));
+    Ok(())
+}
+
+#[test]
+fn terminal_resize_rx_unsupported() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24))?;
+    let error = terminal.resize_rx().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+    Ok(())
+}
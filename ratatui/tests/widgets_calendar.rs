@@ -2,9 +2,10 @@
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
-    style::Style,
+    layout::Rect,
+    style::{Style, Stylize},
     widgets::{
-        calendar::{CalendarEventStore, Monthly},
+        calendar::{CalendarEventStore, Monthly, Yearly},
         Widget,
     },
     Terminal,
@@ -111,3 +112,107 @@ fn show_combo() {
     ]);
     test_render(c, 21, 7, &expected);
 }
+
+#[test]
+fn yearly_months_side_by_side() {
+    let date = Date::from_calendar_date(2023, Month::January, 1).unwrap();
+    let mut events = CalendarEventStore::default();
+    events.add(
+        Date::from_calendar_date(2023, Month::February, 14).unwrap(),
+        Style::new().red(),
+    );
+    let c = Yearly::new(date, events)
+        .months(date, 3)
+        .show_month_header(Style::default())
+        .show_weekdays_header(Style::default());
+    let mut expected = Buffer::with_lines([
+        "    January 2023          February 2023          March 2023      ",
+        " Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa",
+        "  1  2  3  4  5  6  7            1  2  3  4            1  2  3  4",
+        "  8  9 10 11 12 13 14   5  6  7  8  9 10 11   5  6  7  8  9 10 11",
+        " 15 16 17 18 19 20 21  12 13 14 15 16 17 18  12 13 14 15 16 17 18",
+        " 22 23 24 25 26 27 28  19 20 21 22 23 24 25  19 20 21 22 23 24 25",
+        " 29 30 31              26 27 28              26 27 28 29 30 31",
+        "",
+    ]);
+    expected.set_style(Rect::new(29, 4, 2, 1), Style::new().red());
+    test_render(c, 65, 8, &expected);
+}
+
+#[test]
+fn yearly_hides_weekdays_header_when_too_short() {
+    let date = Date::from_calendar_date(2023, Month::November, 1).unwrap();
+    let c = Yearly::new(date, CalendarEventStore::default())
+        .months(date, 2)
+        .show_month_header(Style::default())
+        .show_weekdays_header(Style::default());
+    let expected = Buffer::with_lines([
+        "    November 2023    ",
+        "           1  2  3  4",
+        "  5  6  7  8  9 10 11",
+        " 12 13 14 15 16 17 18",
+        " 19 20 21 22 23 24 25",
+        " 26 27 28 29 30",
+        "",
+        "",
+        "    December 2023    ",
+        "                 1  2",
+        "  3  4  5  6  7  8  9",
+        " 10 11 12 13 14 15 16",
+        " 17 18 19 20 21 22 23",
+        " 24 25 26 27 28 29 30",
+        " 31",
+    ]);
+    test_render(c, 21, 15, &expected);
+}
+
+#[test]
+fn yearly_grid() {
+    let date = Date::from_calendar_date(2023, Month::June, 15).unwrap();
+    let c = Yearly::new(date, CalendarEventStore::default()).show_month_header(Style::default());
+    #[rustfmt::skip]
+    let expected = Buffer::with_lines([
+        "    January 2023          February 2023          March 2023            April 2023         ",
+        "  1  2  3  4  5  6  7            1  2  3  4            1  2  3  4                     1",
+        "  8  9 10 11 12 13 14   5  6  7  8  9 10 11   5  6  7  8  9 10 11   2  3  4  5  6  7  8",
+        " 15 16 17 18 19 20 21  12 13 14 15 16 17 18  12 13 14 15 16 17 18   9 10 11 12 13 14 15",
+        " 22 23 24 25 26 27 28  19 20 21 22 23 24 25  19 20 21 22 23 24 25  16 17 18 19 20 21 22",
+        " 29 30 31              26 27 28              26 27 28 29 30 31     23 24 25 26 27 28 29",
+        "                                                                   30",
+        "",
+        "      May 2023              June 2023             July 2023            August 2023",
+        "     1  2  3  4  5  6               1  2  3                     1         1  2  3  4  5",
+        "  7  8  9 10 11 12 13   4  5  6  7  8  9 10   2  3  4  5  6  7  8   6  7  8  9 10 11 12",
+        " 14 15 16 17 18 19 20  11 12 13 14 15 16 17   9 10 11 12 13 14 15  13 14 15 16 17 18 19",
+        " 21 22 23 24 25 26 27  18 19 20 21 22 23 24  16 17 18 19 20 21 22  20 21 22 23 24 25 26",
+        " 28 29 30 31           25 26 27 28 29 30     23 24 25 26 27 28 29  27 28 29 30 31",
+        "                                             30 31",
+        "",
+        "   September 2023         October 2023          November 2023         December 2023",
+        "                 1  2   1  2  3  4  5  6  7            1  2  3  4                  1  2",
+        "  3  4  5  6  7  8  9   8  9 10 11 12 13 14   5  6  7  8  9 10 11   3  4  5  6  7  8  9",
+        " 10 11 12 13 14 15 16  15 16 17 18 19 20 21  12 13 14 15 16 17 18  10 11 12 13 14 15 16",
+        " 17 18 19 20 21 22 23  22 23 24 25 26 27 28  19 20 21 22 23 24 25  17 18 19 20 21 22 23",
+        " 24 25 26 27 28 29 30  29 30 31              26 27 28 29 30        24 25 26 27 28 29 30",
+        "                                                                   31",
+    ]);
+    test_render(c, 90, 23, &expected);
+}
+
+#[test]
+fn yearly_stops_after_last_representable_month() {
+    let date = Date::from_calendar_date(9999, Month::December, 1).unwrap();
+    let c = Yearly::new(date, CalendarEventStore::default())
+        .months(date, 2)
+        .show_month_header(Style::default());
+    let expected = Buffer::with_lines([
+        "    December 9999                          ",
+        "           1  2  3  4",
+        "  5  6  7  8  9 10 11",
+        " 12 13 14 15 16 17 18",
+        " 19 20 21 22 23 24 25",
+        " 26 27 28 29 30 31",
+        "",
+    ]);
+    test_render(c, 43, 7, &expected);
+}